@@ -78,54 +78,19 @@ else
 fi
 
 SETTINGS="{\"challenge_id\":\"$CHALLENGE_ID\",\"difficulty\":$difficulty,\"algorithm_id\":\"\",\"player_id\":\"\",\"block_id\":\"\"}"
-num_solutions=0
-num_invalid=0
-num_errors=0
-total_ms=0
 
 echo "----------------------------------------------------------------------"
 echo "Testing performance of $CHALLENGE/$ALGORITHM"
 echo "Settings: $SETTINGS"
 echo "Starting nonce: $start_nonce"
 echo "Number of nonces: $num_nonces"
-echo -ne ""
-for ((nonce=start_nonce; nonce<start_nonce+num_nonces; nonce++)); do
-    start_time=$(date +%s%3N)
-    stdout=$(mktemp)
-    stderr=$(mktemp)
-    ./target/release/tig-worker compute_solution "$SETTINGS" $nonce $REPO_DIR/tig-algorithms/wasm/$CHALLENGE/$ALGORITHM.wasm >"$stdout" 2>"$stderr"
-    exit_code=$?
-    output_stdout=$(cat "$stdout")
-    output_stderr=$(cat "$stderr")
-    end_time=$(date +%s%3N)
-    duration=$((end_time - start_time))
-    total_ms=$((total_ms + duration))
-    if [ $exit_code -eq 0 ]; then
-        num_solutions=$((num_solutions + 1))
-    else
-      if echo "$output_stderr" | grep -q "Invalid solution\|No solution found"; then
-          num_invalid=$((num_invalid + 1))
-      else
-          num_errors=$((num_errors + 1))
-      fi
-    fi
-    if [ $((num_solutions)) -eq 0 ]; then
-        avg_ms_per_solution=0
-    else
-        avg_ms_per_solution=$((total_ms / num_solutions))
-    fi
-    if [[ $debug_mode == true ]]; then
-        echo "    Nonce: $nonce"
-        echo "    Exit code: $exit_code"
-        echo "    Stdout: $output_stdout"
-        echo "    Stderr: $output_stderr"
-        echo "    Duration: $duration ms"
-        echo "#instances: $((num_solutions + num_invalid + num_errors)), #solutions: $num_solutions, #invalid: $num_invalid, #errors: $num_errors, average ms/solution: $avg_ms_per_solution"
-    else
-        echo -ne "#instances: $((num_solutions + num_invalid + num_errors)), #solutions: $num_solutions, #invalid: $num_invalid, #errors: $num_errors, average ms/solution: $avg_ms_per_solution\033[K\r"
-    fi
-done
-echo
+NONCES="$start_nonce..$((start_nonce + num_nonces))"
+WASM="$REPO_DIR/tig-algorithms/wasm/$CHALLENGE/$ALGORITHM.wasm"
+if [[ $debug_mode == true ]]; then
+    $TIG_WORKER_PATH compute_batch "$SETTINGS" $NONCES $WASM
+else
+    $TIG_WORKER_PATH compute_batch "$SETTINGS" $NONCES $WASM >/dev/null
+fi
 echo "----------------------------------------------------------------------"
 echo "To re-run this test, run the following commands:"
 echo "    git clone https://github.com/tig-foundation/tig-monorepo.git"
@@ -134,5 +99,4 @@ echo "    git pull origin/$CHALLENGE/$ALGORITHM --no-edit"
 echo "    bash scripts/test_algorithm.sh"
 echo "----------------------------------------------------------------------"
 echo "Share your results on https://www.reddit.com/r/TheInnovationGame"
-echo "----------------------------------------------------------------------"
-rm "$stdout" "$stderr"
\ No newline at end of file
+echo "----------------------------------------------------------------------"
\ No newline at end of file
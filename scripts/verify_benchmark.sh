@@ -60,24 +60,6 @@ echo "Downloading WASM from $download_url"
 curl -s -o "$algorithm_id.wasm" "$download_url"
 
 # verify solutions
-for i in $(seq 0 $(($solutions_count - 1))); do
-    solution_data=$(echo "$proof" | jq -c -S ".solutions_data[$i]")
-    echo Verifying $solution_data
-    nonce=$(echo "$solution_data" | jq -r '.nonce')
-    solution=$(echo "$solution_data" | jq -r '.solution')
-    echo Verifying solution is valid
-    ./target/release/tig-worker verify_solution "$settings" $nonce "$solution"
-    echo Verifying runtime_signature and fuel_consumed
-    compute_output=$(./target/release/tig-worker compute_solution "$settings" $nonce ./$algorithm_id.wasm | python3 -c "
-import sys, json
-data = json.load(sys.stdin)
-data['nonce'] = str(data['nonce'])
-print(json.dumps(data))
-    " | jq -c -S)
-    if [[ "$compute_output" == "$solution_data" ]]; then
-        echo "Ok"
-    else
-        echo "Mismatch. Actual: $compute_output"
-    fi
-done
-rm $algorithm_id.wasm
\ No newline at end of file
+echo "$response" > "$benchmark_id.json"
+$TIG_WORKER_PATH verify_proof "$benchmark_id.json" ./$algorithm_id.wasm || true
+rm "$benchmark_id.json" $algorithm_id.wasm
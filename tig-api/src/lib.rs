@@ -116,11 +116,17 @@ impl Api {
         if let Some(round) = req.round {
             query.insert("round".to_string(), round.to_string());
         }
+        query.insert("include_data".to_string(), req.include_data.to_string());
         let query = QueryMap::from(query);
         self.get(format!("get-block?{}", query.to_query_string()))
             .await
     }
 
+    pub async fn request_api_key(&self, req: RequestApiKeyReq) -> Result<RequestApiKeyResp> {
+        self.post("request-api-key".to_string(), jsonify(&req))
+            .await
+    }
+
     pub async fn submit_algorithm(&self, req: SubmitAlgorithmReq) -> Result<SubmitAlgorithmResp> {
         self.post("submit-algorithm".to_string(), jsonify(&req))
             .await
@@ -0,0 +1,331 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tig_api::*;
+use tig_structs::core::*;
+use warp::{http::Method, hyper::body::Bytes, path::FullPath, Filter};
+
+const API_KEY: &str = "api_key";
+
+#[derive(Debug)]
+struct Request {
+    method: Method,
+    path: String,
+    api_key: Option<String>,
+    query: HashMap<String, String>,
+    body: Bytes,
+}
+
+impl Request {
+    fn body<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+}
+
+// Serves `resp` on every route, recording each request it receives
+fn mock<T: Serialize>(resp: &T) -> (Api, Arc<Mutex<Vec<Request>>>) {
+    let resp = serde_json::to_value(resp).unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    let routes = warp::method()
+        .and(warp::path::full())
+        .and(warp::header::optional::<String>("x-api-key"))
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::body::bytes())
+        .map(move |method, path: FullPath, api_key, query, body| {
+            recorded.lock().unwrap().push(Request {
+                method,
+                path: path.as_str().to_string(),
+                api_key,
+                query,
+                body,
+            });
+            warp::reply::json(&resp)
+        });
+    let (addr, server) = warp::serve(routes).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+    (
+        Api::new(format!("http://{}", addr), API_KEY.to_string()),
+        requests,
+    )
+}
+
+fn last_request(requests: &Arc<Mutex<Vec<Request>>>) -> Request {
+    let mut requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    requests.pop().unwrap()
+}
+
+fn query(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn block_details() -> BlockDetails {
+    BlockDetails {
+        prev_block_id: "prev_block_id".to_string(),
+        height: 10,
+        round: 2,
+        eth_block_num: Some("123".to_string()),
+    }
+}
+
+fn solution_data(nonce: u64) -> SolutionData {
+    SolutionData {
+        nonce,
+        runtime_signature: 1,
+        fuel_consumed: 2,
+        solution: json!({"items": [1, 2, 3]}).as_object().unwrap().clone(),
+    }
+}
+
+#[tokio::test]
+async fn test_get_block() {
+    let resp = GetBlockResp {
+        block: Some(Block {
+            id: "block_id".to_string(),
+            details: block_details(),
+            data: None,
+            config: None,
+        }),
+    };
+
+    let (api, requests) = mock(&resp);
+    let req = GetBlockReq {
+        id: None,
+        round: None,
+        height: None,
+        include_data: false,
+    };
+    assert_eq!(api.get_block(req).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.path, "/get-block");
+    assert_eq!(request.api_key.as_deref(), Some(API_KEY));
+    assert_eq!(request.query, query(&[("include_data", "false")]));
+
+    let (api, requests) = mock(&resp);
+    let req = GetBlockReq {
+        id: Some("block_id".to_string()),
+        round: Some(2),
+        height: Some(10),
+        include_data: true,
+    };
+    assert_eq!(api.get_block(req).await.unwrap(), resp);
+    assert_eq!(
+        last_request(&requests).query,
+        query(&[
+            ("id", "block_id"),
+            ("round", "2"),
+            ("height", "10"),
+            ("include_data", "true"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_get_challenges() {
+    let resp = GetChallengesResp {
+        block_id: "block_id".to_string(),
+        block_details: block_details(),
+        challenges: vec![Challenge {
+            id: "c001".to_string(),
+            details: ChallengeDetails {
+                name: "satisfiability".to_string(),
+            },
+            state: None,
+            block_data: None,
+        }],
+    };
+    let (api, requests) = mock(&resp);
+    let req = GetChallengesReq {
+        block_id: "block_id".to_string(),
+    };
+    assert_eq!(api.get_challenges(req).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.path, "/get-challenges");
+    assert_eq!(request.query, query(&[("block_id", "block_id")]));
+}
+
+#[tokio::test]
+async fn test_get_algorithms() {
+    let resp = GetAlgorithmsResp {
+        block_id: "block_id".to_string(),
+        block_details: block_details(),
+        algorithms: Vec::new(),
+        wasms: Vec::new(),
+    };
+    let (api, requests) = mock(&resp);
+    let req = GetAlgorithmsReq {
+        block_id: "block_id".to_string(),
+    };
+    assert_eq!(api.get_algorithms(req).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.path, "/get-algorithms");
+    assert_eq!(request.query, query(&[("block_id", "block_id")]));
+}
+
+#[tokio::test]
+async fn test_get_players() {
+    let resp = GetPlayersResp {
+        block_id: "block_id".to_string(),
+        block_details: block_details(),
+        players: vec![Player {
+            id: "player_id".to_string(),
+            details: PlayerDetails {
+                name: "player".to_string(),
+                is_multisig: false,
+            },
+            block_data: None,
+        }],
+    };
+    for (player_type, encoded) in [
+        (PlayerType::Benchmarker, "benchmarker"),
+        (PlayerType::Innovator, "innovator"),
+    ] {
+        let (api, requests) = mock(&resp);
+        let req = GetPlayersReq {
+            block_id: "block_id".to_string(),
+            player_type,
+        };
+        assert_eq!(api.get_players(req).await.unwrap(), resp);
+        let request = last_request(&requests);
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(request.path, "/get-players");
+        assert_eq!(
+            request.query,
+            query(&[("block_id", "block_id"), ("player_type", encoded)])
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_get_benchmarks() {
+    let resp = GetBenchmarksResp {
+        block_id: "block_id".to_string(),
+        block_details: block_details(),
+        benchmarks: Vec::new(),
+        proofs: Vec::new(),
+        frauds: Vec::new(),
+    };
+    let (api, requests) = mock(&resp);
+    let req = GetBenchmarksReq {
+        block_id: "block_id".to_string(),
+        player_id: "player_id".to_string(),
+    };
+    assert_eq!(api.get_benchmarks(req).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.path, "/get-benchmarks");
+    assert_eq!(
+        request.query,
+        query(&[("block_id", "block_id"), ("player_id", "player_id")])
+    );
+}
+
+#[tokio::test]
+async fn test_get_benchmark_data() {
+    let resp = GetBenchmarkDataResp {
+        benchmark: None,
+        proof: None,
+        fraud: None,
+    };
+    let (api, requests) = mock(&resp);
+    let req = GetBenchmarkDataReq {
+        benchmark_id: "benchmark_id".to_string(),
+    };
+    assert_eq!(api.get_benchmark_data(req).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.path, "/get-benchmark-data");
+    assert_eq!(request.query, query(&[("benchmark_id", "benchmark_id")]));
+}
+
+#[tokio::test]
+async fn test_request_api_key() {
+    let resp = RequestApiKeyResp {
+        api_key: "new_api_key".to_string(),
+    };
+    for gnosis_safe_setup_tx_hash in [None, Some("tx_hash".to_string())] {
+        let (api, requests) = mock(&resp);
+        let req = RequestApiKeyReq {
+            signature: "signature".to_string(),
+            address: "address".to_string(),
+            gnosis_safe_setup_tx_hash,
+        };
+        assert_eq!(api.request_api_key(req.clone()).await.unwrap(), resp);
+        let request = last_request(&requests);
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.path, "/request-api-key");
+        assert_eq!(request.body::<RequestApiKeyReq>(), req);
+    }
+}
+
+#[tokio::test]
+async fn test_submit_algorithm() {
+    let resp = SubmitAlgorithmResp {
+        algorithm_id: "algorithm_id".to_string(),
+    };
+    let (api, requests) = mock(&resp);
+    let req = SubmitAlgorithmReq {
+        name: "algorithm".to_string(),
+        challenge_id: "c001".to_string(),
+        tx_hash: "tx_hash".to_string(),
+        code: "fn main() {}".to_string(),
+    };
+    assert_eq!(api.submit_algorithm(req.clone()).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.path, "/submit-algorithm");
+    assert_eq!(request.api_key.as_deref(), Some(API_KEY));
+    assert_eq!(request.body::<SubmitAlgorithmReq>(), req);
+}
+
+#[tokio::test]
+async fn test_submit_benchmark() {
+    let resp = SubmitBenchmarkResp {
+        benchmark_id: "benchmark_id".to_string(),
+        verified: Err("invalid".to_string()),
+    };
+    let (api, requests) = mock(&resp);
+    let req = SubmitBenchmarkReq {
+        settings: BenchmarkSettings {
+            player_id: "player_id".to_string(),
+            block_id: "block_id".to_string(),
+            challenge_id: "c001".to_string(),
+            algorithm_id: "c001_a001".to_string(),
+            difficulty: vec![50, 300],
+        },
+        solutions_meta_data: vec![solution_data(0).into(), solution_data(1).into()],
+        solution_data: solution_data(0),
+    };
+    assert_eq!(api.submit_benchmark(req.clone()).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.path, "/submit-benchmark");
+    assert_eq!(request.api_key.as_deref(), Some(API_KEY));
+    assert_eq!(request.body::<SubmitBenchmarkReq>(), req);
+}
+
+#[tokio::test]
+async fn test_submit_proof() {
+    let resp = SubmitProofResp { verified: Ok(()) };
+    let (api, requests) = mock(&resp);
+    let req = SubmitProofReq {
+        benchmark_id: "benchmark_id".to_string(),
+        solutions_data: vec![solution_data(0), solution_data(1)],
+    };
+    assert_eq!(api.submit_proof(req.clone()).await.unwrap(), resp);
+    let request = last_request(&requests);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.path, "/submit-proof");
+    assert_eq!(request.api_key.as_deref(), Some(API_KEY));
+    assert_eq!(request.body::<SubmitProofReq>(), req);
+}
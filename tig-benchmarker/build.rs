@@ -0,0 +1,228 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const ALGORITHMS_SRC: &str = "../tig-algorithms/src";
+
+// Parses lines of the form `pub use <module> as <alias>;`, ignoring commented out lines
+fn parse_reexports(path: &Path) -> Vec<(String, String)> {
+    println!("cargo:rerun-if-changed={}", path.display());
+    let src = fs::read_to_string(path).expect(&format!("Failed to read {}", path.display()));
+    src.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix("pub use ")?.strip_suffix(";")?;
+            let (module, alias) = line.split_once(" as ")?;
+            Some((module.trim().to_string(), alias.trim().to_string()))
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", ALGORITHMS_SRC);
+    let cuda = env::var("CARGO_FEATURE_CUDA").is_ok();
+
+    let mut code = String::new();
+    if cuda {
+        code += "pub type CudaFuncs =
+    std::collections::HashMap<&'static str, cudarc::driver::CudaFunction>;
+
+";
+    }
+    let mut challenge_ids = Vec::new();
+    for (challenge_module, challenge_id) in
+        parse_reexports(&Path::new(ALGORITHMS_SRC).join("lib.rs"))
+    {
+        if !challenge_id.starts_with("c") {
+            continue;
+        }
+        // Only algorithms whose cargo feature (e.g. "c001_a001") is enabled are compiled in
+        let algorithm_ids: Vec<String> = parse_reexports(
+            &Path::new(ALGORITHMS_SRC)
+                .join(&challenge_module)
+                .join("mod.rs"),
+        )
+        .into_iter()
+        .map(|(_, algorithm_id)| algorithm_id)
+        .filter(|algorithm_id| {
+            algorithm_id.starts_with(&format!("{}_a", challenge_id))
+                && env::var(format!("CARGO_FEATURE_{}", algorithm_id.to_uppercase())).is_ok()
+        })
+        .collect();
+
+        let mut arms = String::new();
+        for algorithm_id in algorithm_ids.iter() {
+            arms += &format!(
+                "            \"{a}\" => Some(tig_algorithms::{c}::{a}::solve_challenge as SolveChallengeFn),\n",
+                c = challenge_id,
+                a = algorithm_id
+            );
+        }
+        code += &format!(
+            "pub mod {c} {{
+    pub type SolveChallengeFn =
+        fn(&tig_challenges::{c}::Challenge) -> anyhow::Result<Option<tig_challenges::{c}::Solution>>;
+
+    pub fn solve_challenge(algorithm_id: &str) -> Option<SolveChallengeFn> {{
+        match algorithm_id {{
+{arms}            _ => None,
+        }}
+    }}
+
+    pub fn filter_nonce(
+        algorithm_id: &str,
+        seeds: [u64; 8],
+        difficulty: &Vec<i32>,
+    ) -> anyhow::Result<bool> {{
+        use tig_challenges::ChallengeTrait;
+        let solve_challenge = match solve_challenge(algorithm_id) {{
+            Some(solve_challenge) => solve_challenge,
+            None => return Ok(false),
+        }};
+        let challenge =
+            tig_challenges::{c}::Challenge::generate_instance_from_vec(seeds, difficulty)?;
+        Ok(match solve_challenge(&challenge) {{
+            Ok(Some(solution)) => challenge.verify_solution(&solution).is_err(),
+            _ => true,
+        }})
+    }}
+",
+            c = challenge_id,
+            arms = arms
+        );
+
+        if cuda {
+            let mut arms = String::new();
+            for algorithm_id in algorithm_ids.iter() {
+                arms += &format!(
+                    "            \"{a}\" => Some((tig_algorithms::{c}::{a}::cuda_solve_challenge as CudaSolveChallengeFn, &tig_algorithms::{c}::{a}::KERNEL)),\n",
+                    c = challenge_id,
+                    a = algorithm_id
+                );
+            }
+            code += &format!(
+                "
+    pub type CudaSolveChallengeFn = fn(
+        &tig_challenges::{c}::Challenge,
+        &std::sync::Arc<cudarc::driver::CudaDevice>,
+        std::collections::HashMap<&'static str, cudarc::driver::CudaFunction>,
+    ) -> anyhow::Result<Option<tig_challenges::{c}::Solution>>;
+
+    pub fn cuda_solve_challenge(
+        algorithm_id: &str,
+    ) -> Option<(CudaSolveChallengeFn, &'static Option<tig_algorithms::CudaKernel>)> {{
+        match algorithm_id {{
+{arms}            _ => None,
+        }}
+    }}
+
+    pub async fn cuda_filter_nonce(
+        algorithm_id: &str,
+        seeds: [u64; 8],
+        difficulty: &Vec<i32>,
+        dev: &std::sync::Arc<cudarc::driver::CudaDevice>,
+        challenge_cuda_funcs: &mut Option<super::CudaFuncs>,
+        algorithm_cuda_funcs: &mut Option<super::CudaFuncs>,
+    ) -> anyhow::Result<bool> {{
+        use super::super::run_benchmark::get_or_compile_cuda;
+        use tig_challenges::ChallengeTrait;
+        let (cuda_solve_challenge, algorithm_kernel) = match cuda_solve_challenge(algorithm_id) {{
+            Some(cuda_solve_challenge) => cuda_solve_challenge,
+            None => return Ok(false),
+        }};
+        let challenge_funcs = match challenge_cuda_funcs {{
+            Some(funcs) => funcs.clone(),
+            None => challenge_cuda_funcs
+                .insert(get_or_compile_cuda(\"{c}\", &tig_challenges::{c}::KERNEL, dev).await?)
+                .clone(),
+        }};
+        let algorithm_funcs = match algorithm_cuda_funcs {{
+            Some(funcs) => funcs.clone(),
+            None => algorithm_cuda_funcs
+                .insert(get_or_compile_cuda(algorithm_id, algorithm_kernel, dev).await?)
+                .clone(),
+        }};
+        let challenge = tig_challenges::{c}::Challenge::cuda_generate_instance_from_vec(
+            seeds,
+            difficulty,
+            dev,
+            challenge_funcs,
+        )?;
+        Ok(match cuda_solve_challenge(&challenge, dev, algorithm_funcs) {{
+            Ok(Some(solution)) => challenge.verify_solution(&solution).is_err(),
+            _ => true,
+        }})
+    }}
+",
+                c = challenge_id,
+                arms = arms
+            );
+        }
+        code += "}\n\n";
+        challenge_ids.push(challenge_id);
+    }
+
+    // Dispatches on the challenge id, so run_benchmark needs no per-challenge match
+    let mut arms = String::new();
+    for challenge_id in challenge_ids.iter() {
+        arms += &format!(
+            "        \"{c}\" => {c}::filter_nonce(algorithm_id, seeds, difficulty),\n",
+            c = challenge_id
+        );
+    }
+    code += &format!(
+        "pub fn filter_nonce(
+    challenge_id: &str,
+    algorithm_id: &str,
+    seeds: [u64; 8],
+    difficulty: &Vec<i32>,
+) -> anyhow::Result<bool> {{
+    match challenge_id {{
+{arms}        _ => Ok(false),
+    }}
+}}
+",
+        arms = arms
+    );
+    if cuda {
+        let mut arms = String::new();
+        for challenge_id in challenge_ids.iter() {
+            arms += &format!(
+                "        \"{c}\" => {{
+            {c}::cuda_filter_nonce(
+                algorithm_id,
+                seeds,
+                difficulty,
+                dev,
+                challenge_cuda_funcs,
+                algorithm_cuda_funcs,
+            )
+            .await
+        }}\n",
+                c = challenge_id
+            );
+        }
+        code += &format!(
+            "
+pub async fn cuda_filter_nonce(
+    challenge_id: &str,
+    algorithm_id: &str,
+    seeds: [u64; 8],
+    difficulty: &Vec<i32>,
+    dev: &std::sync::Arc<cudarc::driver::CudaDevice>,
+    challenge_cuda_funcs: &mut Option<CudaFuncs>,
+    algorithm_cuda_funcs: &mut Option<CudaFuncs>,
+) -> anyhow::Result<bool> {{
+    match challenge_id {{
+{arms}        _ => Ok(false),
+    }}
+}}
+",
+            arms = arms
+        );
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("algorithms.rs");
+    fs::write(dest_path, code).expect("Failed to write algorithms.rs");
+}
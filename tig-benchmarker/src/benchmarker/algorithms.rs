@@ -0,0 +1,23 @@
+// Native `solve_challenge` (and `cuda_solve_challenge`) lookup tables, generated by build.rs
+// from the `c00X_aYYY` re-exports in tig-algorithms. Only algorithms with their cargo feature
+// enabled are included. `filter_nonce` (and `cuda_filter_nonce`) returns whether the job's
+// algorithm, if compiled in, finds no valid solution for a nonce, so it can be skipped
+include!(concat!(env!("OUT_DIR"), "/algorithms.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_nonce_without_algorithm() {
+        assert!(!filter_nonce("c003", "c003_a999", [0; 8], &vec![50, 10]).unwrap());
+        assert!(!filter_nonce("c999", "c999_a001", [0; 8], &vec![50, 10]).unwrap());
+    }
+
+    // an instance that cannot be generated is an error rather than a panic
+    #[cfg(feature = "c003_a001")]
+    #[test]
+    fn test_filter_nonce_invalid_difficulty() {
+        assert!(filter_nonce("c003", "c003_a001", [0; 8], &vec![50]).is_err());
+    }
+}
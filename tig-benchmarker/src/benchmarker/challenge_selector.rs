@@ -0,0 +1,461 @@
+use super::{QueryData, Result};
+use rand::{
+    distributions::WeightedIndex,
+    prelude::{Distribution, SliceRandom},
+    rngs::StdRng,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, str::FromStr};
+use tig_protocol::calc_influence_weight;
+use tig_structs::core::*;
+use tig_utils::PreciseNumber;
+
+pub trait ChallengeSelector {
+    // picks which challenge in the algorithm selection to benchmark next
+    fn select<'a>(
+        &self,
+        rng: &mut StdRng,
+        query_data: &'a QueryData,
+        selected_algorithms: &HashMap<String, String>,
+    ) -> Result<&'a Challenge>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChallengeSelection {
+    #[default]
+    QualifierBalance,
+    MaxInfluence,
+}
+
+impl FromStr for ChallengeSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "qualifier_balance" => Ok(ChallengeSelection::QualifierBalance),
+            "max_influence" => Ok(ChallengeSelection::MaxInfluence),
+            _ => Err(format!(
+                "Unknown challenge selector '{}'. Expecting 'qualifier_balance' or 'max_influence'",
+                s
+            )),
+        }
+    }
+}
+
+impl ChallengeSelector for ChallengeSelection {
+    fn select<'a>(
+        &self,
+        rng: &mut StdRng,
+        query_data: &'a QueryData,
+        selected_algorithms: &HashMap<String, String>,
+    ) -> Result<&'a Challenge> {
+        match self {
+            ChallengeSelection::QualifierBalance => {
+                QualifierBalanceSelector.select(rng, query_data, selected_algorithms)
+            }
+            ChallengeSelection::MaxInfluence => {
+                MaxInfluenceSelector.select(rng, query_data, selected_algorithms)
+            }
+        }
+    }
+}
+
+// Favours challenges where the player has a smaller share of qualifiers
+pub struct QualifierBalanceSelector;
+
+impl ChallengeSelector for QualifierBalanceSelector {
+    fn select<'a>(
+        &self,
+        rng: &mut StdRng,
+        query_data: &'a QueryData,
+        selected_algorithms: &HashMap<String, String>,
+    ) -> Result<&'a Challenge> {
+        let QueryData {
+            player_data,
+            challenges,
+            ..
+        } = query_data;
+        let num_qualifiers_by_challenge = num_qualifiers_by_challenge(player_data);
+        let percent_qualifiers_by_challenge: HashMap<String, f64> = challenges
+            .iter()
+            .map(|c| {
+                let player_num_qualifiers = *num_qualifiers_by_challenge.get(&c.id).unwrap_or(&0);
+                let challenge_num_qualifiers = *c.block_data().num_qualifiers();
+                let percent = if player_num_qualifiers == 0 || challenge_num_qualifiers == 0 {
+                    0f64
+                } else {
+                    (player_num_qualifiers as f64) / (challenge_num_qualifiers as f64)
+                };
+                (c.id.clone(), percent)
+            })
+            .collect();
+        let max_percent_qualifiers = *percent_qualifiers_by_challenge
+            .values()
+            .max_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap();
+        let selected = selected_challenges(challenges, selected_algorithms)?;
+        let dist = WeightedIndex::new(
+            &selected
+                .iter()
+                .map(|c| {
+                    4.0 * max_percent_qualifiers / 3.0 - percent_qualifiers_by_challenge[&c.id]
+                        + 1e-10f64
+                })
+                .collect::<Vec<f64>>(),
+        )
+        .map_err(|e| format!("Failed to create WeightedIndex: {}", e))?;
+        Ok(selected[dist.sample(rng)])
+    }
+}
+
+// Picks the challenge where the qualifiers expected from one more benchmark give the player the
+// highest influence weight, as calculated by the protocol (including the imbalance penalty)
+pub struct MaxInfluenceSelector;
+
+impl ChallengeSelector for MaxInfluenceSelector {
+    fn select<'a>(
+        &self,
+        rng: &mut StdRng,
+        query_data: &'a QueryData,
+        selected_algorithms: &HashMap<String, String>,
+    ) -> Result<&'a Challenge> {
+        let QueryData {
+            latest_block,
+            player_data,
+            challenges,
+            benchmarks,
+            ..
+        } = query_data;
+        let num_qualifiers_by_challenge = num_qualifiers_by_challenge(player_data);
+        let imbalance_multiplier = latest_block
+            .config()
+            .optimisable_proof_of_work
+            .imbalance_multiplier;
+
+        // ties are broken randomly
+        let mut selected = selected_challenges(challenges, selected_algorithms)?;
+        selected.shuffle(rng);
+        let mut best: Option<(PreciseNumber, &Challenge)> = None;
+        for candidate in selected {
+            let expected_qualifiers = expected_qualifiers(benchmarks, &candidate.id);
+            let percent_qualifiers: Vec<PreciseNumber> = challenges
+                .iter()
+                .map(|c| {
+                    let mut player_num_qualifiers =
+                        *num_qualifiers_by_challenge.get(&c.id).unwrap_or(&0) as f64;
+                    let mut challenge_num_qualifiers = *c.block_data().num_qualifiers() as f64;
+                    if c.id == candidate.id {
+                        player_num_qualifiers += expected_qualifiers;
+                        challenge_num_qualifiers += expected_qualifiers;
+                    }
+                    if player_num_qualifiers == 0.0 {
+                        PreciseNumber::from(0)
+                    } else {
+                        PreciseNumber::from_f64(player_num_qualifiers)
+                            / PreciseNumber::from_f64(challenge_num_qualifiers)
+                    }
+                })
+                .collect();
+            let (_, _, weight) =
+                calc_influence_weight(&percent_qualifiers, challenges.len(), imbalance_multiplier);
+            if best.as_ref().map_or(true, |(w, _)| weight > *w) {
+                best = Some((weight, candidate));
+            }
+        }
+        Ok(best.unwrap().1)
+    }
+}
+
+// average number of solutions from the player's benchmarks of the challenge, or 1 if there are none
+fn expected_qualifiers(benchmarks: &HashMap<String, Benchmark>, challenge_id: &String) -> f64 {
+    let num_solutions: Vec<u32> = benchmarks
+        .values()
+        .filter(|b| b.settings.challenge_id == *challenge_id)
+        .map(|b| b.details.num_solutions)
+        .collect();
+    if num_solutions.is_empty() {
+        1.0
+    } else {
+        (num_solutions.iter().sum::<u32>() as f64 / num_solutions.len() as f64).max(1.0)
+    }
+}
+
+fn num_qualifiers_by_challenge(player_data: &Option<PlayerBlockData>) -> HashMap<String, u32> {
+    match player_data
+        .as_ref()
+        .map(|x| x.num_qualifiers_by_challenge.as_ref())
+    {
+        Some(Some(num_qualifiers_by_challenge)) => num_qualifiers_by_challenge.clone(),
+        _ => HashMap::new(),
+    }
+}
+
+fn selected_challenges<'a>(
+    challenges: &'a Vec<Challenge>,
+    selected_algorithms: &HashMap<String, String>,
+) -> Result<Vec<&'a Challenge>> {
+    if selected_algorithms.len() == 0 {
+        return Err("Your <algorithm_selection>.json is empty".to_string());
+    };
+    selected_algorithms
+        .keys()
+        .map(|challenge_name| {
+            challenges
+                .iter()
+                .find(|c| c.details.name == *challenge_name)
+                .ok_or_else(|| {
+                    format!(
+                        "Your <algorithm_selection>.json contains a non-existent challenge '{}'",
+                        challenge_name
+                    )
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use tig_utils::dejsonify;
+
+    const CHALLENGE_NAMES: [(&str, &str); 3] = [
+        ("c001", "satisfiability"),
+        ("c002", "vehicle_routing"),
+        ("c003", "knapsack"),
+    ];
+
+    // (challenge_id, player_num_qualifiers, challenge_num_qualifiers) for each challenge
+    fn query_data(num_qualifiers: &[(&str, u32, u32)]) -> QueryData {
+        QueryData {
+            latest_block: Block {
+                id: "block".to_string(),
+                details: BlockDetails {
+                    prev_block_id: "prev_block".to_string(),
+                    height: 200,
+                    round: 20,
+                    eth_block_num: None,
+                },
+                data: None,
+                // imbalance_multiplier is 3.0
+                config: Some(
+                    dejsonify(include_str!("../../../tig-server/example_config.json")).unwrap(),
+                ),
+            },
+            player_data: Some(PlayerBlockData {
+                num_qualifiers_by_challenge: Some(
+                    num_qualifiers
+                        .iter()
+                        .map(|&(id, n, _)| (id.to_string(), n))
+                        .collect(),
+                ),
+                cutoff: None,
+                deposit: None,
+                rolling_deposit: None,
+                imbalance: None,
+                imbalance_penalty: None,
+                influence: None,
+                reward: None,
+                round_earnings: None,
+                slashed_benchmark_ids: None,
+                slashed_reward: None,
+            }),
+            challenges: num_qualifiers
+                .iter()
+                .map(|&(id, _, n)| Challenge {
+                    id: id.to_string(),
+                    details: ChallengeDetails {
+                        name: CHALLENGE_NAMES
+                            .iter()
+                            .find(|x| x.0 == id)
+                            .unwrap()
+                            .1
+                            .to_string(),
+                    },
+                    state: None,
+                    block_data: Some(ChallengeBlockData {
+                        solution_signature_threshold: None,
+                        num_qualifiers: Some(n),
+                        qualifier_difficulties: None,
+                        base_frontier: None,
+                        cutoff_frontier: None,
+                        scaled_frontier: None,
+                        scaling_factor: None,
+                    }),
+                })
+                .collect(),
+            download_urls: HashMap::new(),
+            algorithms_by_challenge: HashMap::new(),
+            benchmarks: HashMap::new(),
+            proofs: HashMap::new(),
+            frauds: HashMap::new(),
+        }
+    }
+
+    fn benchmark(id: &str, challenge_id: &str, num_solutions: u32) -> Benchmark {
+        Benchmark {
+            id: id.to_string(),
+            settings: BenchmarkSettings {
+                player_id: "0x0000000000000000000000000000000000000001".to_string(),
+                block_id: "block".to_string(),
+                challenge_id: challenge_id.to_string(),
+                algorithm_id: format!("{}_a001", challenge_id),
+                difficulty: vec![50, 300],
+            },
+            details: BenchmarkDetails {
+                block_started: 200,
+                num_solutions,
+            },
+            state: None,
+            solutions_meta_data: None,
+            solution_data: None,
+        }
+    }
+
+    fn select_all(query_data: &QueryData) -> HashMap<String, String> {
+        query_data
+            .challenges
+            .iter()
+            .map(|c| (c.details.name.clone(), "algorithm".to_string()))
+            .collect()
+    }
+
+    fn count_selections(
+        selection: ChallengeSelection,
+        query_data: &QueryData,
+        selected_algorithms: &HashMap<String, String>,
+        num_samples: u32,
+    ) -> HashMap<String, u32> {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut counts = HashMap::new();
+        for _ in 0..num_samples {
+            let challenge = selection
+                .select(&mut rng, query_data, selected_algorithms)
+                .unwrap();
+            *counts.entry(challenge.id.clone()).or_default() += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "qualifier_balance".parse::<ChallengeSelection>(),
+            Ok(ChallengeSelection::QualifierBalance)
+        );
+        assert_eq!(
+            "max_influence".parse::<ChallengeSelection>(),
+            Ok(ChallengeSelection::MaxInfluence)
+        );
+        assert!("random".parse::<ChallengeSelection>().is_err());
+    }
+
+    #[test]
+    fn test_qualifier_balance_favours_smaller_share() {
+        let query_data = query_data(&[("c001", 10, 100), ("c002", 30, 100), ("c003", 0, 100)]);
+        // weights are 4/3 * max share - share, i.e. 0.3, 0.1 & 0.4
+        let counts = count_selections(
+            ChallengeSelection::QualifierBalance,
+            &query_data,
+            &select_all(&query_data),
+            8000,
+        );
+        for (id, expected) in [("c001", 3000.0), ("c002", 1000.0), ("c003", 4000.0)] {
+            let count = counts[id] as f64;
+            assert!(
+                (count - expected).abs() < 0.1 * expected,
+                "{} selected {} times, expected ~{}",
+                id,
+                count,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_qualifier_balance_only_selects_from_algorithm_selection() {
+        let query_data = query_data(&[("c001", 10, 100), ("c002", 30, 100), ("c003", 0, 100)]);
+        let selected_algorithms =
+            HashMap::from([("vehicle_routing".to_string(), "algorithm".to_string())]);
+        let counts = count_selections(
+            ChallengeSelection::QualifierBalance,
+            &query_data,
+            &selected_algorithms,
+            100,
+        );
+        assert_eq!(counts, HashMap::from([("c002".to_string(), 100)]));
+    }
+
+    #[test]
+    fn test_max_influence_picks_least_balanced_challenge() {
+        let query_data = query_data(&[("c001", 10, 100), ("c002", 30, 100), ("c003", 0, 100)]);
+        let counts = count_selections(
+            ChallengeSelection::MaxInfluence,
+            &query_data,
+            &select_all(&query_data),
+            100,
+        );
+        assert_eq!(counts, HashMap::from([("c003".to_string(), 100)]));
+
+        // without c003, adding qualifiers to c001 improves balance more than to c002
+        let selected_algorithms = HashMap::from([
+            ("satisfiability".to_string(), "algorithm".to_string()),
+            ("vehicle_routing".to_string(), "algorithm".to_string()),
+        ]);
+        let counts = count_selections(
+            ChallengeSelection::MaxInfluence,
+            &query_data,
+            &selected_algorithms,
+            100,
+        );
+        assert_eq!(counts, HashMap::from([("c001".to_string(), 100)]));
+    }
+
+    #[test]
+    fn test_max_influence_uses_expected_qualifiers() {
+        let mut query_data = query_data(&[("c001", 10, 100), ("c002", 10, 100)]);
+        let selected_algorithms = select_all(&query_data);
+        // equal shares & 1 expected qualifier each is a tie, which is broken randomly
+        let counts = count_selections(
+            ChallengeSelection::MaxInfluence,
+            &query_data,
+            &selected_algorithms,
+            100,
+        );
+        assert_eq!(counts.len(), 2);
+
+        // 50 expected qualifiers on c001 would make the player's qualifiers imbalanced
+        for (id, num_solutions) in [("b1", 40), ("b2", 60)] {
+            query_data
+                .benchmarks
+                .insert(id.to_string(), benchmark(id, "c001", num_solutions));
+        }
+        let counts = count_selections(
+            ChallengeSelection::MaxInfluence,
+            &query_data,
+            &selected_algorithms,
+            100,
+        );
+        assert_eq!(counts, HashMap::from([("c002".to_string(), 100)]));
+    }
+
+    #[test]
+    fn test_invalid_algorithm_selection() {
+        let query_data = query_data(&[("c001", 10, 100)]);
+        let mut rng = StdRng::seed_from_u64(0);
+        for selection in [
+            ChallengeSelection::QualifierBalance,
+            ChallengeSelection::MaxInfluence,
+        ] {
+            assert!(selection
+                .select(&mut rng, &query_data, &HashMap::new())
+                .is_err());
+            let selected_algorithms =
+                HashMap::from([("knapsack".to_string(), "algorithm".to_string())]);
+            assert!(selection
+                .select(&mut rng, &query_data, &selected_algorithms)
+                .is_err());
+        }
+    }
+}
@@ -3,21 +3,28 @@ use crate::future_utils::Mutex;
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
 use tig_utils::get;
+use tig_worker::WasmExecutor;
 
-static CACHE: OnceCell<Mutex<HashMap<String, Vec<u8>>>> = OnceCell::new();
+static CACHE: OnceCell<Mutex<HashMap<String, WasmExecutor>>> = OnceCell::new();
 
-pub async fn execute(job: &Job) -> Result<Vec<u8>> {
+pub async fn execute(job: &Job) -> Result<WasmExecutor> {
     let mut cache = CACHE
         .get_or_init(|| Mutex::new(HashMap::new()))
         .lock()
         .await;
-    if let Some(wasm_blob) = cache.get(&job.settings.algorithm_id) {
-        Ok(wasm_blob.clone())
+    if let Some(executor) = cache.get(&job.settings.algorithm_id) {
+        Ok(executor.clone())
     } else {
         let wasm = get::<Vec<u8>>(&job.download_url, None)
             .await
             .map_err(|e| format!("Failed to download wasm from {}: {:?}", job.download_url, e))?;
-        (*cache).insert(job.settings.algorithm_id.clone(), wasm.clone());
-        Ok(wasm)
+        let executor = WasmExecutor::new(&wasm).map_err(|e| {
+            format!(
+                "Failed to compile wasm for {}: {:?}",
+                job.settings.algorithm_id, e
+            )
+        })?;
+        (*cache).insert(job.settings.algorithm_id.clone(), executor.clone());
+        Ok(executor)
     }
 }
@@ -0,0 +1,651 @@
+use super::difficulty_sampler::DifficultyStrategy;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    rngs::StdRng,
+};
+use serde::{Deserialize, Serialize};
+use tig_structs::core::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "GridConfigFields")]
+pub struct GridConfig {
+    pub padding_factor: f32,
+    pub decay: f32,
+    pub initial_solutions_weight: f32,
+    pub solutions_multiplier: f32,
+}
+
+// unvalidated GridConfig, as read from a strategies file or snapshot
+#[derive(Deserialize)]
+#[serde(default)]
+struct GridConfigFields {
+    padding_factor: f32,
+    decay: f32,
+    initial_solutions_weight: f32,
+    solutions_multiplier: f32,
+}
+
+impl Default for GridConfigFields {
+    fn default() -> Self {
+        let GridConfig {
+            padding_factor,
+            decay,
+            initial_solutions_weight,
+            solutions_multiplier,
+        } = GridConfig::default();
+        Self {
+            padding_factor,
+            decay,
+            initial_solutions_weight,
+            solutions_multiplier,
+        }
+    }
+}
+
+impl TryFrom<GridConfigFields> for GridConfig {
+    type Error = String;
+
+    fn try_from(fields: GridConfigFields) -> Result<Self, Self::Error> {
+        // a cell with no solutions weight can never be sampled
+        if !(fields.initial_solutions_weight.is_finite() && fields.initial_solutions_weight > 0.0) {
+            return Err(format!(
+                "initial_solutions_weight must be positive, got {}",
+                fields.initial_solutions_weight
+            ));
+        }
+        for (name, value) in [
+            ("padding_factor", fields.padding_factor),
+            ("solutions_multiplier", fields.solutions_multiplier),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(format!("{} must not be negative, got {}", name, value));
+            }
+        }
+        if !(0.0..=1.0).contains(&fields.decay) {
+            return Err(format!(
+                "decay must be between 0 and 1, got {}",
+                fields.decay
+            ));
+        }
+        Ok(Self {
+            padding_factor: fields.padding_factor,
+            decay: fields.decay,
+            initial_solutions_weight: fields.initial_solutions_weight,
+            solutions_multiplier: fields.solutions_multiplier,
+        })
+    }
+}
+
+impl Default for GridConfig {
+    fn default() -> Self {
+        Self {
+            padding_factor: 0.2,
+            decay: 0.7,
+            initial_solutions_weight: 500.0,
+            solutions_multiplier: 10.0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Weights {
+    pub qualifier: f32,
+    pub solutions: f32,
+    pub within_range: bool,
+}
+
+impl Weights {
+    pub fn new(initial_solutions_weight: f32) -> Self {
+        Self {
+            qualifier: 1.0,
+            solutions: initial_solutions_weight,
+            within_range: false,
+        }
+    }
+}
+
+// Weights are a grid over the difficulty space, starting at min_difficulty. `weights[x]` holds the
+// cells whose first parameter is x, with the remaining parameters flattened in row-major order
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GridSampler {
+    pub config: GridConfig,
+    pub min_difficulty: Vec<i32>,
+    pub padding: Vec<usize>,
+    pub dimensions: Vec<usize>,
+    pub weights: Vec<Vec<Weights>>,
+    // rebuilt by update_with_block_data
+    #[serde(skip)]
+    pub distribution: Option<WeightedIndex<f32>>,
+}
+
+impl GridSampler {
+    pub fn new(config: GridConfig) -> Self {
+        Self {
+            config,
+            min_difficulty: Vec::new(),
+            padding: Vec::new(),
+            dimensions: Vec::new(),
+            weights: Vec::new(),
+            distribution: None,
+        }
+    }
+
+    fn size(&self) -> Vec<usize> {
+        self.dimensions
+            .iter()
+            .zip(self.padding.iter())
+            .map(|(d, p)| d + p)
+            .collect()
+    }
+
+    // converts the index of a cell within `weights[x]` into its offsets from min_difficulty
+    fn cell_offsets(size: &[usize], x: usize, mut idx: usize) -> Vec<usize> {
+        let mut offsets = vec![0; size.len()];
+        offsets[0] = x;
+        for i in (1..size.len()).rev() {
+            offsets[i] = idx % size[i];
+            idx /= size[i];
+        }
+        offsets
+    }
+
+    // converts offsets from min_difficulty into the index of the cell within `weights[offsets[0]]`
+    fn cell_idx(size: &[usize], offsets: &[usize]) -> usize {
+        (1..size.len()).fold(0, |idx, i| idx * size[i] + offsets[i])
+    }
+
+    fn cell_difficulty(&self, size: &[usize], x: usize, idx: usize) -> Vec<i32> {
+        Self::cell_offsets(size, x, idx)
+            .into_iter()
+            .zip(self.min_difficulty.iter())
+            .map(|(offset, min)| offset as i32 + min)
+            .collect()
+    }
+
+    fn update_valid_range(&mut self, block_data: &ChallengeBlockData) {
+        let (mut lower_frontier, mut upper_frontier) =
+            (block_data.base_frontier(), block_data.scaled_frontier());
+        if *block_data.scaling_factor() < 1.0 {
+            (lower_frontier, upper_frontier) = (upper_frontier, lower_frontier);
+        }
+        let size = self.size();
+        for x in 0..self.weights.len() {
+            for idx in 0..self.weights[x].len() {
+                // difficulties harder than a point on the upper frontier are rejected by the
+                // protocol as above it
+                let difficulty = self.cell_difficulty(&size, x, idx);
+                self.weights[x][idx].within_range = lower_frontier
+                    .iter()
+                    .any(|p| is_at_least_as_hard(&difficulty, p))
+                    && !upper_frontier
+                        .iter()
+                        .any(|p| *p != difficulty && is_at_least_as_hard(&difficulty, p));
+            }
+        }
+    }
+
+    fn update_distributions(&mut self) {
+        let mut distribution = Vec::<f32>::new();
+        let mut fallback = Vec::<f32>::new();
+        for row in self.weights.iter() {
+            for w in row.iter() {
+                distribution.push(if w.within_range {
+                    w.qualifier * w.solutions
+                } else {
+                    0.0
+                });
+                fallback.push(w.qualifier * w.solutions);
+            }
+        }
+        // no cell may be within range of the frontiers, or every weight may have decayed to zero
+        self.distribution = WeightedIndex::new(&distribution)
+            .or_else(|_| WeightedIndex::new(&fallback))
+            .or_else(|_| WeightedIndex::new(vec![1.0; fallback.len()]))
+            .ok();
+    }
+
+    fn update_qualifier_weights(&mut self, block_data: &ChallengeBlockData) {
+        let cutoff_frontier = block_data.cutoff_frontier();
+        let size = self.size();
+        for x in 0..self.weights.len() {
+            for idx in 0..self.weights[x].len() {
+                // every point is a qualifier if there is no cutoff
+                let is_qualifier = cutoff_frontier.is_empty() || {
+                    let difficulty = self.cell_difficulty(&size, x, idx);
+                    cutoff_frontier
+                        .iter()
+                        .any(|p| is_at_least_as_hard(&difficulty, p))
+                };
+                let w = &mut self.weights[x][idx];
+                w.qualifier *= 0.9;
+                if is_qualifier {
+                    w.qualifier += 0.1;
+                }
+            }
+        }
+    }
+
+    fn resize_weights(&mut self, left_pad: &[i32], prev_size: &[usize], size: &[usize]) {
+        let num_cells = size[1..].iter().product::<usize>();
+        let mut weights =
+            vec![vec![Weights::new(self.config.initial_solutions_weight); num_cells]; size[0]];
+        for (x, row) in weights.iter_mut().enumerate() {
+            for (idx, w) in row.iter_mut().enumerate() {
+                // cells that were previously in the grid keep their weights
+                let prev_offsets: Option<Vec<usize>> = Self::cell_offsets(size, x, idx)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, offset)| {
+                        let prev_offset = offset as i32 - left_pad[i];
+                        (prev_offset >= 0 && (prev_offset as usize) < prev_size[i])
+                            .then_some(prev_offset as usize)
+                    })
+                    .collect();
+                if let Some(prev_w) = prev_offsets.and_then(|offsets| {
+                    self.weights
+                        .get(offsets[0])
+                        .and_then(|row| row.get(Self::cell_idx(prev_size, &offsets)))
+                }) {
+                    *w = prev_w.clone();
+                }
+            }
+        }
+        self.weights = weights;
+    }
+
+    fn update_dimensions_and_padding(&mut self, block_data: &ChallengeBlockData) {
+        let hardest_difficulty: Vec<i32> = (0..self.min_difficulty.len())
+            .map(|i| {
+                let v2 = block_data
+                    .scaled_frontier()
+                    .iter()
+                    .map(|x| x[i])
+                    .max()
+                    .unwrap();
+                let v3 = block_data
+                    .base_frontier()
+                    .iter()
+                    .map(|x| x[i])
+                    .max()
+                    .unwrap();
+                match block_data
+                    .qualifier_difficulties()
+                    .iter()
+                    .map(|x| x[i])
+                    .max()
+                {
+                    Some(v1) => v1.max(v2).max(v3),
+                    None => v2.max(v3),
+                }
+            })
+            .collect();
+        self.dimensions = (0..self.min_difficulty.len())
+            .map(|i| (hardest_difficulty[i] - self.min_difficulty[i] + 1) as usize)
+            .collect();
+        self.padding = self
+            .dimensions
+            .iter()
+            .map(|x| (*x as f32 * self.config.padding_factor).ceil() as usize)
+            .collect();
+    }
+}
+
+// whether difficulty `a` is at least as hard as `b` in every parameter
+fn is_at_least_as_hard(a: &[i32], b: &[i32]) -> bool {
+    a.iter().zip(b.iter()).all(|(a, b)| a >= b)
+}
+
+impl DifficultyStrategy for GridSampler {
+    fn sample(&self, rng: &mut StdRng) -> Vec<i32> {
+        // samples an index from the distribution
+        let idx = self
+            .distribution
+            .clone()
+            .expect("You must update sampler first")
+            .sample(rng);
+
+        // convert index into difficulty
+        let size = self.size();
+        let num_cells = size[1..].iter().product::<usize>();
+        self.cell_difficulty(&size, idx / num_cells, idx % num_cells)
+    }
+
+    fn update_with_block_data(
+        &mut self,
+        min_difficulty: &Vec<i32>,
+        block_data: &ChallengeBlockData,
+    ) {
+        // the grid starts afresh if the number of difficulty parameters changes
+        if self.min_difficulty.len() != min_difficulty.len() {
+            self.min_difficulty = min_difficulty.clone();
+            self.dimensions = vec![0; min_difficulty.len()];
+            self.padding = vec![0; min_difficulty.len()];
+            self.weights.clear();
+        }
+        let left_pad: Vec<i32> = (0..min_difficulty.len())
+            .map(|i| self.min_difficulty[i] - min_difficulty[i])
+            .collect();
+        let prev_size = self.size();
+        self.min_difficulty = min_difficulty.clone();
+        self.update_dimensions_and_padding(block_data);
+        let size = self.size();
+        self.resize_weights(&left_pad, &prev_size, &size);
+
+        self.update_qualifier_weights(block_data);
+        self.update_valid_range(block_data);
+        self.update_distributions();
+    }
+
+    fn update_with_solutions(&mut self, difficulty: &Vec<i32>, num_solutions: u32) {
+        let size = self.size();
+        let offsets: Vec<i64> = difficulty
+            .iter()
+            .zip(self.min_difficulty.iter())
+            .map(|(d, min)| (d - min) as i64)
+            .collect();
+        // visits every combination of distances within the padding of each parameter
+        let num_combinations = self.padding.iter().product::<usize>();
+        for combination in 0..num_combinations {
+            let mut distances = vec![0; size.len()];
+            let mut c = combination;
+            for i in (0..size.len()).rev() {
+                distances[i] = c % self.padding[i];
+                c /= self.padding[i];
+            }
+            let dist = distances
+                .iter()
+                .zip(self.padding.iter())
+                .map(|(d, p)| (*d as f32 / *p as f32).powf(2.0))
+                .sum::<f32>()
+                .sqrt();
+            if dist > 1.0 {
+                continue;
+            }
+            let decay = dist * (1.0 - self.config.decay) + self.config.decay;
+            let delta = (1.0 - decay) * num_solutions as f32 * self.config.solutions_multiplier;
+
+            // the cells at these distances on either side of the difficulty
+            let non_zero: Vec<usize> = (0..size.len()).filter(|&i| distances[i] != 0).collect();
+            for signs in 0..(1usize << non_zero.len()) {
+                let mut cell = offsets.clone();
+                for (j, &i) in non_zero.iter().enumerate() {
+                    if signs & (1 << j) == 0 {
+                        cell[i] += distances[i] as i64;
+                    } else {
+                        cell[i] -= distances[i] as i64;
+                    }
+                }
+                if cell
+                    .iter()
+                    .zip(size.iter())
+                    .any(|(&c, &s)| c < 0 || c >= s as i64)
+                {
+                    continue;
+                }
+                let cell: Vec<usize> = cell.into_iter().map(|c| c as usize).collect();
+                let w = &mut self.weights[cell[0]][Self::cell_idx(&size, &cell)];
+                w.solutions *= decay;
+                w.solutions += delta;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+    use tig_utils::{Frontier, FrontierOps};
+
+    fn block_data(
+        base_frontier: Vec<Vec<i32>>,
+        scaled_frontier: Vec<Vec<i32>>,
+    ) -> ChallengeBlockData {
+        ChallengeBlockData {
+            solution_signature_threshold: None,
+            num_qualifiers: None,
+            qualifier_difficulties: Some(Default::default()),
+            base_frontier: Some(base_frontier.into_iter().collect()),
+            cutoff_frontier: Some(Default::default()),
+            scaled_frontier: Some(scaled_frontier.into_iter().collect()),
+            scaling_factor: Some(1.5),
+        }
+    }
+
+    #[test]
+    fn test_config_defaults_missing_fields() {
+        let config: GridConfig = serde_json::from_str(r#"{"decay": 0.5}"#).unwrap();
+        assert_eq!(
+            config,
+            GridConfig {
+                decay: 0.5,
+                ..GridConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        for json in [
+            r#"{"initial_solutions_weight": 0}"#,
+            r#"{"padding_factor": -0.1}"#,
+            r#"{"solutions_multiplier": -1}"#,
+            r#"{"decay": 1.5}"#,
+            r#"{"decay": -0.1}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<GridConfig>(json).is_err(),
+                "{} should be rejected",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn test_sample_within_frontiers() {
+        let mut sampler = GridSampler::new(GridConfig::default());
+        let base_frontier = vec![vec![10, 20], vec![20, 10]];
+        let scaled_frontier = vec![vec![15, 30], vec![30, 15]];
+        sampler.update_with_block_data(
+            &vec![5, 5],
+            &block_data(base_frontier.clone(), scaled_frontier.clone()),
+        );
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let difficulty = sampler.sample(&mut rng);
+            assert!(base_frontier
+                .iter()
+                .any(|p| is_at_least_as_hard(&difficulty, p)));
+            assert!(!scaled_frontier
+                .iter()
+                .any(|p| *p != difficulty && is_at_least_as_hard(&difficulty, p)));
+        }
+    }
+
+    #[test]
+    fn test_sample_without_cells_in_range() {
+        // the scaled frontier is below the base frontier despite the scaling factor
+        let mut sampler = GridSampler::new(GridConfig::default());
+        sampler.update_with_block_data(
+            &vec![0, 0],
+            &block_data(vec![vec![10, 10]], vec![vec![5, 5]]),
+        );
+        assert!(sampler.weights.iter().flatten().all(|w| !w.within_range));
+        let mut rng = StdRng::seed_from_u64(0);
+        let difficulty = sampler.sample(&mut rng);
+        let size = sampler.size();
+        assert!((0..2).all(|i| difficulty[i] >= 0 && difficulty[i] < size[i] as i32));
+    }
+
+    #[test]
+    fn test_sample_favours_cells_with_solutions() {
+        let mut sampler = GridSampler::new(GridConfig::default());
+        let data = block_data(vec![vec![10, 10]], vec![vec![11, 11]]);
+        sampler.update_with_block_data(&vec![10, 10], &data);
+        for _ in 0..10 {
+            sampler.update_with_solutions(&vec![11, 11], 1000);
+        }
+        sampler.update_with_block_data(&vec![10, 10], &data);
+        let mut rng = StdRng::seed_from_u64(0);
+        let num_hardest = (0..100)
+            .filter(|_| sampler.sample(&mut rng) == vec![11, 11])
+            .count();
+        assert!(
+            num_hardest > 75,
+            "sampled hardest cell {} times",
+            num_hardest
+        );
+    }
+    // the 2D implementation of update_valid_range that the grid sampler started from
+    fn baseline_valid_range(
+        min_difficulty: &[i32],
+        size: &[usize],
+        block_data: &ChallengeBlockData,
+    ) -> Vec<Vec<bool>> {
+        let to_cells = |frontier: &Frontier| {
+            let mut points: Vec<Vec<usize>> = frontier
+                .iter()
+                .map(|x| {
+                    vec![
+                        (x[0] - min_difficulty[0]) as usize,
+                        (x[1] - min_difficulty[1]) as usize,
+                    ]
+                })
+                .collect();
+            points.sort_by(|a, b| a[0].cmp(&b[0]));
+            points
+        };
+        let mut lower_cutoff_points = to_cells(block_data.base_frontier());
+        let mut upper_cutoff_points = to_cells(block_data.scaled_frontier());
+        if *block_data.scaling_factor() < 1.0 {
+            (lower_cutoff_points, upper_cutoff_points) = (upper_cutoff_points, lower_cutoff_points);
+        }
+        let mut within_range = vec![vec![false; size[1]]; size[0]];
+        let mut lower_cutoff_idx = 0;
+        let mut lower_cutoff = lower_cutoff_points[0].clone();
+        let mut upper_cutoff_idx = 0;
+        let mut upper_cutoff1 = upper_cutoff_points[0].clone();
+        let mut upper_cutoff2 = upper_cutoff_points.get(1).unwrap_or(&upper_cutoff1).clone();
+        for (i, row) in within_range.iter_mut().enumerate() {
+            if lower_cutoff_idx + 1 < lower_cutoff_points.len()
+                && i == lower_cutoff_points[lower_cutoff_idx + 1][0]
+            {
+                lower_cutoff = lower_cutoff_points[lower_cutoff_idx + 1].clone();
+                lower_cutoff_idx += 1;
+            }
+            if upper_cutoff_idx + 1 < upper_cutoff_points.len()
+                && i == upper_cutoff_points[upper_cutoff_idx + 1][0]
+            {
+                upper_cutoff1 = upper_cutoff_points[upper_cutoff_idx + 1].clone();
+                upper_cutoff2 = upper_cutoff_points
+                    .get(upper_cutoff_idx + 2)
+                    .unwrap_or(&upper_cutoff1)
+                    .clone();
+                upper_cutoff_idx += 1;
+            }
+            for (j, w) in row.iter_mut().enumerate() {
+                let within_lower =
+                    j > lower_cutoff[1] || (j == lower_cutoff[1] && i >= lower_cutoff[0]);
+                let within_upper = (j <= upper_cutoff2[1] && i <= upper_cutoff2[0])
+                    || (j < upper_cutoff1[1] && i < upper_cutoff2[0])
+                    || (j == upper_cutoff1[1] && i == upper_cutoff1[0]);
+                *w = within_lower && within_upper;
+            }
+        }
+        within_range
+    }
+
+    // frontiers as add_block computes them, from a random set of qualifiers
+    fn frontiers_block_data(
+        min_difficulty: &Vec<i32>,
+        max_difficulty: &Vec<i32>,
+        qualifiers: Vec<Vec<i32>>,
+        scaling_factor: f64,
+    ) -> ChallengeBlockData {
+        let base_frontier = qualifiers
+            .into_iter()
+            .map(|d| {
+                d.iter()
+                    .zip(min_difficulty)
+                    .map(|(x, min)| x + min)
+                    .collect()
+            })
+            .collect::<Frontier>()
+            .pareto_frontier()
+            .extend(min_difficulty, max_difficulty);
+        let scaled_frontier = base_frontier
+            .scale(min_difficulty, max_difficulty, scaling_factor)
+            .extend(min_difficulty, max_difficulty);
+        ChallengeBlockData {
+            solution_signature_threshold: None,
+            num_qualifiers: None,
+            qualifier_difficulties: Some(Default::default()),
+            base_frontier: Some(base_frontier),
+            cutoff_frontier: Some(Default::default()),
+            scaled_frontier: Some(scaled_frontier),
+            scaling_factor: Some(scaling_factor),
+        }
+    }
+
+    fn qualifiers(num_dimensions: usize, max_offset: i32) -> impl Strategy<Value = Vec<Vec<i32>>> {
+        prop::collection::vec(prop::collection::vec(0..max_offset, num_dimensions), 1..8)
+    }
+
+    proptest! {
+        #[test]
+        fn test_cell_offsets_round_trip(
+            (size, x, idx) in prop::collection::vec(1..6usize, 1..=4).prop_flat_map(|size| {
+                let num_cells = size[1..].iter().product::<usize>();
+                (Just(size.clone()), 0..size[0], 0..num_cells)
+            })
+        ) {
+            let offsets = GridSampler::cell_offsets(&size, x, idx);
+            prop_assert_eq!(offsets[0], x);
+            prop_assert!(offsets.iter().zip(size.iter()).all(|(o, s)| o < s));
+            prop_assert_eq!(GridSampler::cell_idx(&size, &offsets), idx);
+        }
+
+        #[test]
+        fn test_sample_within_grid(
+            (min_difficulty, qualifiers) in (1..=4usize).prop_flat_map(|n| {
+                (prop::collection::vec(0..100i32, n), qualifiers(n, 8))
+            }),
+            scaling_factor in 0.5..2.0f64,
+            seed: u64,
+        ) {
+            let max_difficulty: Vec<i32> = min_difficulty.iter().map(|x| x + 10).collect();
+            let data = frontiers_block_data(&min_difficulty, &max_difficulty, qualifiers, scaling_factor);
+            let mut sampler = GridSampler::new(GridConfig::default());
+            sampler.update_with_block_data(&min_difficulty, &data);
+            let size = sampler.size();
+            let mut rng = StdRng::seed_from_u64(seed);
+            for _ in 0..20 {
+                let difficulty = sampler.sample(&mut rng);
+                prop_assert_eq!(difficulty.len(), min_difficulty.len());
+                for i in 0..difficulty.len() {
+                    prop_assert!(difficulty[i] >= min_difficulty[i]);
+                    prop_assert!(difficulty[i] < min_difficulty[i] + size[i] as i32);
+                }
+            }
+        }
+
+        #[test]
+        fn test_valid_range_matches_baseline_2d(
+            min_difficulty in prop::collection::vec(0..100i32, 2),
+            qualifiers in qualifiers(2, 30),
+            scaling_factor in 0.5..2.0f64,
+        ) {
+            // the baseline expects a staircase, which scaling only breaks if it clamps to max_difficulty
+            let max_difficulty: Vec<i32> = min_difficulty.iter().map(|x| x + 100).collect();
+            let data = frontiers_block_data(&min_difficulty, &max_difficulty, qualifiers, scaling_factor);
+            let mut sampler = GridSampler::new(GridConfig::default());
+            sampler.update_with_block_data(&min_difficulty, &data);
+            let size = sampler.size();
+            let within_range: Vec<Vec<bool>> = (0..size[0])
+                .map(|x| sampler.weights[x].iter().map(|w| w.within_range).collect())
+                .collect();
+            prop_assert_eq!(within_range, baseline_valid_range(&min_difficulty, &size, &data));
+        }
+    }
+}
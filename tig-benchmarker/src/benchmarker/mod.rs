@@ -1,10 +1,16 @@
-mod difficulty_sampler;
+mod algorithms;
+pub mod challenge_selector;
+pub mod difficulty_sampler;
 pub mod download_wasm;
 mod find_proof_to_submit;
+mod grid_sampler;
+#[cfg(feature = "standalone")]
+pub mod persistence;
 mod query_data;
 mod setup_job;
 mod submit_benchmark;
 mod submit_proof;
+mod thompson_sampler;
 
 #[cfg(not(feature = "cuda"))]
 pub mod run_benchmark;
@@ -13,7 +19,8 @@ pub mod run_benchmark;
 pub mod run_benchmark;
 
 use crate::future_utils::{sleep, spawn, time, Mutex};
-use difficulty_sampler::DifficultySampler;
+use challenge_selector::ChallengeSelection;
+use difficulty_sampler::{DifficultySampler, DifficultyStrategy, DifficultyStrategyConfig};
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::Arc};
@@ -138,6 +145,8 @@ pub struct State {
     pub timer: Option<Timer>,
     pub query_data: QueryData,
     pub selected_algorithms: HashMap<String, String>,
+    pub difficulty_strategies: HashMap<String, DifficultyStrategyConfig>,
+    pub challenge_selector: ChallengeSelection,
     pub job: Option<Job>,
     pub submission_errors: HashMap<String, String>,
     #[serde(skip_serializing)]
@@ -177,8 +186,6 @@ async fn run_once(num_workers: u32, ms_per_benchmark: u32) -> Result<()> {
         state.timer = None;
     }
     update_status("Querying latest data").await;
-    // retain only benchmarks that are within the lifespan period
-    // preserves solution_meta_data and solution_data
     let mut new_query_data = query_data::execute().await?;
     if {
         let state = (*state()).lock().await;
@@ -186,52 +193,21 @@ async fn run_once(num_workers: u32, ms_per_benchmark: u32) -> Result<()> {
     } {
         {
             let mut state = (*state()).lock().await;
-            let block_started_cutoff = new_query_data.latest_block.details.height.saturating_sub(
-                new_query_data
-                    .latest_block
-                    .config()
-                    .benchmark_submissions
-                    .lifespan_period,
+            merge_benchmarks(
+                &mut new_query_data,
+                state.query_data.benchmarks.clone(),
+                state.query_data.proofs.clone(),
+                state.query_data.frauds.clone(),
             );
-            let mut latest_benchmarks = state.query_data.benchmarks.clone();
-            latest_benchmarks.retain(|_, x| x.details.block_started >= block_started_cutoff);
-            latest_benchmarks.extend(new_query_data.benchmarks.drain());
-
-            let mut latest_proofs = state.query_data.proofs.clone();
-            latest_proofs.retain(|id, _| latest_benchmarks.contains_key(id));
-            latest_proofs.extend(new_query_data.proofs.drain());
-
-            let mut latest_frauds = state.query_data.frauds.clone();
-            latest_frauds.retain(|id, _| latest_benchmarks.contains_key(id));
-            latest_frauds.extend(new_query_data.frauds.drain());
-
+            let benchmarks = &new_query_data.benchmarks;
             (*state)
                 .submission_errors
-                .retain(|id, _| latest_benchmarks.contains_key(id));
-            new_query_data.benchmarks = latest_benchmarks;
-            new_query_data.proofs = latest_proofs;
-            new_query_data.frauds = latest_frauds;
+                .retain(|id, _| benchmarks.contains_key(id));
             (*state).query_data = new_query_data;
         }
 
         update_status("Updating difficulty sampler with query data").await;
-        {
-            let mut state = state().lock().await;
-            let State {
-                query_data,
-                difficulty_samplers,
-                ..
-            } = &mut (*state);
-            for challenge in query_data.challenges.iter() {
-                let difficulty_sampler = difficulty_samplers
-                    .entry(challenge.id.clone())
-                    .or_insert_with(|| DifficultySampler::new());
-                let min_difficulty = query_data.latest_block.config().difficulty.parameters
-                    [&challenge.id]
-                    .min_difficulty();
-                difficulty_sampler.update_with_block_data(&min_difficulty, challenge.block_data());
-            }
-        }
+        update_difficulty_samplers(&mut *state().lock().await, None);
     }
 
     update_status("Finding proof to submit").await;
@@ -263,7 +239,7 @@ async fn run_once(num_workers: u32, ms_per_benchmark: u32) -> Result<()> {
         job.download_url.split("/").last().unwrap()
     ))
     .await;
-    let wasm = download_wasm::execute(&job).await?;
+    let executor = download_wasm::execute(&job).await?;
 
     // variables that are shared by workers
     let nonce_iters = match &job.sampled_nonces {
@@ -285,11 +261,11 @@ async fn run_once(num_workers: u32, ms_per_benchmark: u32) -> Result<()> {
     run_benchmark::execute(
         nonce_iters.iter().cloned().collect(),
         &job,
-        &wasm,
+        &executor,
         solutions_data.clone(),
         solutions_count.clone(),
     )
-    .await;
+    .await?;
     {
         let mut state = state().lock().await;
         (*state).timer = Some(Timer::new(ms_per_benchmark as u64));
@@ -396,6 +372,64 @@ async fn run_once(num_workers: u32, ms_per_benchmark: u32) -> Result<()> {
     Ok(())
 }
 
+// updates the sampler of every challenge (or only `challenge_name`) with the latest block data,
+// replacing samplers whose strategy no longer matches the configured one
+fn update_difficulty_samplers(state: &mut State, challenge_name: Option<&String>) {
+    let State {
+        query_data,
+        difficulty_strategies,
+        difficulty_samplers,
+        ..
+    } = state;
+    for challenge in query_data.challenges.iter() {
+        if challenge_name.is_some_and(|name| *name != challenge.details.name) {
+            continue;
+        }
+        let config = difficulty_strategies
+            .get(&challenge.details.name)
+            .cloned()
+            .unwrap_or_default();
+        let difficulty_sampler = difficulty_samplers
+            .entry(challenge.id.clone())
+            .or_insert_with(|| DifficultySampler::new(config.clone()));
+        if difficulty_sampler.config() != config {
+            *difficulty_sampler = DifficultySampler::new(config);
+        }
+        let min_difficulty =
+            query_data.latest_block.config().difficulty.parameters[&challenge.id].min_difficulty();
+        difficulty_sampler.update_with_block_data(&min_difficulty, challenge.block_data());
+    }
+}
+
+// retain only benchmarks that are within the lifespan period
+// preserves solution_meta_data and solution_data
+fn merge_benchmarks(
+    new_query_data: &mut QueryData,
+    mut benchmarks: HashMap<String, Benchmark>,
+    mut proofs: HashMap<String, Proof>,
+    mut frauds: HashMap<String, Fraud>,
+) {
+    let block_started_cutoff = new_query_data.latest_block.details.height.saturating_sub(
+        new_query_data
+            .latest_block
+            .config()
+            .benchmark_submissions
+            .lifespan_period,
+    );
+    benchmarks.retain(|_, x| x.details.block_started >= block_started_cutoff);
+    benchmarks.extend(new_query_data.benchmarks.drain());
+
+    proofs.retain(|id, _| benchmarks.contains_key(id));
+    proofs.extend(new_query_data.proofs.drain());
+
+    frauds.retain(|id, _| benchmarks.contains_key(id));
+    frauds.extend(new_query_data.frauds.drain());
+
+    new_query_data.benchmarks = benchmarks;
+    new_query_data.proofs = proofs;
+    new_query_data.frauds = frauds;
+}
+
 pub async fn drain_solutions(benchmark_id: &String, solutions_data: &mut Vec<SolutionData>) -> u32 {
     let mut state = (*state()).lock().await;
     let QueryData {
@@ -438,7 +472,15 @@ pub async fn start(num_workers: u32, ms_per_benchmark: u32) {
                     state.status = Status::Stopped;
                 }
             }
-            if let Err(e) = run_once(num_workers, ms_per_benchmark).await {
+            let result = run_once(num_workers, ms_per_benchmark).await;
+            #[cfg(feature = "standalone")]
+            {
+                let saved = persistence::save(&*state().lock().await);
+                if let Err(e) = saved {
+                    update_status(&format!("Error: {:?}", e)).await;
+                }
+            }
+            if let Err(e) = result {
                 update_status(&format!("Error: {:?}", e)).await;
                 sleep(5000).await;
             }
@@ -461,28 +503,39 @@ pub async fn select_algorithm(challenge_name: String, algorithm_name: String) {
         .insert(challenge_name, algorithm_name);
 }
 
+pub async fn set_challenge_selector(challenge_selector: ChallengeSelection) {
+    let mut state = (*state()).lock().await;
+    state.challenge_selector = challenge_selector;
+}
+
+pub async fn set_difficulty_strategy(challenge_name: String, config: DifficultyStrategyConfig) {
+    let mut state = (*state()).lock().await;
+    if state.difficulty_strategies.get(&challenge_name) != Some(&config) {
+        state
+            .difficulty_strategies
+            .insert(challenge_name.clone(), config);
+        update_difficulty_samplers(&mut state, Some(&challenge_name));
+    }
+}
+
 pub async fn setup(api_url: String, api_key: String, player_id: String) {
     API.get_or_init(|| Api::new(api_url, api_key));
     PLAYER_ID.get_or_init(|| player_id);
-    let query_data = query_data::execute().await.expect("Failed to query data");
-    let mut difficulty_samplers = HashMap::new();
-    for challenge in query_data.challenges.iter() {
-        let difficulty_sampler = difficulty_samplers
-            .entry(challenge.id.clone())
-            .or_insert_with(|| DifficultySampler::new());
-        let min_difficulty =
-            query_data.latest_block.config().difficulty.parameters[&challenge.id].min_difficulty();
-        difficulty_sampler.update_with_block_data(&min_difficulty, challenge.block_data());
+    let mut state = State {
+        status: Status::Stopped,
+        timer: None,
+        query_data: query_data::execute().await.expect("Failed to query data"),
+        difficulty_samplers: HashMap::new(),
+        selected_algorithms: HashMap::new(),
+        difficulty_strategies: HashMap::new(),
+        challenge_selector: ChallengeSelection::default(),
+        job: None,
+        submission_errors: HashMap::new(),
+    };
+    #[cfg(feature = "standalone")]
+    if let Some(snapshot) = persistence::load().expect("Failed to load persisted state") {
+        snapshot.restore(&mut state);
     }
-    STATE.get_or_init(|| {
-        Mutex::new(State {
-            status: Status::Stopped,
-            timer: None,
-            query_data,
-            difficulty_samplers,
-            selected_algorithms: HashMap::new(),
-            job: None,
-            submission_errors: HashMap::new(),
-        })
-    });
+    update_difficulty_samplers(&mut state, None);
+    STATE.get_or_init(|| Mutex::new(state));
 }
@@ -0,0 +1,260 @@
+use super::{difficulty_sampler::DifficultySampler, merge_benchmarks, Result, State};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, path::PathBuf};
+use tig_structs::core::*;
+use tig_utils::{dejsonify, jsonify};
+
+const SNAPSHOT_FILE: &str = "state.json";
+
+static DATA_DIR: OnceCell<PathBuf> = OnceCell::new();
+
+// Benchmarks & proofs (including their solutions_data) survive restarts so that proofs can
+// still be submitted without recomputing
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Snapshot {
+    pub benchmarks: HashMap<String, Benchmark>,
+    pub proofs: HashMap<String, Proof>,
+    pub submission_errors: HashMap<String, String>,
+    pub difficulty_samplers: HashMap<String, DifficultySampler>,
+}
+
+impl Snapshot {
+    fn from_state(state: &State) -> Self {
+        Self {
+            benchmarks: state.query_data.benchmarks.clone(),
+            proofs: state.query_data.proofs.clone(),
+            submission_errors: state.submission_errors.clone(),
+            difficulty_samplers: state.difficulty_samplers.clone(),
+        }
+    }
+
+    // merges the snapshot into freshly queried state, dropping benchmarks that have expired
+    pub(super) fn restore(self, state: &mut State) {
+        let State {
+            query_data,
+            difficulty_samplers,
+            difficulty_strategies,
+            submission_errors,
+            ..
+        } = state;
+        merge_benchmarks(query_data, self.benchmarks, self.proofs, HashMap::new());
+        *submission_errors = self.submission_errors;
+        submission_errors.retain(|id, _| query_data.benchmarks.contains_key(id));
+        *difficulty_samplers = self.difficulty_samplers;
+        // keeps the strategy of restored samplers until another one is configured
+        *difficulty_strategies = query_data
+            .challenges
+            .iter()
+            .filter_map(|c| {
+                difficulty_samplers
+                    .get(&c.id)
+                    .map(|x| (c.details.name.clone(), x.config()))
+            })
+            .collect();
+    }
+}
+
+pub fn init(data_dir: PathBuf) -> Result<()> {
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data dir {:?}: {:?}", data_dir, e))?;
+    DATA_DIR
+        .set(data_dir)
+        .map_err(|_| "Data dir already initialised".to_string())
+}
+
+pub fn load() -> Result<Option<Snapshot>> {
+    let path = match DATA_DIR.get() {
+        Some(data_dir) => data_dir.join(SNAPSHOT_FILE),
+        None => return Ok(None),
+    };
+    if !path.exists() {
+        return Ok(None);
+    }
+    let snapshot =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {:?}: {:?}", path, e))?;
+    dejsonify::<Snapshot>(&snapshot)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse {:?}: {:?}", path, e))
+}
+
+pub fn save(state: &State) -> Result<()> {
+    let path = match DATA_DIR.get() {
+        Some(data_dir) => data_dir.join(SNAPSHOT_FILE),
+        None => return Ok(()),
+    };
+    // write to a temporary file first so that a crash never leaves a partial snapshot
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, jsonify(&Snapshot::from_state(state)))
+        .map_err(|e| format!("Failed to write {:?}: {:?}", tmp_path, e))?;
+    fs::rename(&tmp_path, &path).map_err(|e| format!("Failed to write {:?}: {:?}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarker::{
+        challenge_selector::ChallengeSelection, difficulty_sampler::DifficultyStrategyConfig,
+        thompson_sampler::ThompsonConfig, QueryData, Status,
+    };
+
+    fn benchmark(id: &str, block_started: u32) -> Benchmark {
+        Benchmark {
+            id: id.to_string(),
+            settings: BenchmarkSettings {
+                player_id: "0x0000000000000000000000000000000000000001".to_string(),
+                block_id: "block".to_string(),
+                challenge_id: "c001".to_string(),
+                algorithm_id: "c001_a001".to_string(),
+                difficulty: vec![50, 300],
+            },
+            details: BenchmarkDetails {
+                block_started,
+                num_solutions: 1,
+            },
+            state: None,
+            solutions_meta_data: Some(vec![SolutionMetaData {
+                nonce: 0,
+                solution_signature: 0,
+            }]),
+            solution_data: None,
+        }
+    }
+
+    fn proof(benchmark_id: &str) -> Proof {
+        Proof {
+            benchmark_id: benchmark_id.to_string(),
+            state: None,
+            solutions_data: Some(vec![SolutionData {
+                nonce: 0,
+                runtime_signature: 0,
+                fuel_consumed: 0,
+                solution: Solution::new(),
+            }]),
+        }
+    }
+
+    fn state(height: u32, benchmark_ids: &[(&str, u32)]) -> State {
+        State {
+            status: Status::Stopped,
+            timer: None,
+            query_data: QueryData {
+                latest_block: Block {
+                    id: "block".to_string(),
+                    details: BlockDetails {
+                        prev_block_id: "prev_block".to_string(),
+                        height,
+                        round: height / 10,
+                        eth_block_num: None,
+                    },
+                    data: None,
+                    // lifespan_period is 120
+                    config: Some(
+                        dejsonify(include_str!("../../../tig-server/example_config.json")).unwrap(),
+                    ),
+                },
+                player_data: None,
+                challenges: vec![Challenge {
+                    id: "c001".to_string(),
+                    details: ChallengeDetails {
+                        name: "satisfiability".to_string(),
+                    },
+                    state: None,
+                    block_data: None,
+                }],
+                download_urls: HashMap::new(),
+                algorithms_by_challenge: HashMap::new(),
+                benchmarks: benchmark_ids
+                    .iter()
+                    .map(|&(id, block_started)| (id.to_string(), benchmark(id, block_started)))
+                    .collect(),
+                proofs: benchmark_ids
+                    .iter()
+                    .map(|&(id, _)| (id.to_string(), proof(id)))
+                    .collect(),
+                frauds: HashMap::new(),
+            },
+            selected_algorithms: HashMap::new(),
+            difficulty_strategies: HashMap::new(),
+            challenge_selector: ChallengeSelection::default(),
+            job: None,
+            submission_errors: HashMap::new(),
+            difficulty_samplers: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let data_dir =
+            std::env::temp_dir().join(format!("tig-benchmarker-test-{}", std::process::id()));
+        init(data_dir.clone()).unwrap();
+        assert!(load().unwrap().is_none());
+
+        let mut state = state(200, &[("b1", 150), ("b2", 190)]);
+        state
+            .submission_errors
+            .insert("b1".to_string(), "Failed to submit".to_string());
+        state.difficulty_samplers.insert(
+            "c001".to_string(),
+            DifficultySampler::new(DifficultyStrategyConfig::Thompson(ThompsonConfig {
+                decay: 0.5,
+                ..ThompsonConfig::default()
+            })),
+        );
+        save(&state).unwrap();
+        assert!(!data_dir.join("state.json.tmp").exists());
+
+        let snapshot = load().unwrap().unwrap();
+        assert_eq!(jsonify(&snapshot), jsonify(&Snapshot::from_state(&state)));
+        assert_eq!(snapshot.benchmarks, state.query_data.benchmarks);
+        assert_eq!(snapshot.proofs, state.query_data.proofs);
+        assert_eq!(snapshot.submission_errors, state.submission_errors);
+        assert_eq!(
+            snapshot.difficulty_samplers["c001"].config(),
+            state.difficulty_samplers["c001"].config()
+        );
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    #[test]
+    fn test_restore_merges_into_queried_state() {
+        // b0 started before the lifespan cutoff of 200 - 120 = 80 so is dropped
+        let mut snapshot_state = state(200, &[("b0", 79), ("b1", 80), ("b2", 150)]);
+        for id in ["b0", "b1"] {
+            snapshot_state
+                .submission_errors
+                .insert(id.to_string(), "Failed to submit".to_string());
+        }
+        let config = DifficultyStrategyConfig::Thompson(ThompsonConfig::default());
+        snapshot_state
+            .difficulty_samplers
+            .insert("c001".to_string(), DifficultySampler::new(config.clone()));
+        let snapshot = Snapshot::from_state(&snapshot_state);
+
+        // the queried version of b2 (e.g. now confirmed) takes precedence over the snapshot
+        let mut state = state(200, &[("b2", 150), ("b3", 190)]);
+        state.query_data.benchmarks.get_mut("b2").unwrap().state = Some(BenchmarkState {
+            block_confirmed: Some(151),
+            sampled_nonces: Some(vec![0]),
+        });
+        let queried_b2 = state.query_data.benchmarks["b2"].clone();
+        snapshot.restore(&mut state);
+
+        let mut benchmark_ids: Vec<&String> = state.query_data.benchmarks.keys().collect();
+        benchmark_ids.sort();
+        assert_eq!(benchmark_ids, vec!["b1", "b2", "b3"]);
+        assert_eq!(state.query_data.benchmarks["b2"], queried_b2);
+        let mut proof_ids: Vec<&String> = state.query_data.proofs.keys().collect();
+        proof_ids.sort();
+        assert_eq!(proof_ids, benchmark_ids);
+        assert_eq!(
+            state.submission_errors.keys().collect::<Vec<_>>(),
+            vec!["b1"]
+        );
+        assert_eq!(state.difficulty_samplers["c001"].config(), config);
+        assert_eq!(
+            state.difficulty_strategies,
+            HashMap::from([("satisfiability".to_string(), config)])
+        );
+    }
+}
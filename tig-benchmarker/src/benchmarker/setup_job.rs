@@ -1,11 +1,13 @@
-use super::{player_id, state, Job, QueryData, Result, State};
+use super::{
+    challenge_selector::ChallengeSelector, difficulty_sampler::DifficultyStrategy, player_id,
+    state, Job, QueryData, Result, State,
+};
 use crate::future_utils::time;
 use rand::{
-    distributions::{Alphanumeric, DistString, WeightedIndex},
+    distributions::{Alphanumeric, DistString},
     rngs::StdRng,
     SeedableRng,
 };
-use rand_distr::Distribution;
 use std::collections::HashMap;
 use tig_structs::core::*;
 
@@ -88,18 +90,17 @@ async fn pick_settings_to_benchmark() -> Result<Job> {
         query_data,
         selected_algorithms,
         difficulty_samplers,
+        challenge_selector,
         ..
     } = &(*state().lock().await);
     let QueryData {
         latest_block,
-        player_data,
-        challenges,
         download_urls,
         algorithms_by_challenge,
         ..
     } = query_data;
     let mut rng = StdRng::seed_from_u64(time() as u64);
-    let challenge = pick_challenge(&mut rng, player_data, challenges, selected_algorithms)?;
+    let challenge = challenge_selector.select(&mut rng, query_data, selected_algorithms)?;
     let selected_algorithm_id = get_algorithm_id(
         algorithms_by_challenge,
         challenge,
@@ -123,73 +124,6 @@ async fn pick_settings_to_benchmark() -> Result<Job> {
     })
 }
 
-fn pick_challenge<'a>(
-    rng: &mut StdRng,
-    player_data: &'a Option<PlayerBlockData>,
-    challenges: &'a Vec<Challenge>,
-    selected_algorithms: &HashMap<String, String>,
-) -> Result<&'a Challenge> {
-    let num_qualifiers_by_challenge = match player_data
-        .as_ref()
-        .map(|x| x.num_qualifiers_by_challenge.as_ref())
-    {
-        Some(Some(num_qualifiers_by_challenge)) => num_qualifiers_by_challenge.clone(),
-        _ => HashMap::new(),
-    };
-    let challenge_name_2_id: HashMap<String, String> = challenges
-        .iter()
-        .map(|c| (c.details.name.clone(), c.id.clone()))
-        .collect();
-    let percent_qualifiers_by_challenge: HashMap<String, f64> = challenges
-        .iter()
-        .map(|c| {
-            let player_num_qualifiers = *num_qualifiers_by_challenge.get(&c.id).unwrap_or(&0);
-            let challenge_num_qualifiers = *c.block_data().num_qualifiers();
-            let percent = if player_num_qualifiers == 0 || challenge_num_qualifiers == 0 {
-                0f64
-            } else {
-                (player_num_qualifiers as f64) / (challenge_num_qualifiers as f64)
-            };
-            (c.id.clone(), percent)
-        })
-        .collect();
-    if selected_algorithms.len() == 0 {
-        return Err("Your <algorithm_selection>.json is empty".to_string());
-    };
-    let mut challenge_weights = Vec::<(String, f64)>::new();
-    for challenge_name in selected_algorithms.keys() {
-        let challenge_id = challenge_name_2_id.get(challenge_name).ok_or_else(|| {
-            format!(
-                "Your <algorithm_selection>.json contains a non-existent challenge '{}'",
-                challenge_name
-            )
-        })?;
-        let max_percent_qualifiers = *percent_qualifiers_by_challenge
-            .values()
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap();
-        challenge_weights.push((
-            challenge_id.clone(),
-            4.0 * max_percent_qualifiers / 3.0 - percent_qualifiers_by_challenge[challenge_id]
-                + 1e-10f64,
-        ));
-    }
-    let dist = WeightedIndex::new(
-        &challenge_weights
-            .iter()
-            .map(|w| w.1.clone())
-            .collect::<Vec<f64>>(),
-    )
-    .map_err(|e| format!("Failed to create WeightedIndex: {}", e))?;
-    let index = dist.sample(rng);
-    let random_challenge_id = challenge_weights[index].0.clone();
-    let challenge = challenges
-        .iter()
-        .find(|c| c.id == *random_challenge_id)
-        .ok_or_else(|| "Selected challenge should exist")?;
-    Ok(challenge)
-}
-
 fn get_algorithm_id(
     algorithms_by_challenge: &HashMap<String, Vec<Algorithm>>,
     challenge: &Challenge,
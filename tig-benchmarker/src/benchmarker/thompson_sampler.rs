@@ -0,0 +1,270 @@
+use super::difficulty_sampler::DifficultyStrategy;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Gamma};
+use serde::{Deserialize, Serialize};
+use tig_structs::core::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "ThompsonConfigFields")]
+pub struct ThompsonConfig {
+    pub prior_solutions: f64,
+    pub prior_benchmarks: f64,
+    // fraction of each arm's evidence that is kept when a new block arrives
+    pub decay: f64,
+}
+
+// unvalidated ThompsonConfig, as read from a strategies file or snapshot
+#[derive(Deserialize)]
+#[serde(default)]
+struct ThompsonConfigFields {
+    prior_solutions: f64,
+    prior_benchmarks: f64,
+    decay: f64,
+}
+
+impl Default for ThompsonConfigFields {
+    fn default() -> Self {
+        let ThompsonConfig {
+            prior_solutions,
+            prior_benchmarks,
+            decay,
+        } = ThompsonConfig::default();
+        Self {
+            prior_solutions,
+            prior_benchmarks,
+            decay,
+        }
+    }
+}
+
+impl TryFrom<ThompsonConfigFields> for ThompsonConfig {
+    type Error = String;
+
+    fn try_from(fields: ThompsonConfigFields) -> Result<Self, Self::Error> {
+        // the priors are the shape & rate of each arm's Gamma posterior, so must be positive
+        for (name, value) in [
+            ("prior_solutions", fields.prior_solutions),
+            ("prior_benchmarks", fields.prior_benchmarks),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(format!("{} must be positive, got {}", name, value));
+            }
+        }
+        if !(0.0..=1.0).contains(&fields.decay) {
+            return Err(format!(
+                "decay must be between 0 and 1, got {}",
+                fields.decay
+            ));
+        }
+        Ok(Self {
+            prior_solutions: fields.prior_solutions,
+            prior_benchmarks: fields.prior_benchmarks,
+            decay: fields.decay,
+        })
+    }
+}
+
+impl Default for ThompsonConfig {
+    fn default() -> Self {
+        Self {
+            prior_solutions: 1.0,
+            prior_benchmarks: 1.0,
+            decay: 0.9,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Arm {
+    pub difficulty: Vec<i32>,
+    pub solutions: f64,
+    pub benchmarks: f64,
+}
+
+// Bandit over the difficulties on the base & scaled frontiers. The solution rate of each arm has a
+// Gamma posterior, and the arm with the highest sampled rate is benchmarked next
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ThompsonSampler {
+    pub config: ThompsonConfig,
+    pub arms: Vec<Arm>,
+}
+
+impl ThompsonSampler {
+    pub fn new(config: ThompsonConfig) -> Self {
+        Self {
+            config,
+            arms: Vec::new(),
+        }
+    }
+}
+
+impl DifficultyStrategy for ThompsonSampler {
+    fn sample(&self, rng: &mut StdRng) -> Vec<i32> {
+        assert!(!self.arms.is_empty(), "You must update sampler first");
+        let mut best = (f64::MIN, 0);
+        for (i, arm) in self.arms.iter().enumerate() {
+            let rate = Gamma::new(arm.solutions, 1.0 / arm.benchmarks)
+                .expect("Gamma parameters should be positive")
+                .sample(rng);
+            if rate > best.0 {
+                best = (rate, i);
+            }
+        }
+        self.arms[best.1].difficulty.clone()
+    }
+
+    fn update_with_block_data(
+        &mut self,
+        min_difficulty: &Vec<i32>,
+        block_data: &ChallengeBlockData,
+    ) {
+        let mut difficulties: Vec<Vec<i32>> = block_data
+            .base_frontier()
+            .iter()
+            .chain(block_data.scaled_frontier().iter())
+            .map(|x| {
+                x.iter()
+                    .zip(min_difficulty.iter())
+                    .map(|(x, min)| *x.max(min))
+                    .collect()
+            })
+            .collect();
+        difficulties.sort();
+        difficulties.dedup();
+
+        let ThompsonConfig {
+            prior_solutions,
+            prior_benchmarks,
+            decay,
+        } = self.config;
+        self.arms = difficulties
+            .into_iter()
+            .map(
+                |difficulty| match self.arms.iter().find(|a| a.difficulty == difficulty) {
+                    Some(arm) => Arm {
+                        difficulty,
+                        solutions: prior_solutions + (arm.solutions - prior_solutions) * decay,
+                        benchmarks: prior_benchmarks + (arm.benchmarks - prior_benchmarks) * decay,
+                    },
+                    None => Arm {
+                        difficulty,
+                        solutions: prior_solutions,
+                        benchmarks: prior_benchmarks,
+                    },
+                },
+            )
+            .collect();
+    }
+
+    fn update_with_solutions(&mut self, difficulty: &Vec<i32>, num_solutions: u32) {
+        if let Some(arm) = self.arms.iter_mut().find(|a| a.difficulty == *difficulty) {
+            arm.solutions += num_solutions as f64;
+            arm.benchmarks += 1.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::difficulty_sampler::DifficultyStrategyConfig;
+    use super::*;
+    use rand::SeedableRng;
+
+    fn block_data(
+        base_frontier: Vec<Vec<i32>>,
+        scaled_frontier: Vec<Vec<i32>>,
+    ) -> ChallengeBlockData {
+        ChallengeBlockData {
+            solution_signature_threshold: None,
+            num_qualifiers: None,
+            qualifier_difficulties: None,
+            base_frontier: Some(base_frontier.into_iter().collect()),
+            cutoff_frontier: None,
+            scaled_frontier: Some(scaled_frontier.into_iter().collect()),
+            scaling_factor: Some(1.5),
+        }
+    }
+
+    #[test]
+    fn test_config_defaults_missing_fields() {
+        let config: ThompsonConfig = serde_json::from_str(r#"{"decay": 0.5}"#).unwrap();
+        assert_eq!(
+            config,
+            ThompsonConfig {
+                decay: 0.5,
+                ..ThompsonConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        for json in [
+            r#"{"prior_solutions": 0}"#,
+            r#"{"prior_benchmarks": 0}"#,
+            r#"{"prior_solutions": -1}"#,
+            r#"{"decay": 1.5}"#,
+            r#"{"decay": -0.1}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<ThompsonConfig>(json).is_err(),
+                "{} should be rejected",
+                json
+            );
+        }
+        // configs are also validated when loaded as a strategy
+        assert!(serde_json::from_str::<DifficultyStrategyConfig>(
+            r#"{"strategy": "thompson", "prior_solutions": 0}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_sample_picks_arm_with_highest_solution_rate() {
+        let mut sampler = ThompsonSampler::new(ThompsonConfig::default());
+        sampler.update_with_block_data(
+            &vec![0, 0],
+            &block_data(vec![vec![10, 20], vec![20, 10]], vec![vec![15, 30]]),
+        );
+        for _ in 0..100 {
+            sampler.update_with_solutions(&vec![10, 20], 0);
+            sampler.update_with_solutions(&vec![20, 10], 10);
+            sampler.update_with_solutions(&vec![15, 30], 1);
+        }
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            assert_eq!(sampler.sample(&mut rng), vec![20, 10]);
+        }
+    }
+
+    #[test]
+    fn test_update_with_block_data_decays_evidence() {
+        let config = ThompsonConfig {
+            prior_solutions: 1.0,
+            prior_benchmarks: 2.0,
+            decay: 0.5,
+        };
+        let mut sampler = ThompsonSampler::new(config);
+        sampler.update_with_block_data(
+            &vec![12, 0],
+            &block_data(vec![vec![10, 20], vec![12, 20]], vec![vec![15, 30]]),
+        );
+        // difficulties are clamped to min_difficulty and deduplicated
+        let difficulties: Vec<Vec<i32>> =
+            sampler.arms.iter().map(|a| a.difficulty.clone()).collect();
+        assert_eq!(difficulties, vec![vec![12, 20], vec![15, 30]]);
+
+        sampler.update_with_solutions(&vec![12, 20], 9);
+        sampler.update_with_block_data(
+            &vec![12, 0],
+            &block_data(vec![vec![12, 20]], vec![vec![16, 30]]),
+        );
+        assert_eq!(sampler.arms.len(), 2);
+        assert_eq!(sampler.arms[0].difficulty, vec![12, 20]);
+        assert_eq!(sampler.arms[0].solutions, 1.0 + 9.0 * 0.5);
+        assert_eq!(sampler.arms[0].benchmarks, 2.0 + 1.0 * 0.5);
+        assert_eq!(sampler.arms[1].difficulty, vec![16, 30]);
+        assert_eq!(sampler.arms[1].solutions, 1.0);
+        assert_eq!(sampler.arms[1].benchmarks, 2.0);
+    }
+}
@@ -27,6 +27,18 @@ mod exports {
         benchmarker::select_algorithm(challenge_name, algorithm_name).await;
     }
 
+    #[wasm_bindgen]
+    pub async fn set_challenge_selector(challenge_selector: String) {
+        let challenge_selector = challenge_selector.parse().unwrap();
+        benchmarker::set_challenge_selector(challenge_selector).await;
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_difficulty_strategy(challenge_name: String, config: JsValue) {
+        let config = serde_wasm_bindgen::from_value(config).unwrap();
+        benchmarker::set_difficulty_strategy(challenge_name, config).await;
+    }
+
     #[wasm_bindgen]
     pub async fn setup(api_url: String, api_key: String, player_id: String) {
         benchmarker::setup(api_url, api_key, player_id.to_string()).await;
@@ -3,7 +3,10 @@
 
 mod benchmarker;
 mod future_utils;
-use benchmarker::{Job, NonceIterator};
+use benchmarker::{
+    challenge_selector::ChallengeSelection, difficulty_sampler::DifficultyStrategyConfig, Job,
+    NonceIterator,
+};
 use clap::{value_parser, Arg, Command};
 use future_utils::{sleep, Mutex};
 use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
@@ -74,6 +77,25 @@ fn cli() -> Command {
                 .default_value("5000000")
                 .value_parser(value_parser!(u64)),
         )
+        .arg(
+            Arg::new("challenge-selector")
+                .long("challenge-selector")
+                .help("(Optional) Set policy for picking which challenge to benchmark next (qualifier_balance or max_influence)")
+                .default_value("qualifier_balance")
+                .value_parser(value_parser!(ChallengeSelection)),
+        )
+        .arg(
+            Arg::new("difficulty-strategies")
+                .long("difficulty-strategies")
+                .help("(Optional) Path to json file mapping challenge names to difficulty sampling strategies")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("data-dir")
+                .long("data-dir")
+                .help("(Optional) Set directory for persisting benchmarks, proofs & sampler state across restarts")
+                .value_parser(value_parser!(PathBuf)),
+        )
 }
 
 #[tokio::main]
@@ -88,6 +110,11 @@ async fn main() {
     let api_key = matches.get_one::<String>("API_KEY").unwrap().clone();
     let player_id = matches.get_one::<String>("PLAYER_ID").unwrap().clone();
     let nonce_offset = matches.get_one::<u64>("offset").unwrap().clone();
+    let data_dir = matches.get_one::<PathBuf>("data-dir").cloned();
+    let strategies_path = matches.get_one::<PathBuf>("difficulty-strategies");
+    let challenge_selector = *matches
+        .get_one::<ChallengeSelection>("challenge-selector")
+        .unwrap();
     if let Some(master) = matches.get_one::<String>("master") {
         slave_node(master, port, num_workers).await;
     } else {
@@ -98,8 +125,11 @@ async fn main() {
             num_workers,
             duration,
             algorithms_path,
+            strategies_path,
+            challenge_selector,
             port,
             nonce_offset,
+            data_dir,
         )
         .await
     }
@@ -142,8 +172,8 @@ async fn slave_node(master: &String, port: u16, num_workers: u32) {
                     "Downloading algorithm {}",
                     job.download_url.split("/").last().unwrap()
                 );
-                let wasm = match benchmarker::download_wasm::execute(job).await {
-                    Ok(wasm) => wasm,
+                let executor = match benchmarker::download_wasm::execute(job).await {
+                    Ok(executor) => executor,
                     Err(e) => {
                         println!("Error downloading wasm: {:?}", e);
                         sleep(5000).await;
@@ -177,14 +207,19 @@ async fn slave_node(master: &String, port: u16, num_workers: u32) {
                     })
                     .collect();
                 println!("Starting benchmark");
-                benchmarker::run_benchmark::execute(
+                if let Err(e) = benchmarker::run_benchmark::execute(
                     nonce_iters.iter().cloned().collect(),
                     job,
-                    &wasm,
+                    &executor,
                     solutions_data.clone(),
                     solutions_count.clone(),
                 )
-                .await;
+                .await
+                {
+                    println!("Error starting benchmark: {}", e);
+                    sleep(5000).await;
+                    continue;
+                }
             }
 
             job = next_job;
@@ -236,10 +271,17 @@ async fn master_node(
     num_workers: u32,
     duration: u32,
     algorithms_path: &PathBuf,
+    strategies_path: Option<&PathBuf>,
+    challenge_selector: ChallengeSelection,
     port: u16,
     nonce_offset: u64,
+    data_dir: Option<PathBuf>,
 ) {
+    if let Some(data_dir) = data_dir {
+        benchmarker::persistence::init(data_dir).expect("Failed to initialise data dir");
+    }
     benchmarker::setup(api_url, api_key, player_id).await;
+    benchmarker::set_challenge_selector(challenge_selector).await;
     benchmarker::start(num_workers, duration).await;
     future_utils::spawn(async move {
         let offsets = Arc::new(Mutex::new(HashMap::new()));
@@ -284,6 +326,15 @@ async fn master_node(
         for (challenge_id, algorithm_id) in selection {
             benchmarker::select_algorithm(challenge_id, algorithm_id).await;
         }
+        if let Some(strategies_path) = strategies_path {
+            let strategies = serde_json::from_str::<HashMap<String, DifficultyStrategyConfig>>(
+                &fs::read_to_string(strategies_path).unwrap(),
+            )
+            .unwrap();
+            for (challenge_name, config) in strategies {
+                benchmarker::set_difficulty_strategy(challenge_name, config).await;
+            }
+        }
         future_utils::sleep(10000).await;
     }
 }
@@ -87,6 +87,7 @@ pub mod vector_search;
 pub use vector_search as c004;
 pub mod vehicle_routing;
 pub use vehicle_routing as c002;
+pub mod registry;
 
 // #[cfg(feature = "cuda")]
 pub struct CudaKernel {
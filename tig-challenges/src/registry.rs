@@ -0,0 +1,141 @@
+use crate::{c001, c002, c003, c004, ChallengeTrait, DifficultyTrait, SolutionTrait};
+use anyhow::{anyhow, Result};
+use serde_json::{from_value, Map, Value};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Type-erased handle to a challenge, used to dispatch on a challenge id at runtime.
+pub trait ChallengeHandle: Send + Sync {
+    fn id(&self) -> &'static str;
+    fn name(&self) -> &'static str;
+    fn num_difficulty_parameters(&self) -> usize;
+    fn generate_instance(
+        &self,
+        seeds: [u64; 8],
+        difficulty: &Vec<i32>,
+    ) -> Result<Box<dyn ChallengeInstance>>;
+}
+
+/// Type-erased challenge instance produced by a `ChallengeHandle`.
+pub trait ChallengeInstance {
+    /// bincode serialization. This is the format passed to an algorithm's wasm `entry_point`
+    fn to_bincode(&self) -> Result<Vec<u8>>;
+    fn to_json(&self) -> Result<String>;
+    /// Pretty-printed `Debug` representation of the native challenge struct
+    fn to_native(&self) -> String;
+    fn verify_solution(&self, solution: &Map<String, Value>) -> Result<()>;
+}
+
+pub struct Registration<C, T, U, const N: usize> {
+    id: &'static str,
+    name: &'static str,
+    _types: PhantomData<fn() -> (C, T, U)>,
+}
+
+impl<C, T, U, const N: usize> Registration<C, T, U, N> {
+    pub const fn new(id: &'static str, name: &'static str) -> Self {
+        Self {
+            id,
+            name,
+            _types: PhantomData,
+        }
+    }
+}
+
+impl<C, T, U, const N: usize> ChallengeHandle for Registration<C, T, U, N>
+where
+    C: ChallengeTrait<T, U, N> + Debug + 'static,
+    T: SolutionTrait + 'static,
+    U: DifficultyTrait<N> + 'static,
+{
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn num_difficulty_parameters(&self) -> usize {
+        N
+    }
+
+    fn generate_instance(
+        &self,
+        seeds: [u64; 8],
+        difficulty: &Vec<i32>,
+    ) -> Result<Box<dyn ChallengeInstance>> {
+        let challenge = C::generate_instance_from_vec(seeds, difficulty)
+            .map_err(|e| anyhow!("Failed to generate {} instance: {}", self.name, e))?;
+        Ok(Box::new(Instance::<C, T, U, N> {
+            name: self.name,
+            challenge,
+            _types: PhantomData,
+        }))
+    }
+}
+
+struct Instance<C, T, U, const N: usize> {
+    name: &'static str,
+    challenge: C,
+    _types: PhantomData<fn() -> (T, U)>,
+}
+
+impl<C, T, U, const N: usize> ChallengeInstance for Instance<C, T, U, N>
+where
+    C: ChallengeTrait<T, U, N> + Debug,
+    T: SolutionTrait,
+    U: DifficultyTrait<N>,
+{
+    fn to_bincode(&self) -> Result<Vec<u8>> {
+        bincode::serialize(&self.challenge)
+            .map_err(|e| anyhow!("Failed to serialize {} instance: {}", self.name, e))
+    }
+
+    fn to_json(&self) -> Result<String> {
+        serde_json::to_string(&self.challenge)
+            .map_err(|e| anyhow!("Failed to serialize {} instance: {}", self.name, e))
+    }
+
+    fn to_native(&self) -> String {
+        format!("{:#?}", self.challenge)
+    }
+
+    fn verify_solution(&self, solution: &Map<String, Value>) -> Result<()> {
+        match from_value::<T>(Value::Object(solution.clone())) {
+            Ok(solution) => self.challenge.verify_solution(&solution),
+            Err(_) => Err(anyhow!(
+                "Invalid solution. Cannot convert to {}::Solution",
+                self.name
+            )),
+        }
+    }
+}
+
+// To add a challenge, append its registration here
+static CHALLENGES: [&dyn ChallengeHandle; 4] = [
+    &Registration::<c001::Challenge, c001::Solution, c001::Difficulty, 2>::new(
+        "c001",
+        "satisfiability",
+    ),
+    &Registration::<c002::Challenge, c002::Solution, c002::Difficulty, 2>::new(
+        "c002",
+        "vehicle_routing",
+    ),
+    &Registration::<c003::Challenge, c003::Solution, c003::Difficulty, 2>::new("c003", "knapsack"),
+    &Registration::<c004::Challenge, c004::Solution, c004::Difficulty, 2>::new(
+        "c004",
+        "vector_search",
+    ),
+];
+
+pub fn all() -> &'static [&'static dyn ChallengeHandle] {
+    &CHALLENGES
+}
+
+pub fn get(challenge_id: &str) -> Result<&'static dyn ChallengeHandle> {
+    CHALLENGES
+        .iter()
+        .find(|c| c.id() == challenge_id)
+        .copied()
+        .ok_or_else(|| anyhow!("Unknown challenge id '{}'", challenge_id))
+}
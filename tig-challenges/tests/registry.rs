@@ -0,0 +1,24 @@
+use serde_json::Map;
+use tig_challenges::registry;
+
+#[test]
+fn test_get_known_challenges() {
+    for id in ["c001", "c002", "c003", "c004"] {
+        assert_eq!(registry::get(id).unwrap().id(), id);
+    }
+    assert_eq!(registry::all().len(), 4);
+}
+
+#[test]
+fn test_get_unknown_challenge() {
+    assert!(registry::get("c999").is_err());
+}
+
+#[test]
+fn test_generate_and_verify() {
+    let handle = registry::get("c003").unwrap();
+    let instance = handle.generate_instance([0; 8], &vec![50, 10]).unwrap();
+    assert!(!instance.to_bincode().unwrap().is_empty());
+    assert!(instance.to_native().starts_with("Challenge {"));
+    assert!(instance.verify_solution(&Map::new()).is_err());
+}
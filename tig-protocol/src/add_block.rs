@@ -1,56 +1,106 @@
-use crate::context::*;
+use crate::{context::*, error::*};
+use anyhow::Context as _;
 use logging_timer::time;
 use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
 use std::collections::{HashMap, HashSet};
 use tig_structs::{config::*, core::*};
 use tig_utils::*;
 
+// The block is added within a transaction, so a failed block is rolled back and can be retried.
+// If rolling back fails too, the error adding the block is returned with the rollback error
 #[time]
-pub(crate) async fn execute<T: Context>(ctx: &T) -> String {
-    let (block, mut cache) = create_block(ctx).await;
-    confirm_mempool_challenges(&block, &mut cache).await;
-    confirm_mempool_algorithms(&block, &mut cache).await;
-    confirm_mempool_benchmarks(&block, &mut cache).await;
-    confirm_mempool_proofs(ctx, &block, &mut cache).await;
-    confirm_mempool_frauds(&block, &mut cache).await;
-    confirm_mempool_wasms(&block, &mut cache).await;
-    update_deposits(ctx, &block, &mut cache).await;
-    update_cutoffs(&block, &mut cache).await;
-    update_qualifiers(&block, &mut cache).await;
-    update_frontiers(&block, &mut cache).await;
-    update_solution_signature_thresholds(&block, &mut cache).await;
-    update_influence(&block, &mut cache).await;
-    update_adoption(&block, &mut cache).await;
-    update_innovator_rewards(&block, &mut cache).await;
-    update_benchmarker_rewards(&block, &mut cache).await;
-    update_merge_points(&block, &mut cache).await;
-    update_merges(&block, &mut cache).await;
-    commit_changes(ctx, &block, &mut cache).await;
-    block.id
+pub(crate) async fn execute<T: Context>(ctx: &T) -> ProtocolResult<String> {
+    ctx.begin_transaction().await.context("begin_transaction")?;
+    let result = match add_block(ctx).await {
+        Ok(block_id) => ctx
+            .commit_transaction()
+            .await
+            .context("commit_transaction")
+            .map(|_| block_id)
+            .map_err(ProtocolError::from),
+        Err(e) => Err(e),
+    };
+    match result {
+        Err(error) => match ctx.rollback_transaction().await {
+            Ok(()) => Err(error),
+            Err(rollback_error) => Err(ProtocolError::RollbackFailure {
+                error: Box::new(error),
+                rollback_error: rollback_error.context("rollback_transaction"),
+            }),
+        },
+        ok => ok,
+    }
+}
+
+async fn add_block<T: Context>(ctx: &T) -> ProtocolResult<String> {
+    let (block, mut cache) = create_block(ctx).await?;
+    process_block(ctx, &block, &mut cache).await?;
+    commit_changes(ctx, &block, &mut cache).await?;
+    Ok(block.id)
+}
+
+// Every step of adding the block up to committing its changes. Replaying a block re-runs these
+#[time]
+pub(crate) async fn process_block<T: Context>(
+    ctx: &T,
+    block: &Block,
+    cache: &mut AddBlockCache,
+) -> ProtocolResult<()> {
+    confirm_mempool_challenges(block, cache).await;
+    confirm_mempool_algorithms(block, cache).await;
+    confirm_mempool_benchmarks(block, cache).await;
+    confirm_mempool_proofs(ctx, block, cache).await?;
+    confirm_mempool_frauds(block, cache).await;
+    confirm_mempool_wasms(block, cache).await;
+    confirm_mempool_breakthroughs(block, cache).await;
+    update_breakthrough_votes(ctx, block, cache).await?;
+    update_deposits(ctx, block, cache).await?;
+    update_cutoffs(block, cache).await;
+    update_qualifiers(block, cache).await;
+    update_frontiers(block, cache).await;
+    update_solution_signature_thresholds(block, cache).await;
+    update_influence(block, cache).await;
+    update_slashing(block, cache).await;
+    update_adoption(block, cache).await;
+    update_innovator_rewards(block, cache).await;
+    update_breakthrough_rewards(block, cache).await;
+    update_benchmarker_rewards(block, cache).await;
+    update_round_earnings(block, cache).await;
+    update_merge_points(block, cache).await;
+    update_merges(block, cache).await;
+    Ok(())
 }
 
-struct AddBlockCache {
+pub(crate) struct AddBlockCache {
     pub mempool_challenges: Vec<Challenge>,
     pub mempool_algorithms: Vec<Algorithm>,
     pub mempool_benchmarks: Vec<Benchmark>,
     pub mempool_proofs: Vec<Proof>,
     pub mempool_frauds: Vec<Fraud>,
     pub mempool_wasms: Vec<Wasm>,
+    pub mempool_breakthroughs: Vec<Breakthrough>,
     pub active_challenges: HashMap<String, Challenge>,
     pub active_algorithms: HashMap<String, Algorithm>,
     pub active_benchmarks: HashMap<String, Benchmark>,
     pub active_players: HashMap<String, Player>,
+    pub slashed_players: HashMap<String, Player>,
+    pub active_breakthroughs: HashMap<String, Breakthrough>,
+    pub pending_breakthroughs: HashMap<String, Breakthrough>,
     pub prev_challenges: HashMap<String, Challenge>,
     pub prev_algorithms: HashMap<String, Algorithm>,
     pub prev_players: HashMap<String, Player>,
+    pub prev_round_earnings_by_player: HashMap<String, PreciseNumber>,
+    pub prev_round_earnings_by_algorithm: HashMap<String, PreciseNumber>,
+    pub banned_player_ids: HashSet<String>,
+    pub slashable_rewards_by_player: HashMap<String, PreciseNumber>,
 }
 
 #[time]
-async fn setup_cache<T: Context>(
+pub(crate) async fn setup_cache<T: Context>(
     ctx: &T,
     details: &BlockDetails,
     config: &ProtocolConfig,
-) -> AddBlockCache {
+) -> ProtocolResult<AddBlockCache> {
     let from_block_started = details
         .height
         .saturating_sub(config.benchmark_submissions.lifespan_period);
@@ -58,7 +108,7 @@ async fn setup_cache<T: Context>(
     for mut challenge in ctx
         .get_challenges(ChallengesFilter::Mempool, None)
         .await
-        .unwrap_or_else(|e| panic!("get_challenges error: {:?}", e))
+        .context("get_challenges")?
     {
         challenge.state = Some(ChallengeState {
             block_confirmed: None,
@@ -70,7 +120,7 @@ async fn setup_cache<T: Context>(
     for mut algorithm in ctx
         .get_algorithms(AlgorithmsFilter::Mempool, None, false)
         .await
-        .unwrap_or_else(|e| panic!("get_algorithms error: {:?}", e))
+        .context("get_algorithms")?
     {
         algorithm.state = Some(AlgorithmState {
             block_confirmed: None,
@@ -78,6 +128,8 @@ async fn setup_cache<T: Context>(
             round_pushed: None,
             round_merged: None,
             banned: false,
+            block_banned: None,
+            ban_reason: None,
         });
         mempool_algorithms.push(algorithm);
     }
@@ -85,7 +137,7 @@ async fn setup_cache<T: Context>(
     for mut benchmark in ctx
         .get_benchmarks(BenchmarksFilter::Mempool { from_block_started }, true)
         .await
-        .unwrap_or_else(|e| panic!("get_benchmarks error: {:?}", e))
+        .context("get_benchmarks")?
     {
         benchmark.state = Some(BenchmarkState {
             block_confirmed: None,
@@ -97,7 +149,7 @@ async fn setup_cache<T: Context>(
     for mut proof in ctx
         .get_proofs(ProofsFilter::Mempool { from_block_started }, false)
         .await
-        .unwrap_or_else(|e| panic!("mempool_proofs error: {:?}", e))
+        .context("get_proofs")?
     {
         proof.state = Some(ProofState {
             block_confirmed: None,
@@ -109,7 +161,7 @@ async fn setup_cache<T: Context>(
     for mut fraud in ctx
         .get_frauds(FraudsFilter::Mempool { from_block_started }, false)
         .await
-        .unwrap_or_else(|e| panic!("mempool_frauds error: {:?}", e))
+        .context("get_frauds")?
     {
         fraud.state = Some(FraudState {
             block_confirmed: None,
@@ -120,7 +172,7 @@ async fn setup_cache<T: Context>(
     for mut wasm in ctx
         .get_wasms(WasmsFilter::Mempool, false)
         .await
-        .unwrap_or_else(|e| panic!("mempool_wasms error: {:?}", e))
+        .context("get_wasms")?
     {
         wasm.state = Some(WasmState {
             block_confirmed: None,
@@ -131,7 +183,7 @@ async fn setup_cache<T: Context>(
     for mut challenge in ctx
         .get_challenges(ChallengesFilter::Confirmed, None)
         .await
-        .unwrap_or_else(|e| panic!("get_challenges error: {:?}", e))
+        .context("get_challenges")?
     {
         if challenge
             .state
@@ -140,22 +192,59 @@ async fn setup_cache<T: Context>(
             .round_active
             .is_some_and(|r| r <= details.round)
         {
-            challenge.block_data = Some(ChallengeBlockData {
-                num_qualifiers: None,
-                solution_signature_threshold: None,
-                scaled_frontier: None,
-                base_frontier: None,
-                cutoff_frontier: None,
-                scaling_factor: None,
-                qualifier_difficulties: None,
-            });
+            challenge.block_data = Some(new_challenge_block_data());
             active_challenges.insert(challenge.id.clone(), challenge);
         }
     }
+    let mut mempool_breakthroughs = Vec::new();
+    let mut active_breakthroughs = HashMap::new();
+    let mut pending_breakthroughs = HashMap::new();
+    if config.breakthroughs.is_some() {
+        for mut breakthrough in ctx
+            .get_breakthroughs(BreakthroughsFilter::Mempool, None)
+            .await
+            .context("get_breakthroughs")?
+        {
+            breakthrough.state = Some(BreakthroughState {
+                block_confirmed: None,
+                round_submitted: None,
+                round_voting_starts: None,
+                round_votes_tallied: None,
+                votes_for: None,
+                votes_against: None,
+                round_active: None,
+            });
+            mempool_breakthroughs.push(breakthrough);
+        }
+        // only the latest approved breakthrough of each challenge is rewarded
+        let mut latest_breakthrough_by_challenge = HashMap::<String, Breakthrough>::new();
+        for breakthrough in ctx
+            .get_breakthroughs(BreakthroughsFilter::Confirmed, None)
+            .await
+            .context("get_breakthroughs")?
+        {
+            let state = breakthrough.state();
+            let challenge_id = &breakthrough.details.challenge_id;
+            if state.round_votes_tallied.is_none() {
+                pending_breakthroughs.insert(breakthrough.id.clone(), breakthrough);
+            } else if state.round_active.is_some_and(|r| r <= details.round)
+                && active_challenges.contains_key(challenge_id)
+                && !latest_breakthrough_by_challenge
+                    .get(challenge_id)
+                    .is_some_and(|b| b.state().round_active > state.round_active)
+            {
+                latest_breakthrough_by_challenge.insert(challenge_id.clone(), breakthrough);
+            }
+        }
+        for mut breakthrough in latest_breakthrough_by_challenge.into_values() {
+            breakthrough.block_data = Some(BreakthroughBlockData { reward: None });
+            active_breakthroughs.insert(breakthrough.id.clone(), breakthrough);
+        }
+    }
     let algorithms = ctx
         .get_algorithms(AlgorithmsFilter::Confirmed, None, false)
         .await
-        .unwrap_or_else(|e| panic!("get_algorithms error: {:?}", e));
+        .context("get_algorithms")?;
     let challenges_with_algorithms = algorithms
         .iter()
         .filter(|a| a.state().round_pushed.is_some())
@@ -173,18 +262,12 @@ async fn setup_cache<T: Context>(
         let wasm = ctx
             .get_wasms(WasmsFilter::AlgorithmId(algorithm.id.clone()), false)
             .await
-            .unwrap_or_else(|e| panic!("get_wasms error: {:?}", e));
-        if !state.banned
+            .context("get_wasms")?;
+        if state.block_banned.is_none_or(|h| h > details.height)
             && details.round >= *state.round_pushed.as_ref().unwrap_or(&round_pushed)
             && wasm.first().is_some_and(|w| w.details.compile_success)
         {
-            algorithm.block_data = Some(AlgorithmBlockData {
-                reward: None,
-                adoption: None,
-                merge_points: None,
-                num_qualifiers_by_player: None,
-                round_earnings: None,
-            });
+            algorithm.block_data = Some(new_algorithm_block_data());
             if state.round_pushed.is_none() {
                 state.round_pushed = Some(round_pushed);
             }
@@ -195,12 +278,12 @@ async fn setup_cache<T: Context>(
     for benchmark in ctx
         .get_benchmarks(BenchmarksFilter::Confirmed { from_block_started }, false)
         .await
-        .unwrap_or_else(|e| panic!("get_benchmarks error: {:?}", e))
+        .context("get_benchmarks")?
     {
         let proof = ctx
             .get_proofs(ProofsFilter::BenchmarkId(benchmark.id.clone()), false)
             .await
-            .unwrap_or_else(|e| panic!("get_proofs error: {:?}", e))
+            .context("get_proofs")?
             .pop();
         if !proof.as_ref().is_some_and(|p| p.state.is_some()) {
             continue;
@@ -208,7 +291,7 @@ async fn setup_cache<T: Context>(
         let fraud = ctx
             .get_frauds(FraudsFilter::BenchmarkId(benchmark.id.clone()), false)
             .await
-            .unwrap_or_else(|e| panic!("get_frauds error: {:?}", e))
+            .context("get_frauds")?
             .pop();
         if fraud.is_some_and(|f| f.state.is_some()) {
             continue;
@@ -223,6 +306,31 @@ async fn setup_cache<T: Context>(
             active_benchmarks.insert(benchmark.id.clone(), benchmark);
         }
     }
+    let (slashed_benchmark_ids_by_player, banned_player_ids) = match &config.slashing {
+        Some(slashing) => {
+            // mempool frauds are confirmed by this block
+            let mut frauds_block_confirmed = mempool_frauds
+                .iter()
+                .map(|f| (f.benchmark_id.clone(), details.height))
+                .collect::<Vec<(String, u32)>>();
+            for fraud in ctx
+                .get_frauds(
+                    FraudsFilter::Confirmed {
+                        from_block_started: from_block_started
+                            .saturating_sub(slashing.ban_period.unwrap_or(0)),
+                    },
+                    false,
+                )
+                .await
+                .context("get_frauds")?
+            {
+                frauds_block_confirmed
+                    .push((fraud.benchmark_id.clone(), *fraud.state().block_confirmed()));
+            }
+            get_slashed_and_banned_players(ctx, details, slashing, frauds_block_confirmed).await?
+        }
+        None => (HashMap::new(), HashSet::new()),
+    };
     let mut active_players = HashMap::new();
     for benchmark in active_benchmarks.values() {
         let mut player = ctx
@@ -231,33 +339,59 @@ async fn setup_cache<T: Context>(
                 None,
             )
             .await
-            .unwrap()
+            .context("get_players")?
             .pop()
-            .unwrap();
-        player.block_data = Some(PlayerBlockData {
-            reward: None,
-            influence: None,
-            cutoff: None,
-            imbalance: None,
-            imbalance_penalty: None,
-            num_qualifiers_by_challenge: None,
-            round_earnings: None,
-            deposit: None,
-            rolling_deposit: None,
-        });
+            .ok_or_else(|| ProtocolError::InvalidPlayer {
+                player_id: benchmark.settings.player_id.clone(),
+            })?;
+        player.block_data = Some(new_player_block_data(
+            config,
+            &slashed_benchmark_ids_by_player,
+            &player.id,
+        ));
         active_players.insert(player.id.clone(), player);
     }
+    // players slashed by this block are slashed whether or not they are active
+    let mut slashed_players = HashMap::new();
+    let mut slashable_rewards_by_player = HashMap::new();
+    if let Some(slashing) = &config.slashing {
+        for player_id in slashed_benchmark_ids_by_player.keys() {
+            slashable_rewards_by_player.insert(
+                player_id.clone(),
+                get_paid_rewards(ctx, details, slashing.window, player_id).await?,
+            );
+            if active_players.contains_key(player_id) {
+                continue;
+            }
+            let mut player = ctx
+                .get_players(PlayersFilter::Id(player_id.clone()), None)
+                .await
+                .context("get_players")?
+                .pop()
+                .ok_or_else(|| ProtocolError::InvalidPlayer {
+                    player_id: player_id.clone(),
+                })?;
+            player.block_data = Some(new_player_block_data(
+                config,
+                &slashed_benchmark_ids_by_player,
+                &player.id,
+            ));
+            slashed_players.insert(player.id.clone(), player);
+        }
+    }
     let mut prev_players = HashMap::<String, Player>::new();
-    for player_id in active_players.keys() {
+    for player_id in active_players.keys().chain(slashed_players.keys()) {
         let player = ctx
             .get_players(
                 PlayersFilter::Id(player_id.clone()),
                 Some(BlockFilter::Id(details.prev_block_id.clone())),
             )
             .await
-            .unwrap()
+            .context("get_players")?
             .pop()
-            .unwrap();
+            .ok_or_else(|| ProtocolError::InvalidPlayer {
+                player_id: player_id.clone(),
+            })?;
         prev_players.insert(player.id.clone(), player);
     }
     let mut prev_algorithms = HashMap::<String, Algorithm>::new();
@@ -269,9 +403,11 @@ async fn setup_cache<T: Context>(
                 false,
             )
             .await
-            .unwrap()
+            .context("get_algorithms")?
             .pop()
-            .unwrap();
+            .ok_or_else(|| ProtocolError::InvalidAlgorithm {
+                algorithm_id: algorithm_id.clone(),
+            })?;
         prev_algorithms.insert(algorithm_id.clone(), algorithm);
     }
     let mut prev_challenges = HashMap::<String, Challenge>::new();
@@ -282,49 +418,293 @@ async fn setup_cache<T: Context>(
                 Some(BlockFilter::Id(details.prev_block_id.clone())),
             )
             .await
-            .unwrap()
+            .context("get_challenges")?
             .pop()
-            .unwrap();
+            .ok_or_else(|| ProtocolError::InvalidChallenge {
+                challenge_id: challenge_id.clone(),
+            })?;
         prev_challenges.insert(challenge_id.clone(), challenge);
     }
-    AddBlockCache {
+    let (prev_round_earnings_by_player, prev_round_earnings_by_algorithm) =
+        get_prev_round_earnings(ctx, details, config, &prev_players, &prev_algorithms).await?;
+    Ok(AddBlockCache {
         mempool_challenges,
         mempool_algorithms,
         mempool_benchmarks,
         mempool_proofs,
         mempool_frauds,
         mempool_wasms,
+        mempool_breakthroughs,
         active_challenges,
         active_algorithms,
         active_benchmarks,
         active_players,
+        slashed_players,
+        active_breakthroughs,
+        pending_breakthroughs,
         prev_challenges,
         prev_algorithms,
         prev_players,
+        prev_round_earnings_by_player,
+        prev_round_earnings_by_algorithm,
+        banned_player_ids,
+        slashable_rewards_by_player,
+    })
+}
+
+fn new_challenge_block_data() -> ChallengeBlockData {
+    ChallengeBlockData {
+        num_qualifiers: None,
+        solution_signature_threshold: None,
+        scaled_frontier: None,
+        base_frontier: None,
+        cutoff_frontier: None,
+        scaling_factor: None,
+        qualifier_difficulties: None,
+    }
+}
+
+fn new_algorithm_block_data() -> AlgorithmBlockData {
+    AlgorithmBlockData {
+        reward: None,
+        adoption: None,
+        merge_points: None,
+        num_qualifiers_by_player: None,
+        round_earnings: None,
+    }
+}
+
+fn new_player_block_data(
+    config: &ProtocolConfig,
+    slashed_benchmark_ids_by_player: &HashMap<String, HashSet<String>>,
+    player_id: &String,
+) -> PlayerBlockData {
+    PlayerBlockData {
+        reward: None,
+        influence: None,
+        cutoff: None,
+        imbalance: None,
+        imbalance_penalty: None,
+        num_qualifiers_by_challenge: None,
+        round_earnings: None,
+        deposit: None,
+        rolling_deposit: None,
+        slashed_benchmark_ids: config.slashing.as_ref().map(|_| {
+            slashed_benchmark_ids_by_player
+                .get(player_id)
+                .cloned()
+                .unwrap_or_default()
+        }),
+        slashed_reward: None,
+    }
+}
+
+// Returns the benchmark ids each player is slashed for by this block, and the players banned from
+// qualifying, given the block each fraud was confirmed in
+async fn get_slashed_and_banned_players<T: Context>(
+    ctx: &T,
+    details: &BlockDetails,
+    slashing: &SlashingConfig,
+    frauds_block_confirmed: Vec<(String, u32)>,
+) -> ProtocolResult<(HashMap<String, HashSet<String>>, HashSet<String>)> {
+    let ban_period = slashing.ban_period.unwrap_or(0);
+    let mut slashed_benchmark_ids_by_player = HashMap::<String, HashSet<String>>::new();
+    let mut banned_player_ids = HashSet::<String>::new();
+    for (benchmark_id, block_confirmed) in frauds_block_confirmed {
+        let num_blocks_since = details.height - block_confirmed;
+        if num_blocks_since > 0 && num_blocks_since >= ban_period {
+            continue;
+        }
+        let player_id = ctx
+            .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
+            .await
+            .context("get_benchmarks")?
+            .pop()
+            .ok_or_else(|| ProtocolError::InvalidBenchmark {
+                benchmark_id: benchmark_id.clone(),
+            })?
+            .settings
+            .player_id;
+        if num_blocks_since < ban_period {
+            banned_player_ids.insert(player_id.clone());
+        }
+        if num_blocks_since == 0 {
+            slashed_benchmark_ids_by_player
+                .entry(player_id)
+                .or_default()
+                .insert(benchmark_id);
+        }
+    }
+    Ok((slashed_benchmark_ids_by_player, banned_player_ids))
+}
+
+// Returns the rewards paid to a player in the `window` blocks before the block at `details`
+async fn get_paid_rewards<T: Context>(
+    ctx: &T,
+    details: &BlockDetails,
+    window: u32,
+    player_id: &str,
+) -> ProtocolResult<PreciseNumber> {
+    let mut paid_rewards = PreciseNumber::from(0);
+    for height in details.height.saturating_sub(window)..details.height {
+        let player = ctx
+            .get_players(
+                PlayersFilter::Id(player_id.to_string()),
+                Some(BlockFilter::Height(height)),
+            )
+            .await
+            .context("get_players")?
+            .pop()
+            .ok_or_else(|| ProtocolError::InvalidPlayer {
+                player_id: player_id.to_string(),
+            })?;
+        if let Some(reward) = player.block_data.and_then(|d| d.reward) {
+            paid_rewards = paid_rewards + reward;
+        }
     }
+    Ok(paid_rewards)
+}
+
+// Returns each player's & algorithm's earnings so far this round, excluding the block at `details`.
+// An entity without block data in the previous block carries its round earnings from the latest
+// earlier block of the round where it had block data
+async fn get_prev_round_earnings<T: Context>(
+    ctx: &T,
+    details: &BlockDetails,
+    config: &ProtocolConfig,
+    prev_players: &HashMap<String, Player>,
+    prev_algorithms: &HashMap<String, Algorithm>,
+) -> ProtocolResult<(
+    HashMap<String, PreciseNumber>,
+    HashMap<String, PreciseNumber>,
+)> {
+    let mut prev_round_earnings_by_player = HashMap::<String, PreciseNumber>::new();
+    let mut prev_round_earnings_by_algorithm = HashMap::<String, PreciseNumber>::new();
+    // first block of the round
+    let round_start = details.height - details.height % config.rounds.blocks_per_round;
+    if details.height == round_start {
+        return Ok((
+            prev_round_earnings_by_player,
+            prev_round_earnings_by_algorithm,
+        ));
+    }
+    let zero = PreciseNumber::from(0);
+    let mut pending_player_ids = Vec::new();
+    for (player_id, player) in prev_players.iter() {
+        match &player.block_data {
+            Some(data) => {
+                prev_round_earnings_by_player
+                    .insert(player_id.clone(), data.round_earnings.unwrap_or(zero));
+            }
+            None => pending_player_ids.push(player_id.clone()),
+        }
+    }
+    let mut pending_algorithm_ids = Vec::new();
+    for (algorithm_id, algorithm) in prev_algorithms.iter() {
+        match &algorithm.block_data {
+            Some(data) => {
+                prev_round_earnings_by_algorithm
+                    .insert(algorithm_id.clone(), data.round_earnings.unwrap_or(zero));
+            }
+            None => pending_algorithm_ids.push(algorithm_id.clone()),
+        }
+    }
+    // the previous block is at height - 1, so the walk back starts before it
+    for height in (round_start..details.height - 1).rev() {
+        if pending_player_ids.is_empty() && pending_algorithm_ids.is_empty() {
+            break;
+        }
+        let block_data = Some(BlockFilter::Height(height));
+        let mut still_pending_player_ids = Vec::new();
+        for player_id in pending_player_ids {
+            let player = ctx
+                .get_players(PlayersFilter::Id(player_id.clone()), block_data.clone())
+                .await
+                .context("get_players")?
+                .pop()
+                .ok_or_else(|| ProtocolError::InvalidPlayer {
+                    player_id: player_id.clone(),
+                })?;
+            match player.block_data {
+                Some(data) => {
+                    prev_round_earnings_by_player
+                        .insert(player_id, data.round_earnings.unwrap_or(zero));
+                }
+                None => still_pending_player_ids.push(player_id),
+            }
+        }
+        pending_player_ids = still_pending_player_ids;
+        let mut still_pending_algorithm_ids = Vec::new();
+        for algorithm_id in pending_algorithm_ids {
+            let algorithm = ctx
+                .get_algorithms(
+                    AlgorithmsFilter::Id(algorithm_id.clone()),
+                    block_data.clone(),
+                    false,
+                )
+                .await
+                .context("get_algorithms")?
+                .pop()
+                .ok_or_else(|| ProtocolError::InvalidAlgorithm {
+                    algorithm_id: algorithm_id.clone(),
+                })?;
+            match algorithm.block_data {
+                Some(data) => {
+                    prev_round_earnings_by_algorithm
+                        .insert(algorithm_id, data.round_earnings.unwrap_or(zero));
+                }
+                None => still_pending_algorithm_ids.push(algorithm_id),
+            }
+        }
+        pending_algorithm_ids = still_pending_algorithm_ids;
+    }
+    Ok((
+        prev_round_earnings_by_player,
+        prev_round_earnings_by_algorithm,
+    ))
 }
 
 #[time]
-async fn create_block<T: Context>(ctx: &T) -> (Block, AddBlockCache) {
+async fn create_block<T: Context>(ctx: &T) -> ProtocolResult<(Block, AddBlockCache)> {
     let latest_block = ctx
         .get_block(BlockFilter::Latest, false)
         .await
-        .unwrap_or_else(|e| panic!("get_block error: {:?}", e))
-        .expect("No latest block found");
-    let config = ctx
-        .get_config()
-        .await
-        .unwrap_or_else(|e| panic!("get_config error: {:?}", e));
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
+    let config = ctx.get_config().await.context("get_config")?;
     let height = latest_block.details.height + 1;
     let details = BlockDetails {
         prev_block_id: latest_block.id.clone(),
         height,
         round: height / config.rounds.blocks_per_round + 1,
-        eth_block_num: Some(ctx.get_latest_eth_block_num().await.unwrap()),
+        eth_block_num: Some(
+            ctx.get_latest_eth_block_num()
+                .await
+                .context("get_latest_eth_block_num")?,
+        ),
     };
-    let cache = setup_cache(ctx, &details, &config).await;
+    let cache = setup_cache(ctx, &details, &config).await?;
+
+    let data = new_block_data(&cache);
+
+    let block_id = ctx
+        .add_block(details.clone(), data.clone(), config.clone())
+        .await
+        .context("add_block")?;
 
-    let data = BlockData {
+    Ok((
+        Block {
+            id: block_id,
+            config: Some(config.clone()),
+            details,
+            data: Some(data),
+        },
+        cache,
+    ))
+}
+
+pub(crate) fn new_block_data(cache: &AddBlockCache) -> BlockData {
+    BlockData {
         mempool_challenge_ids: cache
             .mempool_challenges
             .iter()
@@ -359,22 +739,7 @@ async fn create_block<T: Context>(ctx: &T) -> (Block, AddBlockCache) {
         active_algorithm_ids: cache.active_algorithms.keys().cloned().collect(),
         active_benchmark_ids: cache.active_benchmarks.keys().cloned().collect(),
         active_player_ids: cache.active_players.keys().cloned().collect(),
-    };
-
-    let block_id = ctx
-        .add_block(details.clone(), data.clone(), config.clone())
-        .await
-        .unwrap_or_else(|e| panic!("add_block error: {:?}", e));
-
-    (
-        Block {
-            id: block_id,
-            config: Some(config.clone()),
-            details,
-            data: Some(data),
-        },
-        cache,
-    )
+    }
 }
 
 #[time]
@@ -418,23 +783,30 @@ async fn confirm_mempool_benchmarks(block: &Block, cache: &mut AddBlockCache) {
 }
 
 #[time]
-async fn confirm_mempool_proofs<T: Context>(ctx: &T, block: &Block, cache: &mut AddBlockCache) {
+async fn confirm_mempool_proofs<T: Context>(
+    ctx: &T,
+    block: &Block,
+    cache: &mut AddBlockCache,
+) -> ProtocolResult<()> {
     for proof in cache.mempool_proofs.iter_mut() {
         let benchmark = ctx
             .get_benchmarks(BenchmarksFilter::Id(proof.benchmark_id.clone()), false)
             .await
-            .unwrap_or_else(|e| panic!("get_benchmarks error: {:?}", e))
+            .context("get_benchmarks")?
             .pop()
-            .unwrap();
+            .ok_or_else(|| ProtocolError::InvalidBenchmark {
+                benchmark_id: proof.benchmark_id.clone(),
+            })?;
         let state = proof.state.as_mut().unwrap();
         state.block_confirmed = Some(block.details.height);
         state.submission_delay = Some(block.details.height - benchmark.details.block_started);
     }
+    Ok(())
 }
 
 #[time]
 async fn confirm_mempool_frauds(block: &Block, cache: &mut AddBlockCache) {
-    // Future Todo: slash player's rewards from past day
+    // players are slashed by setup_cache & update_slashing
     for fraud in cache.mempool_frauds.iter_mut() {
         let state = fraud.state.as_mut().unwrap();
         state.block_confirmed = Some(block.details.height);
@@ -450,14 +822,110 @@ async fn confirm_mempool_wasms(block: &Block, cache: &mut AddBlockCache) {
 }
 
 #[time]
-async fn update_deposits<T: Context>(ctx: &T, block: &Block, cache: &mut AddBlockCache) {
+async fn confirm_mempool_breakthroughs(block: &Block, cache: &mut AddBlockCache) {
+    for breakthrough in cache.mempool_breakthroughs.iter_mut() {
+        let state = breakthrough.state.as_mut().unwrap();
+        state.block_confirmed = Some(block.details.height);
+        state.round_submitted = Some(block.details.round);
+    }
+}
+
+#[time]
+async fn update_breakthrough_votes<T: Context>(
+    ctx: &T,
+    block: &Block,
+    cache: &mut AddBlockCache,
+) -> ProtocolResult<()> {
+    let config = block.config();
+    let breakthroughs_config = match &config.breakthroughs {
+        Some(breakthroughs_config) => breakthroughs_config,
+        None => return Ok(()),
+    };
+
+    // first block of the round
+    if block.details.height % config.rounds.blocks_per_round != 0 {
+        return Ok(());
+    }
+
+    let round = block.details.round;
+    let zero = PreciseNumber::from(0);
+    let approval_threshold = PreciseNumber::from_f64(breakthroughs_config.approval_threshold);
+    for breakthrough in cache.pending_breakthroughs.values_mut() {
+        let state = breakthrough.state.as_mut().unwrap();
+        if state.round_voting_starts.is_none() {
+            let algorithm = ctx
+                .get_algorithms(
+                    AlgorithmsFilter::Id(breakthrough.details.algorithm_id.clone()),
+                    None,
+                    false,
+                )
+                .await
+                .context("get_algorithms")?
+                .pop()
+                .ok_or_else(|| ProtocolError::InvalidAlgorithm {
+                    algorithm_id: breakthrough.details.algorithm_id.clone(),
+                })?;
+            // voting starts once the breakthrough's algorithm is merged
+            if algorithm.state().round_merged.is_some_and(|r| r <= round) {
+                state.round_voting_starts = Some(round);
+            }
+        }
+        match state.round_voting_starts {
+            Some(r) if r + breakthroughs_config.vote_period <= round => {}
+            _ => continue,
+        }
+
+        let mut votes_for = zero;
+        let mut votes_against = zero;
+        for vote in ctx.get_votes(&breakthrough.id).await.context("get_votes")? {
+            let weight = match breakthroughs_config.vote_weight {
+                VoteWeight::Deposit => ctx
+                    .get_player_deposit(block.details.eth_block_num(), &vote.player_id)
+                    .await
+                    .context("get_player_deposit")?,
+                VoteWeight::Influence => ctx
+                    .get_players(
+                        PlayersFilter::Id(vote.player_id.clone()),
+                        Some(BlockFilter::Id(block.details.prev_block_id.clone())),
+                    )
+                    .await
+                    .context("get_players")?
+                    .pop()
+                    .and_then(|p| p.block_data)
+                    .and_then(|d| d.influence),
+            }
+            .unwrap_or(zero);
+            if vote.approve {
+                votes_for = votes_for + weight;
+            } else {
+                votes_against = votes_against + weight;
+            }
+        }
+
+        let total_votes = votes_for + votes_against;
+        state.round_votes_tallied = Some(round);
+        state.votes_for = Some(votes_for);
+        state.votes_against = Some(votes_against);
+        if total_votes > zero && votes_for >= total_votes * approval_threshold {
+            state.round_active = Some(round);
+        }
+    }
+    Ok(())
+}
+
+#[time]
+async fn update_deposits<T: Context>(
+    ctx: &T,
+    block: &Block,
+    cache: &mut AddBlockCache,
+) -> ProtocolResult<()> {
     let decay = match &block
         .config()
         .optimisable_proof_of_work
         .rolling_deposit_decay
     {
         Some(decay) => PreciseNumber::from_f64(*decay),
-        None => return, // Proof of deposit not implemented for these blocks
+        None => return Ok(()), // Proof of deposit not implemented for these blocks
     };
     let eth_block_num = block.details.eth_block_num();
     let zero = PreciseNumber::from(0);
@@ -473,11 +941,12 @@ async fn update_deposits<T: Context>(ctx: &T, block: &Block, cache: &mut AddBloc
         let deposit = ctx
             .get_player_deposit(eth_block_num, &player.id)
             .await
-            .unwrap()
+            .context("get_player_deposit")?
             .unwrap_or_else(|| zero.clone());
         data.rolling_deposit = Some(decay * rolling_deposit + (one - decay) * deposit);
         data.deposit = Some(deposit);
     }
+    Ok(())
 }
 
 #[time]
@@ -612,52 +1081,16 @@ async fn update_solution_signature_thresholds(block: &Block, cache: &mut AddBloc
     }
 }
 
-fn find_smallest_range_dimension(points: &Frontier) -> usize {
-    (0..2)
-        .min_by_key(|&d| {
-            let (min, max) = points
-                .iter()
-                .map(|p| p[d])
-                .fold((i32::MAX, i32::MIN), |(min, max), val| {
-                    (min.min(val), max.max(val))
-                });
-            max - min
-        })
-        .unwrap()
-}
-
+// Peels `points` into successive pareto frontiers, hardest first. Only the first frontier is
+// computed if `only_one` is set
 fn pareto_algorithm(points: Frontier, only_one: bool) -> Vec<Frontier> {
     if points.is_empty() {
         return Vec::new();
     }
-    let dimension = find_smallest_range_dimension(&points);
-    let sort_dimension = 1 - dimension;
-
-    let mut buckets: HashMap<i32, Vec<Point>> = HashMap::new();
-    for point in points {
-        buckets.entry(point[dimension]).or_default().push(point);
-    }
-    for (_, group) in buckets.iter_mut() {
-        // sort descending
-        group.sort_unstable_by(|a, b| b[sort_dimension].cmp(&a[sort_dimension]));
-    }
-    let mut result = Vec::new();
-    while !buckets.is_empty() {
-        let points: HashSet<Point> = buckets.values().map(|group| group[0].clone()).collect();
-        let frontier = points.pareto_frontier();
-        for point in frontier.iter() {
-            let bucket = buckets.get_mut(&point[dimension]).unwrap();
-            bucket.remove(0);
-            if bucket.is_empty() {
-                buckets.remove(&point[dimension]);
-            }
-        }
-        result.push(frontier);
-        if only_one {
-            break;
-        }
+    if only_one {
+        return vec![points.pareto_frontier()];
     }
-    result
+    points.pareto_frontiers()
 }
 
 #[time]
@@ -735,6 +1168,11 @@ async fn update_qualifiers(block: &Block, cache: &mut AddBlockCache) {
             {
                 continue;
             }
+            if cache.banned_player_ids.contains(player_id)
+                || !cache.active_algorithms.contains_key(algorithm_id)
+            {
+                continue;
+            }
             curr_frontier_index = frontier_indexes[difficulty];
             let player_data = cache
                 .active_players
@@ -795,7 +1233,7 @@ async fn update_frontiers(block: &Block, cache: &mut AddBlockCache) {
             .collect::<Frontier>();
         let cutoff_frontier = pareto_algorithm(points, true)
             .pop()
-            .unwrap()
+            .unwrap_or_default() // no qualifiers yet
             .into_iter()
             .map(|d| d.into_iter().map(|x| -x).collect())
             .collect::<Frontier>() // mirror the points back;
@@ -853,15 +1291,10 @@ async fn update_influence(block: &Block, cache: &mut AddBlockCache) {
     let total_deposit = cache
         .active_players
         .values()
-        .map(|p| p.block_data().deposit.clone().unwrap())
+        .filter_map(|p| p.block_data().deposit.clone()) // unset without proof of deposit
         .sum::<PreciseNumber>();
 
     let zero = PreciseNumber::from(0);
-    let one = PreciseNumber::from(1);
-    let imbalance_multiplier =
-        PreciseNumber::from_f64(config.optimisable_proof_of_work.imbalance_multiplier);
-    let num_challenges = PreciseNumber::from(cache.active_challenges.len());
-
     let mut weights = Vec::<PreciseNumber>::new();
     for player_id in active_player_ids.iter() {
         let data = cache
@@ -899,19 +1332,12 @@ async fn update_influence(block: &Block, cache: &mut AddBlockCache) {
             });
         }
 
-        let mean = percent_qualifiers.arithmetic_mean();
-        let variance = percent_qualifiers.variance();
-        let cv_sqr = if mean == zero {
-            zero.clone()
-        } else {
-            variance / (mean * mean)
-        };
-
-        let imbalance = cv_sqr / (num_challenges - one);
-        let imbalance_penalty =
-            one - PreciseNumber::approx_inv_exp(imbalance_multiplier * imbalance);
-
-        weights.push(mean * (one - imbalance_penalty));
+        let (imbalance, imbalance_penalty, weight) = calc_influence_weight(
+            &percent_qualifiers,
+            cache.active_challenges.len(),
+            config.optimisable_proof_of_work.imbalance_multiplier,
+        );
+        weights.push(weight);
 
         data.imbalance = Some(imbalance);
         data.imbalance_penalty = Some(imbalance_penalty);
@@ -930,6 +1356,59 @@ async fn update_influence(block: &Block, cache: &mut AddBlockCache) {
     }
 }
 
+#[time]
+async fn update_slashing(block: &Block, cache: &mut AddBlockCache) {
+    let config = block.config();
+    let fraction = match &config.slashing {
+        Some(slashing) => PreciseNumber::from_f64(slashing.fraction),
+        None => return,
+    };
+
+    for player in cache
+        .active_players
+        .values_mut()
+        .chain(cache.slashed_players.values_mut())
+    {
+        let data = player.block_data.as_mut().unwrap();
+        if data.slashed_benchmark_ids().is_empty() {
+            continue;
+        }
+        // taken back from the player's round earnings by update_round_earnings
+        let paid_rewards = cache.slashable_rewards_by_player[&player.id];
+        data.slashed_reward = Some(paid_rewards * fraction);
+    }
+}
+
+// Returns (imbalance, imbalance_penalty, weight) of a player. Influence is the weight normalised
+// across all active players
+pub fn calc_influence_weight(
+    percent_qualifiers: &Vec<PreciseNumber>,
+    num_challenges: usize,
+    imbalance_multiplier: f64,
+) -> (PreciseNumber, PreciseNumber, PreciseNumber) {
+    let zero = PreciseNumber::from(0);
+    let one = PreciseNumber::from(1);
+    let imbalance_multiplier = PreciseNumber::from_f64(imbalance_multiplier);
+    let num_challenges = PreciseNumber::from(num_challenges);
+
+    let mean = percent_qualifiers.arithmetic_mean();
+    let variance = percent_qualifiers.variance();
+    let cv_sqr = if mean == zero {
+        zero.clone()
+    } else {
+        variance / (mean * mean)
+    };
+
+    let imbalance = cv_sqr / (num_challenges - one);
+    let imbalance_penalty = one - PreciseNumber::approx_inv_exp(imbalance_multiplier * imbalance);
+
+    (
+        imbalance,
+        imbalance_penalty,
+        mean * (one - imbalance_penalty),
+    )
+}
+
 #[time]
 async fn update_adoption(block: &Block, cache: &mut AddBlockCache) {
     let mut algorithms_by_challenge = HashMap::<String, Vec<&mut Algorithm>>::new();
@@ -1021,11 +1500,25 @@ async fn update_innovator_rewards(block: &Block, cache: &mut AddBlockCache) {
 }
 
 #[time]
-async fn update_benchmarker_rewards(block: &Block, cache: &mut AddBlockCache) {
+async fn update_breakthrough_rewards(block: &Block, cache: &mut AddBlockCache) {
+    if cache.active_breakthroughs.is_empty() {
+        return;
+    }
     let config = block.config();
 
-    let reward_pool = PreciseNumber::from_f64(get_block_reward(block))
-        * PreciseNumber::from_f64(config.rewards.distribution.benchmarkers);
+    let reward_pool_per_challenge = PreciseNumber::from_f64(get_block_reward(block))
+        * PreciseNumber::from_f64(config.rewards.distribution.breakthroughs)
+        / PreciseNumber::from(cache.active_challenges.len());
+
+    for breakthrough in cache.active_breakthroughs.values_mut() {
+        let data = breakthrough.block_data.as_mut().unwrap();
+        data.reward = Some(reward_pool_per_challenge);
+    }
+}
+
+#[time]
+async fn update_benchmarker_rewards(block: &Block, cache: &mut AddBlockCache) {
+    let reward_pool = get_benchmarkers_reward_pool(block, cache);
 
     for player in cache.active_players.values_mut() {
         let data = player.block_data.as_mut().unwrap();
@@ -1034,6 +1527,37 @@ async fn update_benchmarker_rewards(block: &Block, cache: &mut AddBlockCache) {
     }
 }
 
+#[time]
+async fn update_round_earnings(_block: &Block, cache: &mut AddBlockCache) {
+    let zero = PreciseNumber::from(0);
+
+    for player in cache
+        .active_players
+        .values_mut()
+        .chain(cache.slashed_players.values_mut())
+    {
+        let prev_round_earnings = cache
+            .prev_round_earnings_by_player
+            .get(&player.id)
+            .cloned()
+            .unwrap_or(zero);
+        let data = player.block_data.as_mut().unwrap();
+        let round_earnings = prev_round_earnings + data.reward.unwrap_or(zero);
+        // a slash takes back at most what the player has earned this round
+        let slashed_reward = data.slashed_reward.unwrap_or(zero).min(round_earnings);
+        data.round_earnings = Some(round_earnings - slashed_reward);
+    }
+    for algorithm in cache.active_algorithms.values_mut() {
+        let prev_round_earnings = cache
+            .prev_round_earnings_by_algorithm
+            .get(&algorithm.id)
+            .cloned()
+            .unwrap_or(zero);
+        let data = algorithm.block_data.as_mut().unwrap();
+        data.round_earnings = Some(prev_round_earnings + *data.reward());
+    }
+}
+
 #[time]
 async fn update_merge_points(block: &Block, cache: &mut AddBlockCache) {
     let config = block.config();
@@ -1098,58 +1622,115 @@ async fn update_merges(block: &Block, cache: &mut AddBlockCache) {
 }
 
 #[time]
-async fn commit_changes<T: Context>(ctx: &T, block: &Block, cache: &mut AddBlockCache) {
+async fn commit_changes<T: Context>(
+    ctx: &T,
+    block: &Block,
+    cache: &mut AddBlockCache,
+) -> ProtocolResult<()> {
     for algorithm in cache.mempool_algorithms.drain(..) {
         ctx.update_algorithm_state(&algorithm.id, algorithm.state.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_algorithm_state error: {:?}", e));
+            .context("update_algorithm_state")?;
     }
     for challenge in cache.mempool_challenges.drain(..) {
         ctx.update_challenge_state(&challenge.id, challenge.state.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_challenge_state error: {:?}", e));
+            .context("update_challenge_state")?;
     }
     for benchmark in cache.mempool_benchmarks.drain(..) {
         ctx.update_benchmark_state(&benchmark.id, benchmark.state.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_benchmark_state error: {:?}", e));
+            .context("update_benchmark_state")?;
     }
     for fraud in cache.mempool_frauds.drain(..) {
         ctx.update_fraud_state(&fraud.benchmark_id, fraud.state.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_fraud_state error: {:?}", e));
+            .context("update_fraud_state")?;
     }
     for proof in cache.mempool_proofs.drain(..) {
         ctx.update_proof_state(&proof.benchmark_id, proof.state.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_proof_state error: {:?}", e));
+            .context("update_proof_state")?;
     }
     for wasm in cache.mempool_wasms.drain(..) {
         ctx.update_wasm_state(&wasm.algorithm_id, wasm.state.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_wasm_state error: {:?}", e));
+            .context("update_wasm_state")?;
+    }
+    for breakthrough in cache.mempool_breakthroughs.drain(..) {
+        ctx.update_breakthrough_state(&breakthrough.id, breakthrough.state.unwrap())
+            .await
+            .context("update_breakthrough_state")?;
+    }
+    for (_, breakthrough) in cache.pending_breakthroughs.drain() {
+        ctx.update_breakthrough_state(&breakthrough.id, breakthrough.state.unwrap())
+            .await
+            .context("update_breakthrough_state")?;
+    }
+    for (_, breakthrough) in cache.active_breakthroughs.drain() {
+        ctx.update_breakthrough_block_data(
+            &breakthrough.id,
+            &block.id,
+            breakthrough.block_data.unwrap(),
+        )
+        .await
+        .context("update_breakthrough_block_data")?;
     }
     for (_, algorithm) in cache.active_algorithms.drain() {
         let state = algorithm.state.unwrap();
-        if state.round_pushed.is_some_and(|r| r == block.details.round) {
+        if is_algorithm_state_updated(block, &state) {
             ctx.update_algorithm_state(&algorithm.id, state)
                 .await
-                .unwrap_or_else(|e| panic!("update_algorithm_state error: {:?}", e));
+                .context("update_algorithm_state")?;
         }
         ctx.update_algorithm_block_data(&algorithm.id, &block.id, algorithm.block_data.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_algorithm_block_data error: {:?}", e));
+            .context("update_algorithm_block_data")?;
     }
     for (_, challenge) in cache.active_challenges.drain() {
         ctx.update_challenge_block_data(&challenge.id, &block.id, challenge.block_data.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_challenge_block_data error: {:?}", e));
+            .context("update_challenge_block_data")?;
     }
-    for (_, player) in cache.active_players.drain() {
+    for (_, player) in cache
+        .active_players
+        .drain()
+        .chain(cache.slashed_players.drain())
+    {
         ctx.update_player_block_data(&player.id, &block.id, player.block_data.unwrap())
             .await
-            .unwrap_or_else(|e| panic!("update_player_block_data error: {:?}", e));
+            .context("update_player_block_data")?;
     }
+    Ok(())
+}
+
+// an active algorithm's state only changes in the round it is pushed, and at the last block of the
+// round in which it is merged (update_merges sets round_merged to the next round)
+pub(crate) fn is_algorithm_state_updated(block: &Block, state: &AlgorithmState) -> bool {
+    state.round_pushed.is_some_and(|r| r == block.details.round)
+        || state
+            .round_merged
+            .is_some_and(|r| r == block.details.round + 1)
+}
+
+// the breakthroughs share of challenges without an active breakthrough reverts to benchmarkers
+fn get_benchmarkers_reward_pool(block: &Block, cache: &AddBlockCache) -> PreciseNumber {
+    let config = block.config();
+    let distribution = &config.rewards.distribution;
+    let block_reward = PreciseNumber::from_f64(get_block_reward(block));
+    // Without breakthroughs enabled, the breakthroughs share is never paid out
+    if config.breakthroughs.is_none() {
+        return block_reward * PreciseNumber::from_f64(distribution.benchmarkers);
+    }
+    let num_challenges = cache.active_challenges.len();
+    let unclaimed_breakthroughs = if num_challenges == 0 {
+        PreciseNumber::from_f64(distribution.breakthroughs)
+    } else {
+        PreciseNumber::from_f64(distribution.breakthroughs)
+            * PreciseNumber::from(num_challenges - cache.active_breakthroughs.len())
+            / PreciseNumber::from(num_challenges)
+    };
+    block_reward * (PreciseNumber::from_f64(distribution.benchmarkers) + unclaimed_breakthroughs)
 }
 
 fn get_block_reward(block: &Block) -> f64 {
@@ -0,0 +1,55 @@
+use crate::{context::*, error::*};
+use anyhow::Context as _;
+use logging_timer::time;
+use tig_structs::core::*;
+
+// The ban takes effect from the next block, when the algorithm is no longer active
+#[time]
+pub(crate) async fn execute<T: Context>(
+    ctx: &T,
+    player: &Player,
+    algorithm_id: &String,
+    reason: String,
+) -> ProtocolResult<()> {
+    let latest_block = ctx
+        .get_block(BlockFilter::Latest, false)
+        .await
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
+    verify_player_is_admin(&latest_block, player)?;
+    let algorithm = get_algorithm_by_id(ctx, algorithm_id).await?;
+    let mut state = algorithm.state.unwrap();
+    state.banned = true;
+    state.block_banned = Some(latest_block.details.height + 1);
+    state.ban_reason = Some(reason);
+    ctx.update_algorithm_state(algorithm_id, state)
+        .await
+        .context("update_algorithm_state")?;
+    Ok(())
+}
+
+#[time]
+fn verify_player_is_admin(latest_block: &Block, player: &Player) -> ProtocolResult<()> {
+    let admin_address = &latest_block.config().algorithm_submissions.admin_address;
+    if admin_address.as_ref() != Some(&player.id) {
+        return Err(ProtocolError::UnauthorisedPlayer {
+            player_id: player.id.clone(),
+        });
+    }
+    Ok(())
+}
+
+#[time]
+async fn get_algorithm_by_id<T: Context>(
+    ctx: &T,
+    algorithm_id: &String,
+) -> ProtocolResult<Algorithm> {
+    ctx.get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
+        .await
+        .context("get_algorithms")?
+        .pop()
+        .filter(|a| a.state.is_some())
+        .ok_or_else(|| ProtocolError::InvalidAlgorithm {
+            algorithm_id: algorithm_id.clone(),
+        })
+}
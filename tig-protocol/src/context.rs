@@ -5,6 +5,7 @@ use tig_structs::{config::*, core::*};
 pub enum SubmissionType {
     Algorithm,
     Benchmark,
+    Breakthrough,
     Proof,
 }
 
@@ -31,6 +32,13 @@ pub enum BlockFilter {
     Round(u32),
 }
 #[derive(Debug, Clone, PartialEq)]
+pub enum BreakthroughsFilter {
+    Id(String),
+    AlgorithmId(String),
+    Mempool,
+    Confirmed,
+}
+#[derive(Debug, Clone, PartialEq)]
 pub enum ChallengesFilter {
     Id(String),
     Name(String),
@@ -80,6 +88,11 @@ pub trait Context {
         filter: BlockFilter,
         include_data: bool,
     ) -> ContextResult<Option<Block>>;
+    async fn get_breakthroughs(
+        &self,
+        filter: BreakthroughsFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Breakthrough>>;
     async fn get_challenges(
         &self,
         filter: ChallengesFilter,
@@ -101,6 +114,7 @@ pub trait Context {
         filter: ProofsFilter,
         include_data: bool,
     ) -> ContextResult<Vec<Proof>>;
+    async fn get_votes(&self, breakthrough_id: &String) -> ContextResult<Vec<Vote>>;
     async fn get_wasms(&self, filter: WasmsFilter, include_data: bool) -> ContextResult<Vec<Wasm>>;
     async fn verify_solution(
         &self,
@@ -123,6 +137,14 @@ pub trait Context {
         player_id: &String,
     ) -> ContextResult<Option<PreciseNumber>>;
 
+    // Transactions. A block is added within a transaction, so that if adding it fails, none of
+    // its changes are kept and it can be retried. A transaction covers every write to the context
+    // until it ends, so nothing else may write to the context while one is open. `Protocol` holds
+    // submissions back while it adds a block
+    async fn begin_transaction(&self) -> ContextResult<()>;
+    async fn commit_transaction(&self) -> ContextResult<()>;
+    async fn rollback_transaction(&self) -> ContextResult<()>;
+
     // Mempool
     async fn add_block(
         &self,
@@ -136,6 +158,10 @@ pub trait Context {
         details: AlgorithmDetails,
         code: String,
     ) -> ContextResult<String>;
+    async fn add_breakthrough_to_mempool(
+        &self,
+        details: BreakthroughDetails,
+    ) -> ContextResult<String>;
     async fn add_benchmark_to_mempool(
         &self,
         settings: BenchmarkSettings,
@@ -159,6 +185,13 @@ pub trait Context {
         details: WasmDetails,
         wasm_blob: Option<Vec<u8>>,
     ) -> ContextResult<()>;
+    // votes are not confirmed by blocks. A player's latest vote replaces their previous one
+    async fn add_vote(
+        &self,
+        breakthrough_id: &String,
+        player_id: &String,
+        approve: bool,
+    ) -> ContextResult<()>;
 
     // Updates
     async fn update_challenge_state(
@@ -188,6 +221,17 @@ pub trait Context {
         benchmark_id: &String,
         state: BenchmarkState,
     ) -> ContextResult<()>;
+    async fn update_breakthrough_state(
+        &self,
+        breakthrough_id: &String,
+        state: BreakthroughState,
+    ) -> ContextResult<()>;
+    async fn update_breakthrough_block_data(
+        &self,
+        breakthrough_id: &String,
+        block_id: &String,
+        block_data: BreakthroughBlockData,
+    ) -> ContextResult<()>;
     async fn update_proof_state(
         &self,
         benchmark_id: &String,
@@ -1,7 +1,12 @@
+use crate::context::ContextError;
 use tig_structs::{config::DifficultyParameter, core::BenchmarkSettings};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum ProtocolError {
+    BreakthroughsNotEnabled,
+    ContextFailure {
+        error: ContextError,
+    },
     DifficultyAboveHardestFrontier {
         difficulty: Vec<i32>,
     },
@@ -11,6 +16,9 @@ pub enum ProtocolError {
     DuplicateBenchmarkSettings {
         settings: BenchmarkSettings,
     },
+    DuplicateBreakthrough {
+        algorithm_id: String,
+    },
     DuplicateNonce {
         nonce: u64,
     },
@@ -40,6 +48,9 @@ pub enum ProtocolError {
     InvalidBlock {
         block_id: String,
     },
+    InvalidBreakthrough {
+        breakthrough_id: String,
+    },
     InvalidChallenge {
         challenge_id: String,
     },
@@ -47,6 +58,12 @@ pub enum ProtocolError {
         difficulty: Vec<i32>,
         difficulty_parameters: Vec<DifficultyParameter>,
     },
+    InvalidPlayer {
+        player_id: String,
+    },
+    InvalidProof {
+        benchmark_id: String,
+    },
     InvalidProofNonces {
         expected_nonces: Vec<u64>,
         submitted_nonces: Vec<u64>,
@@ -90,11 +107,28 @@ pub enum ProtocolError {
     InvalidTransaction {
         tx_hash: String,
     },
+    NoLatestBlock,
+    RollbackFailure {
+        error: Box<ProtocolError>,
+        rollback_error: ContextError,
+    },
+    UnauthorisedPlayer {
+        player_id: String,
+    },
+    VotingNotOpen {
+        breakthrough_id: String,
+    },
 }
 
 impl std::fmt::Display for ProtocolError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ProtocolError::BreakthroughsNotEnabled => {
+                write!(f, "Breakthroughs are not enabled")
+            }
+            ProtocolError::ContextFailure { error } => {
+                write!(f, "Context failure in {:#}", error)
+            }
             ProtocolError::DifficultyAboveHardestFrontier {
                 difficulty,
             } => write!(
@@ -112,6 +146,9 @@ impl std::fmt::Display for ProtocolError {
             ProtocolError::DuplicateBenchmarkSettings { settings }=> {
                 write!(f, "A benchmark with settings '{:?}' has been submitted before.", settings)
             }
+            ProtocolError::DuplicateBreakthrough { algorithm_id } => {
+                write!(f, "Breakthrough already submitted for algorithm '{}'", algorithm_id)
+            }
             ProtocolError::DuplicateNonce { nonce } => write!(
                 f,
                 "Nonce '{}' is submitted more than once",
@@ -151,6 +188,9 @@ impl std::fmt::Display for ProtocolError {
             ProtocolError::InvalidBlock { block_id } => {
                 write!(f, "Block '{}' does not exist", block_id)
             }
+            ProtocolError::InvalidBreakthrough { breakthrough_id } => {
+                write!(f, "Breakthrough '{}' does not exist or is not yet confirmed", breakthrough_id)
+            }
             ProtocolError::InvalidChallenge { challenge_id } => {
                 write!(f, "Challenge '{}' either does not exist or in not yet active", challenge_id)
             }
@@ -162,6 +202,12 @@ impl std::fmt::Display for ProtocolError {
                 "Difficulty '{:?}' is invalid. Must match difficulty parameters '{:?}'",
                 difficulty, difficulty_parameters
             ),
+            ProtocolError::InvalidPlayer { player_id } => {
+                write!(f, "Player '{}' does not exist", player_id)
+            }
+            ProtocolError::InvalidProof { benchmark_id } => {
+                write!(f, "Proof for benchmark '{}' does not exist", benchmark_id)
+            }
             ProtocolError::InvalidProofNonces {
                 submitted_nonces,
                 expected_nonces: sampled_nonces,
@@ -237,10 +283,41 @@ impl std::fmt::Display for ProtocolError {
                 "Transaction '{}' is invalid",
                 tx_hash
             ),
+            ProtocolError::NoLatestBlock => {
+                write!(f, "No latest block found")
+            }
+            ProtocolError::RollbackFailure {
+                error,
+                rollback_error,
+            } => write!(
+                f,
+                "{}. Rolling back also failed: {:#}",
+                error, rollback_error
+            ),
+            ProtocolError::UnauthorisedPlayer { player_id } => {
+                write!(f, "Player '{}' is not authorised to make this request", player_id)
+            }
+            ProtocolError::VotingNotOpen { breakthrough_id } => {
+                write!(f, "Voting on breakthrough '{}' is not open", breakthrough_id)
+            }
         }
     }
 }
 
-impl std::error::Error for ProtocolError {}
+impl std::error::Error for ProtocolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProtocolError::ContextFailure { error } => Some(error.as_ref()),
+            ProtocolError::RollbackFailure { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for ProtocolError {
+    fn from(error: ContextError) -> Self {
+        ProtocolError::ContextFailure { error }
+    }
+}
 
 pub type ProtocolResult<T> = std::result::Result<T, ProtocolError>;
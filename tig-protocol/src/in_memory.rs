@@ -0,0 +1,1043 @@
+use crate::context::*;
+use anyhow::anyhow;
+use futures::channel::oneshot;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Mutex, RwLock},
+    thread,
+};
+use tig_structs::{config::*, core::*};
+use tig_utils::{jsonify, md5_from_str};
+use tig_worker::WasmExecutor;
+
+/// The Ethereum queries made by the protocol, so that tests can stub out the chain
+pub trait EthereumProvider {
+    fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction>;
+    fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>>;
+    fn get_latest_eth_block_num(&self) -> ContextResult<String>;
+    fn get_player_deposit(
+        &self,
+        eth_block_num: &String,
+        player_id: &String,
+    ) -> ContextResult<Option<PreciseNumber>>;
+}
+
+/// An `EthereumProvider` that answers from values set by the caller
+#[derive(Debug, Default)]
+pub struct StubEthereum {
+    transactions: RwLock<HashMap<String, Transaction>>,
+    multisig_owners: RwLock<HashMap<String, Vec<String>>>,
+    deposits: RwLock<HashMap<String, PreciseNumber>>,
+    eth_block_num: RwLock<u64>,
+}
+
+impl StubEthereum {
+    pub fn add_transaction(&self, tx_hash: String, transaction: Transaction) {
+        self.transactions
+            .write()
+            .unwrap()
+            .insert(tx_hash, transaction);
+    }
+
+    pub fn set_multisig_owners(&self, address: String, owners: Vec<String>) {
+        self.multisig_owners
+            .write()
+            .unwrap()
+            .insert(address, owners);
+    }
+
+    pub fn set_player_deposit(&self, player_id: String, deposit: PreciseNumber) {
+        self.deposits.write().unwrap().insert(player_id, deposit);
+    }
+
+    pub fn set_latest_eth_block_num(&self, eth_block_num: u64) {
+        *self.eth_block_num.write().unwrap() = eth_block_num;
+    }
+}
+
+impl EthereumProvider for StubEthereum {
+    fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction> {
+        self.transactions
+            .read()
+            .unwrap()
+            .get(tx_hash)
+            .cloned()
+            .ok_or_else(|| anyhow!("Transaction {} not found", tx_hash))
+    }
+
+    fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>> {
+        Ok(self
+            .multisig_owners
+            .read()
+            .unwrap()
+            .get(address)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn get_latest_eth_block_num(&self) -> ContextResult<String> {
+        Ok(self.eth_block_num.read().unwrap().to_string())
+    }
+
+    fn get_player_deposit(
+        &self,
+        _eth_block_num: &String,
+        player_id: &String,
+    ) -> ContextResult<Option<PreciseNumber>> {
+        Ok(self.deposits.read().unwrap().get(player_id).cloned())
+    }
+}
+
+// Reverts a change made during a transaction
+type Undo = Box<dyn FnOnce(&mut Store) + Send + Sync>;
+
+#[derive(Default)]
+struct Store {
+    config: Option<ProtocolConfig>,
+    blocks: BTreeMap<u32, Block>,
+    block_heights: HashMap<String, u32>,
+    algorithms: BTreeMap<String, Algorithm>,
+    benchmarks: BTreeMap<String, Benchmark>,
+    breakthroughs: BTreeMap<String, Breakthrough>,
+    challenges: BTreeMap<String, Challenge>,
+    frauds: BTreeMap<String, Fraud>,
+    players: BTreeMap<String, Player>,
+    proofs: BTreeMap<String, Proof>,
+    wasms: BTreeMap<String, Wasm>,
+    // keyed by (breakthrough_id, player_id)
+    votes: BTreeMap<(String, String), Vote>,
+    // keyed by (id, block_id)
+    algorithms_block_data: HashMap<(String, String), AlgorithmBlockData>,
+    breakthroughs_block_data: HashMap<(String, String), BreakthroughBlockData>,
+    challenges_block_data: HashMap<(String, String), ChallengeBlockData>,
+    players_block_data: HashMap<(String, String), PlayerBlockData>,
+    // changes are only recorded during a transaction. Every write is recorded, whoever makes it
+    undo_log: Option<Vec<Undo>>,
+}
+
+// Inserts into one of the store's maps, recording how to undo it
+macro_rules! insert {
+    ($store:expr, $map:ident, $key:expr, $value:expr) => {{
+        let key = $key;
+        let prev = $store.$map.insert(key.clone(), $value);
+        $store.record(move |s| match prev {
+            Some(prev) => {
+                s.$map.insert(key, prev);
+            }
+            None => {
+                s.$map.remove(&key);
+            }
+        });
+    }};
+}
+
+// Replaces the state of an entity, recording how to undo it
+macro_rules! update_state {
+    ($store:expr, $map:ident, $id:expr, $state:expr, $kind:expr) => {{
+        let prev = $store
+            .$map
+            .get_mut($id)
+            .ok_or_else(|| not_found($kind, $id))?
+            .state
+            .replace($state);
+        let id = $id.clone();
+        $store.record(move |s| s.$map.get_mut(&id).unwrap().state = prev);
+    }};
+}
+
+impl Store {
+    fn record(&mut self, undo: impl FnOnce(&mut Store) + Send + Sync + 'static) {
+        if let Some(undo_log) = self.undo_log.as_mut() {
+            undo_log.push(Box::new(undo));
+        }
+    }
+
+    fn get_block(&self, filter: &BlockFilter) -> Option<&Block> {
+        match filter {
+            BlockFilter::Latest => self.blocks.values().next_back(),
+            BlockFilter::Height(height) => self.blocks.get(height),
+            BlockFilter::Id(id) => self.block_heights.get(id).and_then(|h| self.blocks.get(h)),
+            BlockFilter::Round(round) => self.blocks.values().rfind(|b| b.details.round == *round),
+        }
+    }
+
+    fn block_id(&self, block_data: &Option<BlockFilter>) -> Option<String> {
+        block_data
+            .as_ref()
+            .and_then(|f| self.get_block(f))
+            .map(|b| b.id.clone())
+    }
+}
+
+/// A `Context` that keeps every entity in memory, for running the protocol end to end in tests
+/// and simulations. Solutions are computed & verified with `tig_worker`
+pub struct InMemoryContext<E: EthereumProvider = StubEthereum> {
+    pub eth: E,
+    store: RwLock<Store>,
+    executors: ExecutorCache,
+}
+
+impl InMemoryContext<StubEthereum> {
+    pub fn new(config: ProtocolConfig) -> Self {
+        Self::with_ethereum(config, StubEthereum::default())
+    }
+}
+
+impl<E: EthereumProvider> InMemoryContext<E> {
+    /// Creates a context whose genesis block (height 0) uses `config`
+    pub fn with_ethereum(config: ProtocolConfig, eth: E) -> Self {
+        let ctx = Self {
+            eth,
+            store: RwLock::new(Store::default()),
+            executors: ExecutorCache::default(),
+        };
+        ctx.insert_block(
+            BlockDetails {
+                prev_block_id: String::new(),
+                height: 0,
+                round: 1,
+                eth_block_num: None,
+            },
+            BlockData {
+                mempool_challenge_ids: HashSet::new(),
+                mempool_algorithm_ids: HashSet::new(),
+                mempool_benchmark_ids: HashSet::new(),
+                mempool_proof_ids: HashSet::new(),
+                mempool_fraud_ids: HashSet::new(),
+                mempool_wasm_ids: HashSet::new(),
+                active_challenge_ids: HashSet::new(),
+                active_algorithm_ids: HashSet::new(),
+                active_benchmark_ids: HashSet::new(),
+                active_player_ids: HashSet::new(),
+            },
+            config.clone(),
+        );
+        ctx.set_config(config);
+        ctx
+    }
+
+    /// Sets the config used by subsequent blocks
+    pub fn set_config(&self, config: ProtocolConfig) {
+        self.store.write().unwrap().config = Some(config);
+    }
+
+    /// Players are registered off-chain, so there is no `Context` method for adding them
+    pub fn add_player(&self, id: String, details: PlayerDetails) {
+        self.store.write().unwrap().players.insert(
+            id.clone(),
+            Player {
+                id,
+                details,
+                block_data: None,
+            },
+        );
+    }
+
+    fn insert_block(
+        &self,
+        details: BlockDetails,
+        data: BlockData,
+        config: ProtocolConfig,
+    ) -> String {
+        let id = md5_from_str(&jsonify(&details));
+        let mut store = self.store.write().unwrap();
+        insert!(store, block_heights, id.clone(), details.height);
+        insert!(
+            store,
+            blocks,
+            details.height,
+            Block {
+                id: id.clone(),
+                details,
+                data: Some(data),
+                config: Some(config),
+            }
+        );
+        id
+    }
+}
+
+/// Compiled wasm executors cached by algorithm id, so that a wasm is only compiled once
+#[derive(Default)]
+pub(crate) struct ExecutorCache(Mutex<HashMap<String, WasmExecutor>>);
+
+impl ExecutorCache {
+    /// `wasm_blob` is only called when the algorithm's wasm has not been compiled yet
+    pub(crate) async fn compute_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        wasm_vm_config: &WasmVMConfig,
+        wasm_blob: impl FnOnce() -> ContextResult<Option<Vec<u8>>>,
+    ) -> ContextResult<anyhow::Result<SolutionData>> {
+        let executor = match self.get_executor(&settings.algorithm_id, wasm_blob)? {
+            Ok(executor) => executor,
+            Err(e) => return Ok(Err(e)),
+        };
+        let settings = settings.clone();
+        let (max_memory, max_fuel) = (wasm_vm_config.max_memory, wasm_vm_config.max_fuel);
+        Ok(
+            match spawn_blocking(move || {
+                executor.compute_solution(&settings, nonce, max_memory, max_fuel)
+            })
+            .await?
+            {
+                Ok(Some(solution_data)) => Ok(solution_data),
+                Ok(None) => Err(anyhow!("No solution found for nonce {}", nonce)),
+                Err(e) => Err(e.into()),
+            },
+        )
+    }
+
+    fn get_executor(
+        &self,
+        algorithm_id: &String,
+        wasm_blob: impl FnOnce() -> ContextResult<Option<Vec<u8>>>,
+    ) -> ContextResult<anyhow::Result<WasmExecutor>> {
+        let mut executors = self.0.lock().unwrap();
+        if let Some(executor) = executors.get(algorithm_id) {
+            return Ok(Ok(executor.clone()));
+        }
+        let wasm_blob =
+            wasm_blob()?.ok_or_else(|| anyhow!("No wasm blob for algorithm {}", algorithm_id))?;
+        Ok(match WasmExecutor::new(&wasm_blob) {
+            Ok(executor) => {
+                executors.insert(algorithm_id.clone(), executor.clone());
+                Ok(executor)
+            }
+            Err(e) => Err(e.into()),
+        })
+    }
+
+    pub(crate) fn remove(&self, algorithm_id: &String) {
+        self.0.lock().unwrap().remove(algorithm_id);
+    }
+}
+
+/// Runs CPU bound work on its own thread. Verifying a nonce is synchronous, so without this the
+/// nonces of a proof would be verified one after another however many are in flight
+pub(crate) async fn spawn_blocking<R: Send + 'static>(
+    work: impl FnOnce() -> R + Send + 'static,
+) -> ContextResult<R> {
+    let (sender, receiver) = oneshot::channel();
+    thread::spawn(move || {
+        let _ = sender.send(work());
+    });
+    receiver
+        .await
+        .map_err(|_| anyhow!("Blocking work panicked"))
+}
+
+// Id filters are a single lookup, as the protocol makes many of these per block
+fn lookup<'a, V>(
+    entities: &'a BTreeMap<String, V>,
+    id: Option<&String>,
+) -> impl Iterator<Item = &'a V> {
+    match id {
+        Some(id) => entities.range(id.clone()..=id.clone()),
+        None => entities.range::<String, _>(..),
+    }
+    .map(|(_, v)| v)
+}
+
+fn not_found(entity: &str, id: &String) -> ContextError {
+    anyhow!("{} {} not found", entity, id)
+}
+
+impl<E: EthereumProvider> Context for InMemoryContext<E> {
+    async fn get_algorithms(
+        &self,
+        filter: AlgorithmsFilter,
+        block_data: Option<BlockFilter>,
+        include_data: bool,
+    ) -> ContextResult<Vec<Algorithm>> {
+        let store = self.store.read().unwrap();
+        let block_id = store.block_id(&block_data);
+        let id = match &filter {
+            AlgorithmsFilter::Id(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.algorithms, id)
+            .filter(|a| match &filter {
+                AlgorithmsFilter::Id(id) => a.id == *id,
+                AlgorithmsFilter::Name(name) => a.details.name == *name,
+                AlgorithmsFilter::TxHash(tx_hash) => a.details.tx_hash == *tx_hash,
+                AlgorithmsFilter::Mempool => a.state.is_none(),
+                AlgorithmsFilter::Confirmed => a.state.is_some(),
+            })
+            .map(|a| {
+                let mut a = a.clone();
+                a.block_data = block_id.as_ref().and_then(|block_id| {
+                    store
+                        .algorithms_block_data
+                        .get(&(a.id.clone(), block_id.clone()))
+                        .cloned()
+                });
+                if !include_data {
+                    a.code = None;
+                }
+                a
+            })
+            .collect())
+    }
+
+    async fn get_benchmarks(
+        &self,
+        filter: BenchmarksFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Benchmark>> {
+        let store = self.store.read().unwrap();
+        let id = match &filter {
+            BenchmarksFilter::Id(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.benchmarks, id)
+            .filter(|b| match &filter {
+                BenchmarksFilter::Id(id) => b.id == *id,
+                BenchmarksFilter::Settings(settings) => b.settings == *settings,
+                BenchmarksFilter::Mempool { from_block_started } => {
+                    b.state.is_none() && b.details.block_started >= *from_block_started
+                }
+                BenchmarksFilter::Confirmed { from_block_started } => {
+                    b.state.is_some() && b.details.block_started >= *from_block_started
+                }
+            })
+            .map(|b| {
+                let mut b = b.clone();
+                if !include_data {
+                    b.solutions_meta_data = None;
+                    b.solution_data = None;
+                }
+                b
+            })
+            .collect())
+    }
+
+    async fn get_block(
+        &self,
+        filter: BlockFilter,
+        include_data: bool,
+    ) -> ContextResult<Option<Block>> {
+        let store = self.store.read().unwrap();
+        Ok(store.get_block(&filter).map(|b| {
+            let mut b = b.clone();
+            if !include_data {
+                b.data = None;
+            }
+            b
+        }))
+    }
+
+    async fn get_breakthroughs(
+        &self,
+        filter: BreakthroughsFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Breakthrough>> {
+        let store = self.store.read().unwrap();
+        let block_id = store.block_id(&block_data);
+        let id = match &filter {
+            BreakthroughsFilter::Id(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.breakthroughs, id)
+            .filter(|b| match &filter {
+                BreakthroughsFilter::Id(id) => b.id == *id,
+                BreakthroughsFilter::AlgorithmId(id) => b.details.algorithm_id == *id,
+                BreakthroughsFilter::Mempool => b.state.is_none(),
+                BreakthroughsFilter::Confirmed => b.state.is_some(),
+            })
+            .map(|b| {
+                let mut b = b.clone();
+                b.block_data = block_id.as_ref().and_then(|block_id| {
+                    store
+                        .breakthroughs_block_data
+                        .get(&(b.id.clone(), block_id.clone()))
+                        .cloned()
+                });
+                b
+            })
+            .collect())
+    }
+
+    async fn get_challenges(
+        &self,
+        filter: ChallengesFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Challenge>> {
+        let store = self.store.read().unwrap();
+        let block_id = store.block_id(&block_data);
+        let id = match &filter {
+            ChallengesFilter::Id(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.challenges, id)
+            .filter(|c| match &filter {
+                ChallengesFilter::Id(id) => c.id == *id,
+                ChallengesFilter::Name(name) => c.details.name == *name,
+                ChallengesFilter::Mempool => c.state.is_none(),
+                ChallengesFilter::Confirmed => c.state.is_some(),
+            })
+            .map(|c| {
+                let mut c = c.clone();
+                c.block_data = block_id.as_ref().and_then(|block_id| {
+                    store
+                        .challenges_block_data
+                        .get(&(c.id.clone(), block_id.clone()))
+                        .cloned()
+                });
+                c
+            })
+            .collect())
+    }
+
+    async fn get_config(&self) -> ContextResult<ProtocolConfig> {
+        self.store
+            .read()
+            .unwrap()
+            .config
+            .clone()
+            .ok_or_else(|| anyhow!("Config not set"))
+    }
+
+    async fn get_frauds(
+        &self,
+        filter: FraudsFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Fraud>> {
+        let store = self.store.read().unwrap();
+        let block_started = |f: &Fraud| store.benchmarks[&f.benchmark_id].details.block_started;
+        let id = match &filter {
+            FraudsFilter::BenchmarkId(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.frauds, id)
+            .filter(|f| match &filter {
+                FraudsFilter::BenchmarkId(id) => f.benchmark_id == *id,
+                FraudsFilter::Mempool { from_block_started } => {
+                    f.state.is_none() && block_started(f) >= *from_block_started
+                }
+                FraudsFilter::Confirmed { from_block_started } => {
+                    f.state.is_some() && block_started(f) >= *from_block_started
+                }
+            })
+            .map(|f| {
+                let mut f = f.clone();
+                if !include_data {
+                    f.allegation = None;
+                }
+                f
+            })
+            .collect())
+    }
+
+    async fn get_players(
+        &self,
+        filter: PlayersFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Player>> {
+        let store = self.store.read().unwrap();
+        let block_id = store.block_id(&block_data);
+        let id = match &filter {
+            PlayersFilter::Id(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.players, id)
+            .filter(|p| match &filter {
+                PlayersFilter::Id(id) => p.id == *id,
+                PlayersFilter::Name(name) => p.details.name == *name,
+                PlayersFilter::Benchmarkers => store
+                    .benchmarks
+                    .values()
+                    .any(|b| b.settings.player_id == p.id),
+                PlayersFilter::Innovators => store
+                    .algorithms
+                    .values()
+                    .any(|a| a.details.player_id == p.id),
+            })
+            .map(|p| {
+                let mut p = p.clone();
+                p.block_data = block_id.as_ref().and_then(|block_id| {
+                    store
+                        .players_block_data
+                        .get(&(p.id.clone(), block_id.clone()))
+                        .cloned()
+                });
+                p
+            })
+            .collect())
+    }
+
+    async fn get_proofs(
+        &self,
+        filter: ProofsFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Proof>> {
+        let store = self.store.read().unwrap();
+        let block_started = |p: &Proof| store.benchmarks[&p.benchmark_id].details.block_started;
+        let id = match &filter {
+            ProofsFilter::BenchmarkId(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.proofs, id)
+            .filter(|p| match &filter {
+                ProofsFilter::BenchmarkId(id) => p.benchmark_id == *id,
+                ProofsFilter::Mempool { from_block_started } => {
+                    p.state.is_none() && block_started(p) >= *from_block_started
+                }
+                ProofsFilter::Confirmed { from_block_started } => {
+                    p.state.is_some() && block_started(p) >= *from_block_started
+                }
+            })
+            .map(|p| {
+                let mut p = p.clone();
+                if !include_data {
+                    p.solutions_data = None;
+                }
+                p
+            })
+            .collect())
+    }
+
+    async fn get_votes(&self, breakthrough_id: &String) -> ContextResult<Vec<Vote>> {
+        let store = self.store.read().unwrap();
+        Ok(store
+            .votes
+            .range((breakthrough_id.clone(), String::new())..)
+            .take_while(|((id, _), _)| id == breakthrough_id)
+            .map(|(_, v)| v.clone())
+            .collect())
+    }
+
+    async fn get_wasms(&self, filter: WasmsFilter, include_data: bool) -> ContextResult<Vec<Wasm>> {
+        let store = self.store.read().unwrap();
+        let id = match &filter {
+            WasmsFilter::AlgorithmId(id) => Some(id),
+            _ => None,
+        };
+        Ok(lookup(&store.wasms, id)
+            .filter(|w| match &filter {
+                WasmsFilter::AlgorithmId(id) => w.algorithm_id == *id,
+                WasmsFilter::Mempool => w.state.is_none(),
+                WasmsFilter::Confirmed => w.state.is_some(),
+            })
+            .map(|w| {
+                let mut w = w.clone();
+                if !include_data {
+                    w.wasm_blob = None;
+                }
+                w
+            })
+            .collect())
+    }
+
+    async fn verify_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        solution: &Solution,
+    ) -> ContextResult<anyhow::Result<()>> {
+        let (settings, solution) = (settings.clone(), solution.clone());
+        spawn_blocking(move || tig_worker::verify_solution(&settings, nonce, &solution)).await
+    }
+
+    async fn compute_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        wasm_vm_config: &WasmVMConfig,
+    ) -> ContextResult<anyhow::Result<SolutionData>> {
+        self.executors
+            .compute_solution(settings, nonce, wasm_vm_config, || {
+                let store = self.store.read().unwrap();
+                Ok(store
+                    .wasms
+                    .get(&settings.algorithm_id)
+                    .and_then(|w| w.wasm_blob.clone()))
+            })
+            .await
+    }
+
+    async fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction> {
+        self.eth.get_transaction(tx_hash)
+    }
+
+    async fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>> {
+        self.eth.get_multisig_owners(address)
+    }
+
+    async fn get_latest_eth_block_num(&self) -> ContextResult<String> {
+        self.eth.get_latest_eth_block_num()
+    }
+
+    async fn get_player_deposit(
+        &self,
+        eth_block_num: &String,
+        player_id: &String,
+    ) -> ContextResult<Option<PreciseNumber>> {
+        self.eth.get_player_deposit(eth_block_num, player_id)
+    }
+
+    async fn begin_transaction(&self) -> ContextResult<()> {
+        self.store.write().unwrap().undo_log = Some(Vec::new());
+        Ok(())
+    }
+
+    async fn commit_transaction(&self) -> ContextResult<()> {
+        self.store.write().unwrap().undo_log = None;
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        let undo_log = store
+            .undo_log
+            .take()
+            .ok_or_else(|| anyhow!("No transaction to roll back"))?;
+        for undo in undo_log.into_iter().rev() {
+            undo(&mut store);
+        }
+        Ok(())
+    }
+
+    async fn add_block(
+        &self,
+        details: BlockDetails,
+        data: BlockData,
+        config: ProtocolConfig,
+    ) -> ContextResult<String> {
+        Ok(self.insert_block(details, data, config))
+    }
+
+    async fn add_challenge_to_mempool(&self, details: ChallengeDetails) -> ContextResult<String> {
+        let mut store = self.store.write().unwrap();
+        let id = format!("c{:03}", store.challenges.len() + 1);
+        insert!(
+            store,
+            challenges,
+            id.clone(),
+            Challenge {
+                id: id.clone(),
+                details,
+                state: None,
+                block_data: None,
+            }
+        );
+        Ok(id)
+    }
+
+    async fn add_algorithm_to_mempool(
+        &self,
+        details: AlgorithmDetails,
+        code: String,
+    ) -> ContextResult<String> {
+        let mut store = self.store.write().unwrap();
+        let num_algorithms = store
+            .algorithms
+            .values()
+            .filter(|a| a.details.challenge_id == details.challenge_id)
+            .count();
+        let id = format!("{}_a{:03}", details.challenge_id, num_algorithms + 1);
+        insert!(
+            store,
+            algorithms,
+            id.clone(),
+            Algorithm {
+                id: id.clone(),
+                details,
+                state: None,
+                block_data: None,
+                code: Some(code),
+            }
+        );
+        Ok(id)
+    }
+
+    async fn add_breakthrough_to_mempool(
+        &self,
+        details: BreakthroughDetails,
+    ) -> ContextResult<String> {
+        let mut store = self.store.write().unwrap();
+        let num_breakthroughs = store
+            .breakthroughs
+            .values()
+            .filter(|b| b.details.challenge_id == details.challenge_id)
+            .count();
+        let id = format!("{}_b{:03}", details.challenge_id, num_breakthroughs + 1);
+        insert!(
+            store,
+            breakthroughs,
+            id.clone(),
+            Breakthrough {
+                id: id.clone(),
+                details,
+                state: None,
+                block_data: None,
+            }
+        );
+        Ok(id)
+    }
+
+    async fn add_benchmark_to_mempool(
+        &self,
+        settings: BenchmarkSettings,
+        details: BenchmarkDetails,
+        solutions_metadata: Vec<SolutionMetaData>,
+        solution_data: SolutionData,
+    ) -> ContextResult<String> {
+        let mut store = self.store.write().unwrap();
+        let id = md5_from_str(&jsonify(&settings));
+        insert!(
+            store,
+            benchmarks,
+            id.clone(),
+            Benchmark {
+                id: id.clone(),
+                settings,
+                details,
+                state: None,
+                solutions_meta_data: Some(solutions_metadata),
+                solution_data: Some(solution_data),
+            }
+        );
+        Ok(id)
+    }
+
+    async fn add_proof_to_mempool(
+        &self,
+        benchmark_id: &String,
+        solutions_data: Vec<SolutionData>,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.benchmarks.contains_key(benchmark_id) {
+            return Err(not_found("Benchmark", benchmark_id));
+        }
+        insert!(
+            store,
+            proofs,
+            benchmark_id.clone(),
+            Proof {
+                benchmark_id: benchmark_id.clone(),
+                state: None,
+                solutions_data: Some(solutions_data),
+            }
+        );
+        Ok(())
+    }
+
+    async fn add_fraud_to_mempool(
+        &self,
+        benchmark_id: &String,
+        allegation: String,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.benchmarks.contains_key(benchmark_id) {
+            return Err(not_found("Benchmark", benchmark_id));
+        }
+        insert!(
+            store,
+            frauds,
+            benchmark_id.clone(),
+            Fraud {
+                benchmark_id: benchmark_id.clone(),
+                state: None,
+                allegation: Some(allegation),
+            }
+        );
+        Ok(())
+    }
+
+    async fn add_wasm_to_mempool(
+        &self,
+        algorithm_id: &String,
+        details: WasmDetails,
+        wasm_blob: Option<Vec<u8>>,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.algorithms.contains_key(algorithm_id) {
+            return Err(not_found("Algorithm", algorithm_id));
+        }
+        insert!(
+            store,
+            wasms,
+            algorithm_id.clone(),
+            Wasm {
+                algorithm_id: algorithm_id.clone(),
+                details,
+                state: None,
+                wasm_blob,
+            }
+        );
+        drop(store);
+        self.executors.remove(algorithm_id);
+        Ok(())
+    }
+
+    async fn add_vote(
+        &self,
+        breakthrough_id: &String,
+        player_id: &String,
+        approve: bool,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.breakthroughs.contains_key(breakthrough_id) {
+            return Err(not_found("Breakthrough", breakthrough_id));
+        }
+        insert!(
+            store,
+            votes,
+            (breakthrough_id.clone(), player_id.clone()),
+            Vote {
+                breakthrough_id: breakthrough_id.clone(),
+                player_id: player_id.clone(),
+                approve,
+            }
+        );
+        Ok(())
+    }
+
+    async fn update_challenge_state(
+        &self,
+        challenge_id: &String,
+        state: ChallengeState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, challenges, challenge_id, state, "Challenge");
+        Ok(())
+    }
+
+    async fn update_challenge_block_data(
+        &self,
+        challenge_id: &String,
+        block_id: &String,
+        block_data: ChallengeBlockData,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.challenges.contains_key(challenge_id) {
+            return Err(not_found("Challenge", challenge_id));
+        }
+        insert!(
+            store,
+            challenges_block_data,
+            (challenge_id.clone(), block_id.clone()),
+            block_data
+        );
+        Ok(())
+    }
+
+    async fn update_algorithm_state(
+        &self,
+        algorithm_id: &String,
+        state: AlgorithmState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, algorithms, algorithm_id, state, "Algorithm");
+        Ok(())
+    }
+
+    async fn update_algorithm_block_data(
+        &self,
+        algorithm_id: &String,
+        block_id: &String,
+        block_data: AlgorithmBlockData,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.algorithms.contains_key(algorithm_id) {
+            return Err(not_found("Algorithm", algorithm_id));
+        }
+        insert!(
+            store,
+            algorithms_block_data,
+            (algorithm_id.clone(), block_id.clone()),
+            block_data
+        );
+        Ok(())
+    }
+
+    async fn update_benchmark_state(
+        &self,
+        benchmark_id: &String,
+        state: BenchmarkState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, benchmarks, benchmark_id, state, "Benchmark");
+        Ok(())
+    }
+
+    async fn update_breakthrough_state(
+        &self,
+        breakthrough_id: &String,
+        state: BreakthroughState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, breakthroughs, breakthrough_id, state, "Breakthrough");
+        Ok(())
+    }
+
+    async fn update_breakthrough_block_data(
+        &self,
+        breakthrough_id: &String,
+        block_id: &String,
+        block_data: BreakthroughBlockData,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.breakthroughs.contains_key(breakthrough_id) {
+            return Err(not_found("Breakthrough", breakthrough_id));
+        }
+        insert!(
+            store,
+            breakthroughs_block_data,
+            (breakthrough_id.clone(), block_id.clone()),
+            block_data
+        );
+        Ok(())
+    }
+
+    async fn update_proof_state(
+        &self,
+        benchmark_id: &String,
+        state: ProofState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, proofs, benchmark_id, state, "Proof");
+        Ok(())
+    }
+
+    async fn update_fraud_state(
+        &self,
+        benchmark_id: &String,
+        state: FraudState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, frauds, benchmark_id, state, "Fraud");
+        Ok(())
+    }
+
+    async fn update_player_block_data(
+        &self,
+        player_id: &String,
+        block_id: &String,
+        block_data: PlayerBlockData,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        if !store.players.contains_key(player_id) {
+            return Err(not_found("Player", player_id));
+        }
+        insert!(
+            store,
+            players_block_data,
+            (player_id.clone(), block_id.clone()),
+            block_data
+        );
+        Ok(())
+    }
+
+    async fn update_wasm_state(
+        &self,
+        algorithm_id: &String,
+        state: WasmState,
+    ) -> ContextResult<()> {
+        let mut store = self.store.write().unwrap();
+        update_state!(store, wasms, algorithm_id, state, "Wasm");
+        Ok(())
+    }
+}
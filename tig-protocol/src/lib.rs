@@ -1,21 +1,48 @@
 mod add_block;
+mod ban_algorithm;
 pub mod context;
 mod error;
+#[cfg(feature = "in-memory")]
+pub mod in_memory;
+mod replay_block;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 mod submit_algorithm;
 mod submit_benchmark;
+mod submit_breakthrough;
 mod submit_proof;
+mod submit_vote;
 mod verify_proof;
+pub use add_block::calc_influence_weight;
 use context::*;
 pub use error::*;
+pub use replay_block::Discrepancy;
 use tig_structs::core::*;
+use tokio::sync::RwLock;
+
+/// Number of sampled nonces verified concurrently when `Protocol::new` is used
+pub const DEFAULT_VERIFICATION_PARALLELISM: usize = 8;
 
 pub struct Protocol<T: Context> {
     pub ctx: T,
+    verification_parallelism: usize,
+    // held exclusively while adding a block, so that no submission writes to the context during
+    // its transaction and gets rolled back with it
+    block_lock: RwLock<()>,
 }
 
 impl<'a, T: Context> Protocol<T> {
     pub fn new(ctx: T) -> Self {
-        Self { ctx }
+        Self::with_verification_parallelism(ctx, DEFAULT_VERIFICATION_PARALLELISM)
+    }
+
+    /// Creates a protocol that verifies up to `parallelism` sampled nonces of a proof at a time
+    pub fn with_verification_parallelism(ctx: T, parallelism: usize) -> Self {
+        Self {
+            ctx,
+            verification_parallelism: parallelism,
+            block_lock: RwLock::new(()),
+        }
     }
 
     pub async fn submit_algorithm(
@@ -24,6 +51,7 @@ impl<'a, T: Context> Protocol<T> {
         details: AlgorithmDetails,
         code: String,
     ) -> ProtocolResult<String> {
+        let _guard = self.block_lock.read().await;
         submit_algorithm::execute(&self.ctx, player, details, code).await
     }
 
@@ -34,6 +62,7 @@ impl<'a, T: Context> Protocol<T> {
         solutions_meta_data: Vec<SolutionMetaData>,
         solution_data: SolutionData,
     ) -> ProtocolResult<(String, Result<(), String>)> {
+        let _guard = self.block_lock.read().await;
         submit_benchmark::execute(
             &self.ctx,
             player,
@@ -44,20 +73,68 @@ impl<'a, T: Context> Protocol<T> {
         .await
     }
 
+    pub async fn submit_breakthrough(
+        &self,
+        player: &Player,
+        details: BreakthroughDetails,
+    ) -> ProtocolResult<String> {
+        let _guard = self.block_lock.read().await;
+        submit_breakthrough::execute(&self.ctx, player, details).await
+    }
+
     pub async fn submit_proof(
         &self,
         player: &Player,
         benchmark_id: &String,
         solutions_data: Vec<SolutionData>,
     ) -> ProtocolResult<Result<(), String>> {
-        submit_proof::execute(&self.ctx, player, benchmark_id, solutions_data).await
+        let _guard = self.block_lock.read().await;
+        submit_proof::execute(
+            &self.ctx,
+            player,
+            benchmark_id,
+            solutions_data,
+            self.verification_parallelism,
+        )
+        .await
+    }
+
+    pub async fn submit_vote(
+        &self,
+        player: &Player,
+        breakthrough_id: &String,
+        approve: bool,
+    ) -> ProtocolResult<()> {
+        let _guard = self.block_lock.read().await;
+        submit_vote::execute(&self.ctx, player, breakthrough_id, approve).await
     }
 
     pub async fn verify_proof(&self, benchmark_id: &String) -> ProtocolResult<Result<(), String>> {
-        verify_proof::execute(&self.ctx, benchmark_id).await
+        let _guard = self.block_lock.read().await;
+        verify_proof::execute(&self.ctx, benchmark_id, self.verification_parallelism).await
+    }
+
+    pub async fn ban_algorithm(
+        &self,
+        player: &Player,
+        algorithm_id: &String,
+        reason: String,
+    ) -> ProtocolResult<()> {
+        let _guard = self.block_lock.read().await;
+        ban_algorithm::execute(&self.ctx, player, algorithm_id, reason).await
+    }
+
+    /// Recomputes `block_id` from the previous block, and reports every field of its block data
+    /// and of the state it set that differs from the published one
+    pub async fn replay_block(&self, block_id: &String) -> ProtocolResult<Vec<Discrepancy>> {
+        let _guard = self.block_lock.read().await;
+        replay_block::execute(&self.ctx, block_id).await
     }
 
-    pub async fn add_block(&self) -> String {
+    // Context failures before the block is added leave the context untouched, so the block can be
+    // retried
+    pub async fn add_block(&self) -> ProtocolResult<String> {
+        let _guard = self.block_lock.write().await;
         add_block::execute(&self.ctx).await
     }
 }
@@ -0,0 +1,677 @@
+use crate::{add_block::*, context::*, error::*};
+use anyhow::{anyhow, Context as _};
+use logging_timer::time;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use tig_structs::{config::*, core::*};
+
+/// A field of the block's data, or of an entity's state or block data, whose published value differs
+/// from the replayed one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discrepancy {
+    pub entity: String,
+    pub id: String,
+    pub field: String,
+    pub published: Value,
+    pub recomputed: Value,
+}
+
+// The block is recomputed the way add_block created it, from the chain as it was before the block,
+// and compared with the chain as it was just after it. Nothing is committed
+#[time]
+pub(crate) async fn execute<T: Context>(
+    ctx: &T,
+    block_id: &String,
+) -> ProtocolResult<Vec<Discrepancy>> {
+    let block = get_block_by_id(ctx, block_id).await?;
+    let before = ChainAsOf::before(ctx, &block);
+    let after = ChainAsOf::after(ctx, &block);
+    let mut cache = setup_cache(&before, &block.details, block.config()).await?;
+    let mut discrepancies = Vec::new();
+    let replayed = Block {
+        data: Some(new_block_data(&cache)),
+        ..block.clone()
+    };
+    diff_fields(
+        "block",
+        &block.id,
+        "",
+        Some(block.data()),
+        replayed.data(),
+        &mut discrepancies,
+    );
+    process_block(&before, &replayed, &mut cache).await?;
+
+    for challenge in cache.mempool_challenges.iter_mut() {
+        let published = after
+            .get_challenges(ChallengesFilter::Id(challenge.id.clone()), None)
+            .await
+            .context("get_challenges")?
+            .pop()
+            .and_then(|c| c.state);
+        let state = challenge.state.as_mut().unwrap();
+        // challenges are activated outside of blocks
+        if let Some(published) = &published {
+            state.round_active = published.round_active;
+        }
+        diff_fields(
+            "challenge",
+            &challenge.id,
+            "state.",
+            published.as_ref(),
+            state,
+            &mut discrepancies,
+        );
+    }
+    for algorithm in cache.mempool_algorithms.iter() {
+        let published = after
+            .get_algorithms(AlgorithmsFilter::Id(algorithm.id.clone()), None, false)
+            .await
+            .context("get_algorithms")?
+            .pop()
+            .and_then(|a| a.state);
+        diff_fields(
+            "algorithm",
+            &algorithm.id,
+            "state.",
+            published.as_ref(),
+            algorithm.state(),
+            &mut discrepancies,
+        );
+    }
+    for benchmark in cache.mempool_benchmarks.iter() {
+        let published = after
+            .get_benchmarks(BenchmarksFilter::Id(benchmark.id.clone()), false)
+            .await
+            .context("get_benchmarks")?
+            .pop()
+            .and_then(|b| b.state);
+        diff_fields(
+            "benchmark",
+            &benchmark.id,
+            "state.",
+            published.as_ref(),
+            benchmark.state(),
+            &mut discrepancies,
+        );
+    }
+    for proof in cache.mempool_proofs.iter() {
+        let published = after
+            .get_proofs(ProofsFilter::BenchmarkId(proof.benchmark_id.clone()), false)
+            .await
+            .context("get_proofs")?
+            .pop()
+            .and_then(|p| p.state);
+        diff_fields(
+            "proof",
+            &proof.benchmark_id,
+            "state.",
+            published.as_ref(),
+            proof.state(),
+            &mut discrepancies,
+        );
+    }
+    for fraud in cache.mempool_frauds.iter() {
+        let published = after
+            .get_frauds(FraudsFilter::BenchmarkId(fraud.benchmark_id.clone()), false)
+            .await
+            .context("get_frauds")?
+            .pop()
+            .and_then(|f| f.state);
+        diff_fields(
+            "fraud",
+            &fraud.benchmark_id,
+            "state.",
+            published.as_ref(),
+            fraud.state(),
+            &mut discrepancies,
+        );
+    }
+    for wasm in cache.mempool_wasms.iter() {
+        let published = after
+            .get_wasms(WasmsFilter::AlgorithmId(wasm.algorithm_id.clone()), false)
+            .await
+            .context("get_wasms")?
+            .pop()
+            .and_then(|w| w.state);
+        diff_fields(
+            "wasm",
+            &wasm.algorithm_id,
+            "state.",
+            published.as_ref(),
+            wasm.state(),
+            &mut discrepancies,
+        );
+    }
+    for breakthrough in cache
+        .mempool_breakthroughs
+        .iter()
+        .chain(cache.pending_breakthroughs.values())
+    {
+        let published = after
+            .get_breakthroughs(BreakthroughsFilter::Id(breakthrough.id.clone()), None)
+            .await
+            .context("get_breakthroughs")?
+            .pop()
+            .and_then(|b| b.state);
+        diff_fields(
+            "breakthrough",
+            &breakthrough.id,
+            "state.",
+            published.as_ref(),
+            breakthrough.state(),
+            &mut discrepancies,
+        );
+    }
+    for algorithm in cache.active_algorithms.values() {
+        let published = after
+            .get_algorithms(AlgorithmsFilter::Id(algorithm.id.clone()), None, false)
+            .await
+            .context("get_algorithms")?
+            .pop()
+            .and_then(|a| a.state);
+        // the block only writes the state of an active algorithm when pushing or merging it
+        if is_algorithm_state_updated(&replayed, algorithm.state())
+            || published
+                .as_ref()
+                .is_some_and(|s| is_algorithm_state_updated(&replayed, s))
+        {
+            diff_fields(
+                "algorithm",
+                &algorithm.id,
+                "state.",
+                published.as_ref(),
+                algorithm.state(),
+                &mut discrepancies,
+            );
+        }
+    }
+
+    let published = Some(BlockFilter::Id(block.id.clone()));
+    for challenge in cache.active_challenges.values() {
+        let published = ctx
+            .get_challenges(
+                ChallengesFilter::Id(challenge.id.clone()),
+                published.clone(),
+            )
+            .await
+            .context("get_challenges")?
+            .pop()
+            .and_then(|c| c.block_data);
+        diff_fields(
+            "challenge",
+            &challenge.id,
+            "",
+            published.as_ref(),
+            challenge.block_data(),
+            &mut discrepancies,
+        );
+    }
+    for algorithm in cache.active_algorithms.values() {
+        let published = ctx
+            .get_algorithms(
+                AlgorithmsFilter::Id(algorithm.id.clone()),
+                published.clone(),
+                false,
+            )
+            .await
+            .context("get_algorithms")?
+            .pop()
+            .and_then(|a| a.block_data);
+        diff_fields(
+            "algorithm",
+            &algorithm.id,
+            "",
+            published.as_ref(),
+            algorithm.block_data(),
+            &mut discrepancies,
+        );
+    }
+    for player in cache
+        .active_players
+        .values()
+        .chain(cache.slashed_players.values())
+    {
+        let published = ctx
+            .get_players(PlayersFilter::Id(player.id.clone()), published.clone())
+            .await
+            .context("get_players")?
+            .pop()
+            .and_then(|p| p.block_data);
+        diff_fields(
+            "player",
+            &player.id,
+            "",
+            published.as_ref(),
+            player.block_data(),
+            &mut discrepancies,
+        );
+    }
+    for breakthrough in cache.active_breakthroughs.values() {
+        let published = ctx
+            .get_breakthroughs(
+                BreakthroughsFilter::Id(breakthrough.id.clone()),
+                published.clone(),
+            )
+            .await
+            .context("get_breakthroughs")?
+            .pop()
+            .and_then(|b| b.block_data);
+        diff_fields(
+            "breakthrough",
+            &breakthrough.id,
+            "",
+            published.as_ref(),
+            breakthrough.block_data(),
+            &mut discrepancies,
+        );
+    }
+    discrepancies.sort_by(|a, b| (&a.entity, &a.id, &a.field).cmp(&(&b.entity, &b.id, &b.field)));
+    Ok(discrepancies)
+}
+
+#[time]
+async fn get_block_by_id<T: Context>(ctx: &T, block_id: &String) -> ProtocolResult<Block> {
+    ctx.get_block(BlockFilter::Id(block_id.clone()), true)
+        .await
+        .context("get_block")?
+        // the genesis block has no previous block to replay from
+        .filter(|b| b.details.height > 0)
+        .ok_or_else(|| ProtocolError::InvalidBlock {
+            block_id: block_id.clone(),
+        })
+}
+
+// The chain as it was before the block at `height` was added. Entities confirmed by later blocks
+// were not submitted yet, those confirmed by the block were in the mempool, and state set by the
+// block or later blocks is undone. Bans applying from a block after `last_ban_height` are undone
+struct ChainAsOf<'a, T: Context> {
+    ctx: &'a T,
+    height: u32,
+    last_ban_height: u32,
+    blocks_per_round: u32,
+}
+
+// Whether an entity is looked up among the mempool or the confirmed entities
+#[derive(Clone, Copy, PartialEq)]
+enum Confirmation {
+    Any,
+    Mempool,
+    Confirmed,
+}
+
+impl<'a, T: Context> ChainAsOf<'a, T> {
+    // The chain the block was added to. Bans made since the previous block apply from this one
+    fn before(ctx: &'a T, block: &Block) -> Self {
+        Self {
+            ctx,
+            height: block.details.height,
+            last_ban_height: block.details.height,
+            blocks_per_round: block.config().rounds.blocks_per_round,
+        }
+    }
+
+    // The chain just after the block was added, before any later ban
+    fn after(ctx: &'a T, block: &Block) -> Self {
+        Self {
+            height: block.details.height + 1,
+            ..Self::before(ctx, block)
+        }
+    }
+
+    // Returns None if the entity was not submitted yet, or its state as of `height`
+    fn state_as_of<S>(
+        &self,
+        state: Option<S>,
+        confirmation: Confirmation,
+        block_confirmed: impl Fn(&S) -> Option<u32>,
+    ) -> Option<Option<S>> {
+        let state = match block_confirmed(state.as_ref()?) {
+            Some(h) if h < self.height => state,
+            Some(h) if h == self.height => None,
+            _ => return None,
+        };
+        match (confirmation, state.is_some()) {
+            (Confirmation::Mempool, true) | (Confirmation::Confirmed, false) => None,
+            _ => Some(state),
+        }
+    }
+
+    fn is_before(&self, height: u32) -> bool {
+        height < self.height
+    }
+
+    fn round_start(&self, round: u32) -> u32 {
+        round.saturating_sub(1) * self.blocks_per_round
+    }
+}
+
+macro_rules! read_only {
+    ($(async fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            async fn $name(&self $(, $arg: $ty)*) -> $ret {
+                let _ = ($($arg,)*);
+                Err(anyhow!("Replaying a block is read only"))
+            }
+        )*
+    };
+}
+
+impl<'a, T: Context> Context for ChainAsOf<'a, T> {
+    async fn get_algorithms(
+        &self,
+        filter: AlgorithmsFilter,
+        block_data: Option<BlockFilter>,
+        include_data: bool,
+    ) -> ContextResult<Vec<Algorithm>> {
+        let (filter, confirmation) = match filter {
+            AlgorithmsFilter::Mempool => (AlgorithmsFilter::Confirmed, Confirmation::Mempool),
+            AlgorithmsFilter::Confirmed => (AlgorithmsFilter::Confirmed, Confirmation::Confirmed),
+            filter => (filter, Confirmation::Any),
+        };
+        let mut algorithms = Vec::new();
+        for mut algorithm in self
+            .ctx
+            .get_algorithms(filter, block_data, include_data)
+            .await?
+        {
+            let state = algorithm.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                algorithm.state = state.map(|mut state| {
+                    // pushed from the first block of the round, and merged by the last block of
+                    // the previous round
+                    state.round_pushed = state
+                        .round_pushed
+                        .filter(|r| self.is_before(self.round_start(*r)));
+                    state.round_merged = state
+                        .round_merged
+                        .filter(|r| self.is_before(self.round_start(*r) - 1));
+                    if state.block_banned.is_none_or(|h| h > self.last_ban_height) {
+                        state.banned = false;
+                        state.block_banned = None;
+                        state.ban_reason = None;
+                    }
+                    state
+                });
+                algorithms.push(algorithm);
+            }
+        }
+        Ok(algorithms)
+    }
+
+    async fn get_benchmarks(
+        &self,
+        filter: BenchmarksFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Benchmark>> {
+        let (filter, confirmation) = match filter {
+            BenchmarksFilter::Mempool { from_block_started } => (
+                BenchmarksFilter::Confirmed { from_block_started },
+                Confirmation::Mempool,
+            ),
+            filter @ BenchmarksFilter::Confirmed { .. } => (filter, Confirmation::Confirmed),
+            filter => (filter, Confirmation::Any),
+        };
+        let mut benchmarks = Vec::new();
+        for mut benchmark in self.ctx.get_benchmarks(filter, include_data).await? {
+            let state = benchmark.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                benchmark.state = state;
+                benchmarks.push(benchmark);
+            }
+        }
+        Ok(benchmarks)
+    }
+
+    async fn get_block(
+        &self,
+        filter: BlockFilter,
+        include_data: bool,
+    ) -> ContextResult<Option<Block>> {
+        self.ctx.get_block(filter, include_data).await
+    }
+
+    async fn get_breakthroughs(
+        &self,
+        filter: BreakthroughsFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Breakthrough>> {
+        let (filter, confirmation) = match filter {
+            BreakthroughsFilter::Mempool => (BreakthroughsFilter::Confirmed, Confirmation::Mempool),
+            BreakthroughsFilter::Confirmed => {
+                (BreakthroughsFilter::Confirmed, Confirmation::Confirmed)
+            }
+            filter => (filter, Confirmation::Any),
+        };
+        let mut breakthroughs = Vec::new();
+        for mut breakthrough in self.ctx.get_breakthroughs(filter, block_data).await? {
+            let state = breakthrough.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                breakthrough.state = state.map(|mut state| {
+                    // votes are started & tallied by the first block of the round
+                    state.round_voting_starts = state
+                        .round_voting_starts
+                        .filter(|r| self.is_before(self.round_start(*r)));
+                    if !state
+                        .round_votes_tallied
+                        .is_some_and(|r| self.is_before(self.round_start(r)))
+                    {
+                        state.round_votes_tallied = None;
+                        state.votes_for = None;
+                        state.votes_against = None;
+                        state.round_active = None;
+                    }
+                    state
+                });
+                breakthroughs.push(breakthrough);
+            }
+        }
+        Ok(breakthroughs)
+    }
+
+    async fn get_challenges(
+        &self,
+        filter: ChallengesFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Challenge>> {
+        let (filter, confirmation) = match filter {
+            ChallengesFilter::Mempool => (ChallengesFilter::Confirmed, Confirmation::Mempool),
+            ChallengesFilter::Confirmed => (ChallengesFilter::Confirmed, Confirmation::Confirmed),
+            filter => (filter, Confirmation::Any),
+        };
+        let mut challenges = Vec::new();
+        for mut challenge in self.ctx.get_challenges(filter, block_data).await? {
+            let state = challenge.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                challenge.state = state;
+                challenges.push(challenge);
+            }
+        }
+        Ok(challenges)
+    }
+
+    async fn get_config(&self) -> ContextResult<ProtocolConfig> {
+        self.ctx.get_config().await
+    }
+
+    async fn get_frauds(
+        &self,
+        filter: FraudsFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Fraud>> {
+        let (filter, confirmation) = match filter {
+            FraudsFilter::Mempool { from_block_started } => (
+                FraudsFilter::Confirmed { from_block_started },
+                Confirmation::Mempool,
+            ),
+            filter @ FraudsFilter::Confirmed { .. } => (filter, Confirmation::Confirmed),
+            filter => (filter, Confirmation::Any),
+        };
+        let mut frauds = Vec::new();
+        for mut fraud in self.ctx.get_frauds(filter, include_data).await? {
+            let state = fraud.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                fraud.state = state;
+                frauds.push(fraud);
+            }
+        }
+        Ok(frauds)
+    }
+
+    async fn get_players(
+        &self,
+        filter: PlayersFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Player>> {
+        self.ctx.get_players(filter, block_data).await
+    }
+
+    async fn get_proofs(
+        &self,
+        filter: ProofsFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Proof>> {
+        let (filter, confirmation) = match filter {
+            ProofsFilter::Mempool { from_block_started } => (
+                ProofsFilter::Confirmed { from_block_started },
+                Confirmation::Mempool,
+            ),
+            filter @ ProofsFilter::Confirmed { .. } => (filter, Confirmation::Confirmed),
+            filter => (filter, Confirmation::Any),
+        };
+        let mut proofs = Vec::new();
+        for mut proof in self.ctx.get_proofs(filter, include_data).await? {
+            let state = proof.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                proof.state = state;
+                proofs.push(proof);
+            }
+        }
+        Ok(proofs)
+    }
+
+    async fn get_votes(&self, breakthrough_id: &String) -> ContextResult<Vec<Vote>> {
+        self.ctx.get_votes(breakthrough_id).await
+    }
+
+    async fn get_wasms(&self, filter: WasmsFilter, include_data: bool) -> ContextResult<Vec<Wasm>> {
+        let (filter, confirmation) = match filter {
+            WasmsFilter::Mempool => (WasmsFilter::Confirmed, Confirmation::Mempool),
+            WasmsFilter::Confirmed => (WasmsFilter::Confirmed, Confirmation::Confirmed),
+            filter => (filter, Confirmation::Any),
+        };
+        let mut wasms = Vec::new();
+        for mut wasm in self.ctx.get_wasms(filter, include_data).await? {
+            let state = wasm.state.take();
+            if let Some(state) = self.state_as_of(state, confirmation, |s| s.block_confirmed) {
+                wasm.state = state;
+                wasms.push(wasm);
+            }
+        }
+        Ok(wasms)
+    }
+
+    async fn verify_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        solution: &Solution,
+    ) -> ContextResult<anyhow::Result<()>> {
+        self.ctx.verify_solution(settings, nonce, solution).await
+    }
+
+    async fn compute_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        wasm_vm_config: &WasmVMConfig,
+    ) -> ContextResult<anyhow::Result<SolutionData>> {
+        self.ctx
+            .compute_solution(settings, nonce, wasm_vm_config)
+            .await
+    }
+
+    async fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction> {
+        self.ctx.get_transaction(tx_hash).await
+    }
+
+    async fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>> {
+        self.ctx.get_multisig_owners(address).await
+    }
+
+    async fn get_latest_eth_block_num(&self) -> ContextResult<String> {
+        self.ctx.get_latest_eth_block_num().await
+    }
+
+    async fn get_player_deposit(
+        &self,
+        eth_block_num: &String,
+        player_id: &String,
+    ) -> ContextResult<Option<PreciseNumber>> {
+        self.ctx.get_player_deposit(eth_block_num, player_id).await
+    }
+
+    read_only! {
+        async fn begin_transaction(&self) -> ContextResult<()>;
+        async fn commit_transaction(&self) -> ContextResult<()>;
+        async fn rollback_transaction(&self) -> ContextResult<()>;
+        async fn add_block(&self, details: BlockDetails, data: BlockData, config: ProtocolConfig) -> ContextResult<String>;
+        async fn add_challenge_to_mempool(&self, details: ChallengeDetails) -> ContextResult<String>;
+        async fn add_algorithm_to_mempool(&self, details: AlgorithmDetails, code: String) -> ContextResult<String>;
+        async fn add_breakthrough_to_mempool(&self, details: BreakthroughDetails) -> ContextResult<String>;
+        async fn add_benchmark_to_mempool(&self, settings: BenchmarkSettings, details: BenchmarkDetails, solutions_metadata: Vec<SolutionMetaData>, solution_data: SolutionData) -> ContextResult<String>;
+        async fn add_proof_to_mempool(&self, benchmark_id: &String, solutions_data: Vec<SolutionData>) -> ContextResult<()>;
+        async fn add_fraud_to_mempool(&self, benchmark_id: &String, allegation: String) -> ContextResult<()>;
+        async fn add_wasm_to_mempool(&self, algorithm_id: &String, details: WasmDetails, wasm_blob: Option<Vec<u8>>) -> ContextResult<()>;
+        async fn add_vote(&self, breakthrough_id: &String, player_id: &String, approve: bool) -> ContextResult<()>;
+        async fn update_challenge_state(&self, challenge_id: &String, state: ChallengeState) -> ContextResult<()>;
+        async fn update_challenge_block_data(&self, challenge_id: &String, block_id: &String, block_data: ChallengeBlockData) -> ContextResult<()>;
+        async fn update_algorithm_state(&self, algorithm_id: &String, state: AlgorithmState) -> ContextResult<()>;
+        async fn update_algorithm_block_data(&self, algorithm_id: &String, block_id: &String, block_data: AlgorithmBlockData) -> ContextResult<()>;
+        async fn update_benchmark_state(&self, benchmark_id: &String, state: BenchmarkState) -> ContextResult<()>;
+        async fn update_breakthrough_state(&self, breakthrough_id: &String, state: BreakthroughState) -> ContextResult<()>;
+        async fn update_breakthrough_block_data(&self, breakthrough_id: &String, block_id: &String, block_data: BreakthroughBlockData) -> ContextResult<()>;
+        async fn update_proof_state(&self, benchmark_id: &String, state: ProofState) -> ContextResult<()>;
+        async fn update_fraud_state(&self, benchmark_id: &String, state: FraudState) -> ContextResult<()>;
+        async fn update_player_block_data(&self, player_id: &String, block_id: &String, block_data: PlayerBlockData) -> ContextResult<()>;
+        async fn update_wasm_state(&self, algorithm_id: &String, state: WasmState) -> ContextResult<()>;
+    }
+}
+
+// Fields are compared as their typed values, so that the ordering of sets does not matter. A field
+// is reported as `prefix` followed by its name
+fn diff_fields<T: Serialize + DeserializeOwned + PartialEq>(
+    entity: &str,
+    id: &String,
+    prefix: &str,
+    published: Option<&T>,
+    recomputed: &T,
+    discrepancies: &mut Vec<Discrepancy>,
+) {
+    let to_map = |value: &T| match serde_json::to_value(value) {
+        Ok(Value::Object(map)) => map,
+        _ => panic!("Expecting {} fields to serialize to an object", entity),
+    };
+    let published_map = published.map(to_map).unwrap_or_default();
+    for (field, recomputed_value) in to_map(recomputed) {
+        let is_equal = match published {
+            Some(published) => {
+                let mut patched = published_map.clone();
+                patched.insert(field.clone(), recomputed_value.clone());
+                serde_json::from_value::<T>(Value::Object(patched))
+                    .is_ok_and(|patched| patched == *published)
+            }
+            // missing block data has every field as None
+            None => recomputed_value.is_null(),
+        };
+        if !is_equal {
+            discrepancies.push(Discrepancy {
+                entity: entity.to_string(),
+                id: id.clone(),
+                published: published_map.get(&field).cloned().unwrap_or(Value::Null),
+                field: format!("{}{}", prefix, field),
+                recomputed: recomputed_value,
+            });
+        }
+    }
+}
@@ -0,0 +1,1135 @@
+use crate::{
+    context::*,
+    in_memory::{spawn_blocking, EthereumProvider, ExecutorCache, StubEthereum},
+};
+use anyhow::anyhow;
+use rusqlite::{params, params_from_iter, types::Value, Connection, OptionalExtension, Row};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashSet, path::Path, sync::Mutex};
+use tig_structs::{config::*, core::*};
+use tig_utils::{dejsonify, jsonify, md5_from_str};
+
+// Entities are stored as JSON columns, alongside the columns that filters query on
+const SCHEMA: &str = "
+    PRAGMA foreign_keys = ON;
+    PRAGMA journal_mode = WAL;
+    PRAGMA synchronous = NORMAL;
+
+    CREATE TABLE IF NOT EXISTS config (
+        id INTEGER PRIMARY KEY CHECK (id = 0),
+        config TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS blocks (
+        id TEXT PRIMARY KEY,
+        height INTEGER NOT NULL UNIQUE,
+        round INTEGER NOT NULL,
+        details TEXT NOT NULL,
+        data TEXT NOT NULL,
+        config TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS blocks_round ON blocks (round, height);
+
+    CREATE TABLE IF NOT EXISTS players (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        details TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS players_name ON players (name);
+
+    CREATE TABLE IF NOT EXISTS challenges (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        details TEXT NOT NULL,
+        state TEXT
+    );
+
+    CREATE TABLE IF NOT EXISTS algorithms (
+        id TEXT PRIMARY KEY,
+        challenge_id TEXT NOT NULL REFERENCES challenges (id),
+        player_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        tx_hash TEXT NOT NULL,
+        details TEXT NOT NULL,
+        state TEXT,
+        code TEXT
+    );
+    CREATE INDEX IF NOT EXISTS algorithms_challenge_id ON algorithms (challenge_id);
+    CREATE INDEX IF NOT EXISTS algorithms_player_id ON algorithms (player_id);
+    CREATE INDEX IF NOT EXISTS algorithms_name ON algorithms (name);
+    CREATE INDEX IF NOT EXISTS algorithms_tx_hash ON algorithms (tx_hash);
+
+    CREATE TABLE IF NOT EXISTS benchmarks (
+        id TEXT PRIMARY KEY,
+        player_id TEXT NOT NULL,
+        block_started INTEGER NOT NULL,
+        settings TEXT NOT NULL,
+        details TEXT NOT NULL,
+        state TEXT,
+        solutions_meta_data TEXT,
+        solution_data TEXT
+    );
+    CREATE INDEX IF NOT EXISTS benchmarks_player_id ON benchmarks (player_id);
+    CREATE INDEX IF NOT EXISTS benchmarks_block_started ON benchmarks (block_started);
+
+    CREATE TABLE IF NOT EXISTS proofs (
+        benchmark_id TEXT PRIMARY KEY REFERENCES benchmarks (id),
+        state TEXT,
+        solutions_data TEXT
+    );
+
+    CREATE TABLE IF NOT EXISTS frauds (
+        benchmark_id TEXT PRIMARY KEY REFERENCES benchmarks (id),
+        state TEXT,
+        allegation TEXT
+    );
+
+    CREATE TABLE IF NOT EXISTS wasms (
+        algorithm_id TEXT PRIMARY KEY REFERENCES algorithms (id),
+        details TEXT NOT NULL,
+        state TEXT,
+        wasm_blob BLOB
+    );
+
+    CREATE TABLE IF NOT EXISTS breakthroughs (
+        id TEXT PRIMARY KEY,
+        challenge_id TEXT NOT NULL REFERENCES challenges (id),
+        algorithm_id TEXT NOT NULL REFERENCES algorithms (id),
+        details TEXT NOT NULL,
+        state TEXT
+    );
+    CREATE INDEX IF NOT EXISTS breakthroughs_challenge_id ON breakthroughs (challenge_id);
+    CREATE INDEX IF NOT EXISTS breakthroughs_algorithm_id ON breakthroughs (algorithm_id);
+
+    CREATE TABLE IF NOT EXISTS votes (
+        breakthrough_id TEXT NOT NULL REFERENCES breakthroughs (id),
+        player_id TEXT NOT NULL,
+        approve INTEGER NOT NULL,
+        PRIMARY KEY (breakthrough_id, player_id)
+    );
+
+    CREATE TABLE IF NOT EXISTS algorithms_block_data (
+        algorithm_id TEXT NOT NULL REFERENCES algorithms (id),
+        block_id TEXT NOT NULL REFERENCES blocks (id),
+        data TEXT NOT NULL,
+        PRIMARY KEY (algorithm_id, block_id)
+    );
+    CREATE TABLE IF NOT EXISTS breakthroughs_block_data (
+        breakthrough_id TEXT NOT NULL REFERENCES breakthroughs (id),
+        block_id TEXT NOT NULL REFERENCES blocks (id),
+        data TEXT NOT NULL,
+        PRIMARY KEY (breakthrough_id, block_id)
+    );
+    CREATE TABLE IF NOT EXISTS challenges_block_data (
+        challenge_id TEXT NOT NULL REFERENCES challenges (id),
+        block_id TEXT NOT NULL REFERENCES blocks (id),
+        data TEXT NOT NULL,
+        PRIMARY KEY (challenge_id, block_id)
+    );
+    CREATE TABLE IF NOT EXISTS players_block_data (
+        player_id TEXT NOT NULL REFERENCES players (id),
+        block_id TEXT NOT NULL REFERENCES blocks (id),
+        data TEXT NOT NULL,
+        PRIMARY KEY (player_id, block_id)
+    );
+";
+
+fn from_json<T: DeserializeOwned>(json_str: &str) -> ContextResult<T> {
+    Ok(dejsonify::<T>(json_str)?)
+}
+
+fn from_json_opt<T: DeserializeOwned>(json_str: Option<String>) -> ContextResult<Option<T>> {
+    json_str.map(|s| from_json(&s)).transpose()
+}
+
+fn query<T>(
+    conn: &Connection,
+    sql: &str,
+    params: Vec<Value>,
+    f: impl FnMut(&Row<'_>) -> rusqlite::Result<T>,
+) -> ContextResult<Vec<T>> {
+    let mut stmt = conn.prepare_cached(sql)?;
+    let rows = stmt
+        .query_map(params_from_iter(params), f)?
+        .collect::<rusqlite::Result<Vec<T>>>()?;
+    Ok(rows)
+}
+
+fn not_found(entity: &str, id: &String) -> ContextError {
+    anyhow!("{} {} not found", entity, id)
+}
+
+fn update_state<T: Serialize>(
+    conn: &Connection,
+    table: &str,
+    id_column: &str,
+    entity: &str,
+    id: &String,
+    state: &T,
+) -> ContextResult<()> {
+    let num_updated = conn.execute(
+        &format!("UPDATE {} SET state = ?1 WHERE {} = ?2", table, id_column),
+        params![jsonify(state), id],
+    )?;
+    if num_updated == 0 {
+        return Err(not_found(entity, id));
+    }
+    Ok(())
+}
+
+fn upsert_block_data<T: Serialize>(
+    conn: &Connection,
+    table: &str,
+    id_column: &str,
+    id: &String,
+    block_id: &String,
+    block_data: &T,
+) -> ContextResult<()> {
+    conn.execute(
+        &format!(
+            "INSERT INTO {table} ({id_column}, block_id, data) VALUES (?1, ?2, ?3)
+            ON CONFLICT ({id_column}, block_id) DO UPDATE SET data = excluded.data"
+        ),
+        params![id, block_id, jsonify(block_data)],
+    )?;
+    Ok(())
+}
+
+// Each variant resolves to a single indexed lookup
+fn block_condition(filter: &BlockFilter) -> (&'static str, Vec<Value>) {
+    match filter {
+        BlockFilter::Latest => ("1 ORDER BY height DESC LIMIT 1", vec![]),
+        BlockFilter::Height(height) => ("height = ?", vec![Value::from(*height)]),
+        BlockFilter::Id(id) => ("id = ?", vec![Value::from(id.clone())]),
+        BlockFilter::Round(round) => (
+            "round = ? ORDER BY height DESC LIMIT 1",
+            vec![Value::from(*round)],
+        ),
+    }
+}
+
+/// A `Context` that persists every entity to a SQLite database, for running a private network
+/// with its own `ProtocolConfig`. Solutions are computed & verified with `tig_worker`
+pub struct SqliteContext<E: EthereumProvider = StubEthereum> {
+    pub eth: E,
+    conn: Mutex<Connection>,
+    executors: ExecutorCache,
+}
+
+impl SqliteContext<StubEthereum> {
+    pub fn open<P: AsRef<Path>>(path: P, config: ProtocolConfig) -> ContextResult<Self> {
+        Self::with_ethereum(Connection::open(path)?, config, StubEthereum::default())
+    }
+}
+
+impl<E: EthereumProvider> SqliteContext<E> {
+    /// Creates the schema if needed. A new database gets a genesis block (height 0) using
+    /// `config`, and an existing database uses `config` for its subsequent blocks
+    pub fn with_ethereum(conn: Connection, config: ProtocolConfig, eth: E) -> ContextResult<Self> {
+        conn.execute_batch(SCHEMA)?;
+        let ctx = Self {
+            eth,
+            conn: Mutex::new(conn),
+            executors: ExecutorCache::default(),
+        };
+        let has_genesis = ctx
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT 1 FROM blocks WHERE height = 0", [], |_| Ok(()))
+            .optional()?
+            .is_some();
+        if !has_genesis {
+            ctx.insert_block(
+                BlockDetails {
+                    prev_block_id: String::new(),
+                    height: 0,
+                    round: 1,
+                    eth_block_num: None,
+                },
+                BlockData {
+                    mempool_challenge_ids: HashSet::new(),
+                    mempool_algorithm_ids: HashSet::new(),
+                    mempool_benchmark_ids: HashSet::new(),
+                    mempool_proof_ids: HashSet::new(),
+                    mempool_fraud_ids: HashSet::new(),
+                    mempool_wasm_ids: HashSet::new(),
+                    active_challenge_ids: HashSet::new(),
+                    active_algorithm_ids: HashSet::new(),
+                    active_benchmark_ids: HashSet::new(),
+                    active_player_ids: HashSet::new(),
+                },
+                config.clone(),
+            )?;
+        }
+        ctx.set_config(config)?;
+        Ok(ctx)
+    }
+
+    /// Sets the config used by subsequent blocks
+    pub fn set_config(&self, config: ProtocolConfig) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO config (id, config) VALUES (0, ?1)
+            ON CONFLICT (id) DO UPDATE SET config = excluded.config",
+            params![jsonify(&config)],
+        )?;
+        Ok(())
+    }
+
+    /// Players are registered off-chain, so there is no `Context` method for adding them
+    pub fn add_player(&self, id: String, details: PlayerDetails) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO players (id, name, details) VALUES (?1, ?2, ?3)
+            ON CONFLICT (id) DO UPDATE SET name = excluded.name, details = excluded.details",
+            params![id, details.name, jsonify(&details)],
+        )?;
+        Ok(())
+    }
+
+    fn insert_block(
+        &self,
+        details: BlockDetails,
+        data: BlockData,
+        config: ProtocolConfig,
+    ) -> ContextResult<String> {
+        let id = md5_from_str(&jsonify(&details));
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO blocks (id, height, round, details, data, config)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                id,
+                details.height,
+                details.round,
+                jsonify(&details),
+                jsonify(&data),
+                jsonify(&config)
+            ],
+        )?;
+        Ok(id)
+    }
+
+    fn block_id(conn: &Connection, block_data: &Option<BlockFilter>) -> ContextResult<Value> {
+        let filter = match block_data {
+            Some(filter) => filter,
+            None => return Ok(Value::Null),
+        };
+        let (condition, params) = block_condition(filter);
+        Ok(query(
+            conn,
+            &format!("SELECT id FROM blocks WHERE {}", condition),
+            params,
+            |row| row.get::<_, String>(0),
+        )?
+        .pop()
+        .map_or(Value::Null, Value::from))
+    }
+}
+
+impl<E: EthereumProvider> Context for SqliteContext<E> {
+    async fn get_algorithms(
+        &self,
+        filter: AlgorithmsFilter,
+        block_data: Option<BlockFilter>,
+        include_data: bool,
+    ) -> ContextResult<Vec<Algorithm>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, param) = match filter {
+            AlgorithmsFilter::Id(id) => ("a.id = ?", Some(id)),
+            AlgorithmsFilter::Name(name) => ("a.name = ?", Some(name)),
+            AlgorithmsFilter::TxHash(tx_hash) => ("a.tx_hash = ?", Some(tx_hash)),
+            AlgorithmsFilter::Mempool => ("a.state IS NULL", None),
+            AlgorithmsFilter::Confirmed => ("a.state IS NOT NULL", None),
+        };
+        let mut params = vec![Self::block_id(&conn, &block_data)?];
+        params.extend(param.map(Value::from));
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT a.id, a.details, a.state, {}, d.data FROM algorithms a
+                LEFT JOIN algorithms_block_data d ON d.algorithm_id = a.id AND d.block_id = ?
+                WHERE {} ORDER BY a.id",
+                if include_data { "a.code" } else { "NULL" },
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(id, details, state, code, block_data)| {
+                Ok(Algorithm {
+                    id,
+                    details: from_json(&details)?,
+                    state: from_json_opt(state)?,
+                    block_data: from_json_opt(block_data)?,
+                    code,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_benchmarks(
+        &self,
+        filter: BenchmarksFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Benchmark>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, params) = match filter {
+            BenchmarksFilter::Id(id) => ("id = ?", vec![Value::from(id)]),
+            BenchmarksFilter::Settings(settings) => {
+                ("settings = ?", vec![Value::from(jsonify(&settings))])
+            }
+            BenchmarksFilter::Mempool { from_block_started } => (
+                "state IS NULL AND block_started >= ?",
+                vec![Value::from(from_block_started)],
+            ),
+            BenchmarksFilter::Confirmed { from_block_started } => (
+                "state IS NOT NULL AND block_started >= ?",
+                vec![Value::from(from_block_started)],
+            ),
+        };
+        let data_columns = if include_data {
+            "solutions_meta_data, solution_data"
+        } else {
+            "NULL, NULL"
+        };
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT id, settings, details, state, {} FROM benchmarks WHERE {} ORDER BY id",
+                data_columns, condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(
+                |(id, settings, details, state, solutions_meta_data, solution_data)| {
+                    Ok(Benchmark {
+                        id,
+                        settings: from_json(&settings)?,
+                        details: from_json(&details)?,
+                        state: from_json_opt(state)?,
+                        solutions_meta_data: from_json_opt(solutions_meta_data)?,
+                        solution_data: from_json_opt(solution_data)?,
+                    })
+                },
+            )
+            .collect()
+    }
+
+    async fn get_block(
+        &self,
+        filter: BlockFilter,
+        include_data: bool,
+    ) -> ContextResult<Option<Block>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, params) = block_condition(&filter);
+        let row = query(
+            &conn,
+            &format!(
+                "SELECT id, details, {}, config FROM blocks WHERE {}",
+                if include_data { "data" } else { "NULL" },
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            },
+        )?
+        .pop();
+        row.map(|(id, details, data, config)| {
+            Ok(Block {
+                id,
+                details: from_json(&details)?,
+                data: from_json_opt(data)?,
+                config: Some(from_json(&config)?),
+            })
+        })
+        .transpose()
+    }
+
+    async fn get_breakthroughs(
+        &self,
+        filter: BreakthroughsFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Breakthrough>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, param) = match filter {
+            BreakthroughsFilter::Id(id) => ("b.id = ?", Some(id)),
+            BreakthroughsFilter::AlgorithmId(id) => ("b.algorithm_id = ?", Some(id)),
+            BreakthroughsFilter::Mempool => ("b.state IS NULL", None),
+            BreakthroughsFilter::Confirmed => ("b.state IS NOT NULL", None),
+        };
+        let mut params = vec![Self::block_id(&conn, &block_data)?];
+        params.extend(param.map(Value::from));
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT b.id, b.details, b.state, d.data FROM breakthroughs b
+                LEFT JOIN breakthroughs_block_data d ON d.breakthrough_id = b.id AND d.block_id = ?
+                WHERE {} ORDER BY b.id",
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(id, details, state, block_data)| {
+                Ok(Breakthrough {
+                    id,
+                    details: from_json(&details)?,
+                    state: from_json_opt(state)?,
+                    block_data: from_json_opt(block_data)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_challenges(
+        &self,
+        filter: ChallengesFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Challenge>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, param) = match filter {
+            ChallengesFilter::Id(id) => ("c.id = ?", Some(id)),
+            ChallengesFilter::Name(name) => ("c.name = ?", Some(name)),
+            ChallengesFilter::Mempool => ("c.state IS NULL", None),
+            ChallengesFilter::Confirmed => ("c.state IS NOT NULL", None),
+        };
+        let mut params = vec![Self::block_id(&conn, &block_data)?];
+        params.extend(param.map(Value::from));
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT c.id, c.details, c.state, d.data FROM challenges c
+                LEFT JOIN challenges_block_data d ON d.challenge_id = c.id AND d.block_id = ?
+                WHERE {} ORDER BY c.id",
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(id, details, state, block_data)| {
+                Ok(Challenge {
+                    id,
+                    details: from_json(&details)?,
+                    state: from_json_opt(state)?,
+                    block_data: from_json_opt(block_data)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_config(&self) -> ContextResult<ProtocolConfig> {
+        let config = self
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT config FROM config WHERE id = 0", [], |row| {
+                row.get::<_, String>(0)
+            })
+            .optional()?
+            .ok_or_else(|| anyhow!("Config not set"))?;
+        from_json(&config)
+    }
+
+    async fn get_frauds(
+        &self,
+        filter: FraudsFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Fraud>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, params) = match filter {
+            FraudsFilter::BenchmarkId(id) => ("f.benchmark_id = ?", vec![Value::from(id)]),
+            FraudsFilter::Mempool { from_block_started } => (
+                "f.state IS NULL AND b.block_started >= ?",
+                vec![Value::from(from_block_started)],
+            ),
+            FraudsFilter::Confirmed { from_block_started } => (
+                "f.state IS NOT NULL AND b.block_started >= ?",
+                vec![Value::from(from_block_started)],
+            ),
+        };
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT f.benchmark_id, f.state, {} FROM frauds f
+                JOIN benchmarks b ON b.id = f.benchmark_id
+                WHERE {} ORDER BY f.benchmark_id",
+                if include_data { "f.allegation" } else { "NULL" },
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(benchmark_id, state, allegation)| {
+                Ok(Fraud {
+                    benchmark_id,
+                    state: from_json_opt(state)?,
+                    allegation,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_players(
+        &self,
+        filter: PlayersFilter,
+        block_data: Option<BlockFilter>,
+    ) -> ContextResult<Vec<Player>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, param) = match filter {
+            PlayersFilter::Id(id) => ("p.id = ?", Some(id)),
+            PlayersFilter::Name(name) => ("p.name = ?", Some(name)),
+            PlayersFilter::Benchmarkers => ("p.id IN (SELECT player_id FROM benchmarks)", None),
+            PlayersFilter::Innovators => ("p.id IN (SELECT player_id FROM algorithms)", None),
+        };
+        let mut params = vec![Self::block_id(&conn, &block_data)?];
+        params.extend(param.map(Value::from));
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT p.id, p.details, d.data FROM players p
+                LEFT JOIN players_block_data d ON d.player_id = p.id AND d.block_id = ?
+                WHERE {} ORDER BY p.id",
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(id, details, block_data)| {
+                Ok(Player {
+                    id,
+                    details: from_json(&details)?,
+                    block_data: from_json_opt(block_data)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_proofs(
+        &self,
+        filter: ProofsFilter,
+        include_data: bool,
+    ) -> ContextResult<Vec<Proof>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, params) = match filter {
+            ProofsFilter::BenchmarkId(id) => ("p.benchmark_id = ?", vec![Value::from(id)]),
+            ProofsFilter::Mempool { from_block_started } => (
+                "p.state IS NULL AND b.block_started >= ?",
+                vec![Value::from(from_block_started)],
+            ),
+            ProofsFilter::Confirmed { from_block_started } => (
+                "p.state IS NOT NULL AND b.block_started >= ?",
+                vec![Value::from(from_block_started)],
+            ),
+        };
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT p.benchmark_id, p.state, {} FROM proofs p
+                JOIN benchmarks b ON b.id = p.benchmark_id
+                WHERE {} ORDER BY p.benchmark_id",
+                if include_data {
+                    "p.solutions_data"
+                } else {
+                    "NULL"
+                },
+                condition
+            ),
+            params,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(benchmark_id, state, solutions_data)| {
+                Ok(Proof {
+                    benchmark_id,
+                    state: from_json_opt(state)?,
+                    solutions_data: from_json_opt(solutions_data)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_votes(&self, breakthrough_id: &String) -> ContextResult<Vec<Vote>> {
+        let conn = self.conn.lock().unwrap();
+        query(
+            &conn,
+            "SELECT player_id, approve FROM votes WHERE breakthrough_id = ? ORDER BY player_id",
+            vec![Value::from(breakthrough_id.clone())],
+            |row| {
+                Ok(Vote {
+                    breakthrough_id: breakthrough_id.clone(),
+                    player_id: row.get(0)?,
+                    approve: row.get(1)?,
+                })
+            },
+        )
+    }
+
+    async fn get_wasms(&self, filter: WasmsFilter, include_data: bool) -> ContextResult<Vec<Wasm>> {
+        let conn = self.conn.lock().unwrap();
+        let (condition, param) = match filter {
+            WasmsFilter::AlgorithmId(id) => ("algorithm_id = ?", Some(id)),
+            WasmsFilter::Mempool => ("state IS NULL", None),
+            WasmsFilter::Confirmed => ("state IS NOT NULL", None),
+        };
+        let rows = query(
+            &conn,
+            &format!(
+                "SELECT algorithm_id, details, state, {} FROM wasms WHERE {} ORDER BY algorithm_id",
+                if include_data { "wasm_blob" } else { "NULL" },
+                condition
+            ),
+            param.into_iter().map(Value::from).collect(),
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<Vec<u8>>>(3)?,
+                ))
+            },
+        )?;
+        rows.into_iter()
+            .map(|(algorithm_id, details, state, wasm_blob)| {
+                Ok(Wasm {
+                    algorithm_id,
+                    details: from_json(&details)?,
+                    state: from_json_opt(state)?,
+                    wasm_blob,
+                })
+            })
+            .collect()
+    }
+
+    async fn verify_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        solution: &Solution,
+    ) -> ContextResult<anyhow::Result<()>> {
+        let (settings, solution) = (settings.clone(), solution.clone());
+        spawn_blocking(move || tig_worker::verify_solution(&settings, nonce, &solution)).await
+    }
+
+    async fn compute_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        wasm_vm_config: &WasmVMConfig,
+    ) -> ContextResult<anyhow::Result<SolutionData>> {
+        self.executors
+            .compute_solution(settings, nonce, wasm_vm_config, || {
+                Ok(self
+                    .conn
+                    .lock()
+                    .unwrap()
+                    .query_row(
+                        "SELECT wasm_blob FROM wasms WHERE algorithm_id = ?1",
+                        params![settings.algorithm_id],
+                        |row| row.get::<_, Option<Vec<u8>>>(0),
+                    )
+                    .optional()?
+                    .flatten())
+            })
+            .await
+    }
+
+    async fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction> {
+        self.eth.get_transaction(tx_hash)
+    }
+
+    async fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>> {
+        self.eth.get_multisig_owners(address)
+    }
+
+    async fn get_latest_eth_block_num(&self) -> ContextResult<String> {
+        self.eth.get_latest_eth_block_num()
+    }
+
+    async fn get_player_deposit(
+        &self,
+        eth_block_num: &String,
+        player_id: &String,
+    ) -> ContextResult<Option<PreciseNumber>> {
+        self.eth.get_player_deposit(eth_block_num, player_id)
+    }
+
+    async fn begin_transaction(&self) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute_batch("BEGIN")?;
+        Ok(())
+    }
+
+    async fn commit_transaction(&self) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute_batch("COMMIT")?;
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute_batch("ROLLBACK")?;
+        Ok(())
+    }
+
+    async fn add_block(
+        &self,
+        details: BlockDetails,
+        data: BlockData,
+        config: ProtocolConfig,
+    ) -> ContextResult<String> {
+        self.insert_block(details, data, config)
+    }
+
+    async fn add_challenge_to_mempool(&self, details: ChallengeDetails) -> ContextResult<String> {
+        let conn = self.conn.lock().unwrap();
+        let num_challenges: u32 =
+            conn.query_row("SELECT COUNT(*) FROM challenges", [], |row| row.get(0))?;
+        let id = format!("c{:03}", num_challenges + 1);
+        conn.execute(
+            "INSERT INTO challenges (id, name, details) VALUES (?1, ?2, ?3)",
+            params![id, details.name, jsonify(&details)],
+        )?;
+        Ok(id)
+    }
+
+    async fn add_algorithm_to_mempool(
+        &self,
+        details: AlgorithmDetails,
+        code: String,
+    ) -> ContextResult<String> {
+        let conn = self.conn.lock().unwrap();
+        let num_algorithms: u32 = conn.query_row(
+            "SELECT COUNT(*) FROM algorithms WHERE challenge_id = ?1",
+            params![details.challenge_id],
+            |row| row.get(0),
+        )?;
+        let id = format!("{}_a{:03}", details.challenge_id, num_algorithms + 1);
+        conn.execute(
+            "INSERT INTO algorithms (id, challenge_id, player_id, name, tx_hash, details, code)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                id,
+                details.challenge_id,
+                details.player_id,
+                details.name,
+                details.tx_hash,
+                jsonify(&details),
+                code
+            ],
+        )?;
+        Ok(id)
+    }
+
+    async fn add_breakthrough_to_mempool(
+        &self,
+        details: BreakthroughDetails,
+    ) -> ContextResult<String> {
+        let conn = self.conn.lock().unwrap();
+        let num_breakthroughs: u32 = conn.query_row(
+            "SELECT COUNT(*) FROM breakthroughs WHERE challenge_id = ?1",
+            params![details.challenge_id],
+            |row| row.get(0),
+        )?;
+        let id = format!("{}_b{:03}", details.challenge_id, num_breakthroughs + 1);
+        conn.execute(
+            "INSERT INTO breakthroughs (id, challenge_id, algorithm_id, details)
+            VALUES (?1, ?2, ?3, ?4)",
+            params![
+                id,
+                details.challenge_id,
+                details.algorithm_id,
+                jsonify(&details)
+            ],
+        )?;
+        Ok(id)
+    }
+
+    async fn add_benchmark_to_mempool(
+        &self,
+        settings: BenchmarkSettings,
+        details: BenchmarkDetails,
+        solutions_metadata: Vec<SolutionMetaData>,
+        solution_data: SolutionData,
+    ) -> ContextResult<String> {
+        let settings_json = jsonify(&settings);
+        let id = md5_from_str(&settings_json);
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO benchmarks
+            (id, player_id, block_started, settings, details, solutions_meta_data, solution_data)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                id,
+                settings.player_id,
+                details.block_started,
+                settings_json,
+                jsonify(&details),
+                jsonify(&solutions_metadata),
+                jsonify(&solution_data)
+            ],
+        )?;
+        Ok(id)
+    }
+
+    async fn add_proof_to_mempool(
+        &self,
+        benchmark_id: &String,
+        solutions_data: Vec<SolutionData>,
+    ) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO proofs (benchmark_id, solutions_data) VALUES (?1, ?2)",
+            params![benchmark_id, jsonify(&solutions_data)],
+        )?;
+        Ok(())
+    }
+
+    async fn add_fraud_to_mempool(
+        &self,
+        benchmark_id: &String,
+        allegation: String,
+    ) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO frauds (benchmark_id, allegation) VALUES (?1, ?2)",
+            params![benchmark_id, allegation],
+        )?;
+        Ok(())
+    }
+
+    async fn add_wasm_to_mempool(
+        &self,
+        algorithm_id: &String,
+        details: WasmDetails,
+        wasm_blob: Option<Vec<u8>>,
+    ) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO wasms (algorithm_id, details, wasm_blob) VALUES (?1, ?2, ?3)
+            ON CONFLICT (algorithm_id) DO UPDATE
+            SET details = excluded.details, state = NULL, wasm_blob = excluded.wasm_blob",
+            params![algorithm_id, jsonify(&details), wasm_blob],
+        )?;
+        self.executors.remove(algorithm_id);
+        Ok(())
+    }
+
+    async fn add_vote(
+        &self,
+        breakthrough_id: &String,
+        player_id: &String,
+        approve: bool,
+    ) -> ContextResult<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO votes (breakthrough_id, player_id, approve) VALUES (?1, ?2, ?3)
+            ON CONFLICT (breakthrough_id, player_id) DO UPDATE SET approve = excluded.approve",
+            params![breakthrough_id, player_id, approve],
+        )?;
+        Ok(())
+    }
+
+    async fn update_challenge_state(
+        &self,
+        challenge_id: &String,
+        state: ChallengeState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(&conn, "challenges", "id", "Challenge", challenge_id, &state)
+    }
+
+    async fn update_challenge_block_data(
+        &self,
+        challenge_id: &String,
+        block_id: &String,
+        block_data: ChallengeBlockData,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        upsert_block_data(
+            &conn,
+            "challenges_block_data",
+            "challenge_id",
+            challenge_id,
+            block_id,
+            &block_data,
+        )
+    }
+
+    async fn update_algorithm_state(
+        &self,
+        algorithm_id: &String,
+        state: AlgorithmState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(&conn, "algorithms", "id", "Algorithm", algorithm_id, &state)
+    }
+
+    async fn update_algorithm_block_data(
+        &self,
+        algorithm_id: &String,
+        block_id: &String,
+        block_data: AlgorithmBlockData,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        upsert_block_data(
+            &conn,
+            "algorithms_block_data",
+            "algorithm_id",
+            algorithm_id,
+            block_id,
+            &block_data,
+        )
+    }
+
+    async fn update_benchmark_state(
+        &self,
+        benchmark_id: &String,
+        state: BenchmarkState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(&conn, "benchmarks", "id", "Benchmark", benchmark_id, &state)
+    }
+
+    async fn update_breakthrough_state(
+        &self,
+        breakthrough_id: &String,
+        state: BreakthroughState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(
+            &conn,
+            "breakthroughs",
+            "id",
+            "Breakthrough",
+            breakthrough_id,
+            &state,
+        )
+    }
+
+    async fn update_breakthrough_block_data(
+        &self,
+        breakthrough_id: &String,
+        block_id: &String,
+        block_data: BreakthroughBlockData,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        upsert_block_data(
+            &conn,
+            "breakthroughs_block_data",
+            "breakthrough_id",
+            breakthrough_id,
+            block_id,
+            &block_data,
+        )
+    }
+
+    async fn update_proof_state(
+        &self,
+        benchmark_id: &String,
+        state: ProofState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(
+            &conn,
+            "proofs",
+            "benchmark_id",
+            "Proof",
+            benchmark_id,
+            &state,
+        )
+    }
+
+    async fn update_fraud_state(
+        &self,
+        benchmark_id: &String,
+        state: FraudState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(
+            &conn,
+            "frauds",
+            "benchmark_id",
+            "Fraud",
+            benchmark_id,
+            &state,
+        )
+    }
+
+    async fn update_player_block_data(
+        &self,
+        player_id: &String,
+        block_id: &String,
+        block_data: PlayerBlockData,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        upsert_block_data(
+            &conn,
+            "players_block_data",
+            "player_id",
+            player_id,
+            block_id,
+            &block_data,
+        )
+    }
+
+    async fn update_wasm_state(
+        &self,
+        algorithm_id: &String,
+        state: WasmState,
+    ) -> ContextResult<()> {
+        let conn = self.conn.lock().unwrap();
+        update_state(&conn, "wasms", "algorithm_id", "Wasm", algorithm_id, &state)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{context::*, error::*};
+use anyhow::Context as _;
 use logging_timer::time;
 use std::collections::HashSet;
 use tig_structs::core::*;
@@ -16,7 +17,7 @@ pub(crate) async fn execute<T: Context>(
     let algorithm_id = ctx
         .add_algorithm_to_mempool(details, code)
         .await
-        .unwrap_or_else(|e| panic!("add_algorithm_to_mempool error: {:?}", e));
+        .context("add_algorithm_to_mempool")?;
     Ok(algorithm_id)
 }
 
@@ -28,12 +29,12 @@ async fn verify_challenge_exists<T: Context>(
     let latest_block = ctx
         .get_block(BlockFilter::Latest, false)
         .await
-        .unwrap_or_else(|e| panic!("get_block error: {:?}", e))
-        .expect("Expecting latest block to exist");
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
     if !ctx
         .get_challenges(ChallengesFilter::Id(details.challenge_id.clone()), None)
         .await
-        .unwrap_or_else(|e| panic!("get_challenges error: {:?}", e))
+        .context("get_challenges")?
         .first()
         .is_some_and(|c| {
             c.state()
@@ -58,8 +59,8 @@ async fn verify_submission_fee<T: Context>(
     let block = ctx
         .get_block(BlockFilter::Latest, false)
         .await
-        .unwrap_or_else(|e| panic!("get_block error: {:?}", e))
-        .expect("No latest block found");
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
 
     if ctx
         .get_algorithms(
@@ -68,7 +69,7 @@ async fn verify_submission_fee<T: Context>(
             false,
         )
         .await
-        .unwrap_or_else(|e| panic!("get_algorithms error: {:?}", e))
+        .context("get_algorithms")?
         .first()
         .is_some()
     {
@@ -82,7 +83,7 @@ async fn verify_submission_fee<T: Context>(
         let multisig_owners = ctx
             .get_multisig_owners(&player.id)
             .await
-            .unwrap_or_else(|e| panic!("get_multisig_owners error: {:?}", e));
+            .context("get_multisig_owners")?;
         valid_senders.extend(multisig_owners.into_iter());
     }
 
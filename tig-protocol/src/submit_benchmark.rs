@@ -1,4 +1,5 @@
 use crate::{context::*, error::*};
+use anyhow::Context as _;
 use logging_timer::time;
 use std::collections::HashMap;
 use tig_structs::core::*;
@@ -24,6 +25,10 @@ pub(crate) async fn execute<T: Context>(
     verify_benchmark_difficulty(&settings.difficulty, &challenge, &block)?;
     let verification =
         verify_solution_is_valid(ctx, &settings, &solutions_meta_data, &solution_data).await;
+    // a context failure is not the benchmarker's fault, so must not be flagged as fraud
+    if let Err(e @ ProtocolError::ContextFailure { .. }) = verification {
+        return Err(e);
+    }
     let benchmark_id = ctx
         .add_benchmark_to_mempool(
             settings,
@@ -35,12 +40,12 @@ pub(crate) async fn execute<T: Context>(
             solution_data,
         )
         .await
-        .unwrap_or_else(|e| panic!("add_benchmark_to_mempool error: {:?}", e));
+        .context("add_benchmark_to_mempool")?;
     let mut verified = Ok(());
     if let Err(e) = verification {
         ctx.add_fraud_to_mempool(&benchmark_id, e.to_string())
             .await
-            .unwrap_or_else(|e| panic!("add_fraud_to_mempool error: {:?}", e));
+            .context("add_fraud_to_mempool")?;
         verified = Err(e.to_string());
     }
     Ok((benchmark_id, verified))
@@ -65,8 +70,8 @@ async fn verify_sufficient_lifespan<T: Context>(ctx: &T, block: &Block) -> Proto
     let latest_block = ctx
         .get_block(BlockFilter::Latest, false)
         .await
-        .unwrap_or_else(|e| panic!("get_block error: {:?}", e))
-        .expect("Expecting latest block to exist");
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
     let config = block.config();
     let submission_delay = latest_block.details.height - block.details.height + 1;
     if submission_delay * (config.benchmark_submissions.submission_delay_multiplier + 1)
@@ -94,7 +99,7 @@ async fn get_challenge_by_id<T: Context>(
             Some(BlockFilter::Id(block.id.clone())),
         )
         .await
-        .unwrap_or_else(|e| panic!("get_challenges error: {:?}", e))
+        .context("get_challenges")?
         .first()
         .map(|x| x.to_owned())
         .ok_or_else(|| ProtocolError::InvalidChallenge {
@@ -112,7 +117,7 @@ async fn verify_algorithm<T: Context>(
     if !ctx
         .get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
         .await
-        .unwrap_or_else(|e| panic!("get_algorithms error: {:?}", e))
+        .context("get_algorithms")?
         .first()
         .is_some()
     {
@@ -132,7 +137,7 @@ async fn verify_algorithm<T: Context>(
 async fn get_block_by_id<T: Context>(ctx: &T, block_id: &String) -> ProtocolResult<Block> {
     ctx.get_block(BlockFilter::Id(block_id.clone()), true)
         .await
-        .unwrap_or_else(|e| panic!("get_block error: {:?}", e))
+        .context("get_block")?
         .ok_or_else(|| ProtocolError::InvalidBlock {
             block_id: block_id.clone(),
         })
@@ -161,7 +166,7 @@ async fn verify_benchmark_settings_are_unique<T: Context>(
     if ctx
         .get_benchmarks(BenchmarksFilter::Settings(settings.clone()), false)
         .await
-        .unwrap_or_else(|e| panic!("get_benchmarks error: {:?}", e))
+        .context("get_benchmarks")?
         .first()
         .is_some()
     {
@@ -291,7 +296,7 @@ async fn verify_solution_is_valid<T: Context>(
     if ctx
         .verify_solution(settings, solution_data.nonce, &solution_data.solution)
         .await
-        .unwrap_or_else(|e| panic!("verify_solution error: {:?}", e))
+        .context("verify_solution")?
         .is_err()
     {
         return Err(ProtocolError::InvalidSolution {
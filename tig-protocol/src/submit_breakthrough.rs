@@ -0,0 +1,101 @@
+use crate::{context::*, error::*};
+use anyhow::Context as _;
+use logging_timer::time;
+use tig_structs::core::*;
+
+#[time]
+pub(crate) async fn execute<T: Context>(
+    ctx: &T,
+    player: &Player,
+    details: BreakthroughDetails,
+) -> ProtocolResult<String> {
+    verify_breakthroughs_enabled(ctx).await?;
+    let algorithm = get_algorithm_by_id(ctx, &details.algorithm_id).await?;
+    verify_algorithm_ownership(player, &details, &algorithm)?;
+    verify_challenge_matches(&details, &algorithm)?;
+    verify_breakthrough_not_already_submitted(ctx, &details).await?;
+    let breakthrough_id = ctx
+        .add_breakthrough_to_mempool(details)
+        .await
+        .context("add_breakthrough_to_mempool")?;
+    Ok(breakthrough_id)
+}
+
+#[time]
+async fn verify_breakthroughs_enabled<T: Context>(ctx: &T) -> ProtocolResult<()> {
+    let latest_block = ctx
+        .get_block(BlockFilter::Latest, false)
+        .await
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
+    if latest_block.config().breakthroughs.is_none() {
+        return Err(ProtocolError::BreakthroughsNotEnabled);
+    }
+    Ok(())
+}
+
+#[time]
+async fn get_algorithm_by_id<T: Context>(
+    ctx: &T,
+    algorithm_id: &String,
+) -> ProtocolResult<Algorithm> {
+    ctx.get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
+        .await
+        .context("get_algorithms")?
+        .pop()
+        .filter(|a| a.state.is_some())
+        .ok_or_else(|| ProtocolError::InvalidAlgorithm {
+            algorithm_id: algorithm_id.clone(),
+        })
+}
+
+#[time]
+fn verify_algorithm_ownership(
+    player: &Player,
+    details: &BreakthroughDetails,
+    algorithm: &Algorithm,
+) -> ProtocolResult<()> {
+    let expected_player_id = algorithm.details.player_id.clone();
+    if player.id != expected_player_id || details.player_id != expected_player_id {
+        return Err(ProtocolError::InvalidSubmittingPlayer {
+            actual_player_id: player.id.clone(),
+            expected_player_id,
+        });
+    }
+    Ok(())
+}
+
+#[time]
+fn verify_challenge_matches(
+    details: &BreakthroughDetails,
+    algorithm: &Algorithm,
+) -> ProtocolResult<()> {
+    if details.challenge_id != algorithm.details.challenge_id {
+        return Err(ProtocolError::InvalidChallenge {
+            challenge_id: details.challenge_id.clone(),
+        });
+    }
+    Ok(())
+}
+
+#[time]
+async fn verify_breakthrough_not_already_submitted<T: Context>(
+    ctx: &T,
+    details: &BreakthroughDetails,
+) -> ProtocolResult<()> {
+    if ctx
+        .get_breakthroughs(
+            BreakthroughsFilter::AlgorithmId(details.algorithm_id.clone()),
+            None,
+        )
+        .await
+        .context("get_breakthroughs")?
+        .first()
+        .is_some()
+    {
+        return Err(ProtocolError::DuplicateBreakthrough {
+            algorithm_id: details.algorithm_id.clone(),
+        });
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use crate::{context::*, error::*};
+use crate::{context::*, error::*, verify_proof::verify_nonces};
+use anyhow::Context as _;
 use logging_timer::time;
 use std::collections::{HashMap, HashSet};
 use tig_structs::core::*;
@@ -9,20 +10,26 @@ pub(crate) async fn execute<T: Context>(
     player: &Player,
     benchmark_id: &String,
     solutions_data: Vec<SolutionData>,
+    parallelism: usize,
 ) -> ProtocolResult<Result<(), String>> {
     verify_no_fraud(ctx, benchmark_id).await?;
     verify_proof_not_already_submitted(ctx, benchmark_id).await?;
     let benchmark = get_benchmark_by_id(ctx, benchmark_id).await?;
     verify_benchmark_ownership(player, &benchmark)?;
     verify_sampled_nonces(&benchmark, &solutions_data)?;
-    let verification_result = verify_solutions_are_valid(ctx, &benchmark, &solutions_data).await;
+    let verification_result =
+        verify_solutions_are_valid(ctx, &benchmark, &solutions_data, parallelism).await;
+    // a context failure is not the benchmarker's fault, so must not be flagged as fraud
+    if let Err(e @ ProtocolError::ContextFailure { .. }) = verification_result {
+        return Err(e);
+    }
     ctx.add_proof_to_mempool(benchmark_id, solutions_data)
         .await
-        .unwrap_or_else(|e| panic!("add_proof_to_mempool error: {:?}", e));
+        .context("add_proof_to_mempool")?;
     if let Err(e) = verification_result {
         ctx.add_fraud_to_mempool(benchmark_id, e.to_string())
             .await
-            .unwrap_or_else(|e| panic!("add_fraud_to_mempool error: {:?}", e));
+            .context("add_fraud_to_mempool")?;
         return Ok(Err(e.to_string()));
     }
     Ok(Ok(()))
@@ -35,7 +42,7 @@ async fn get_benchmark_by_id<T: Context>(
 ) -> ProtocolResult<Benchmark> {
     ctx.get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), true)
         .await
-        .unwrap_or_else(|e| panic!("add_benchmark_to_mempool error: {:?}", e))
+        .context("get_benchmarks")?
         .first()
         .map(|x| x.to_owned())
         .ok_or_else(|| ProtocolError::InvalidBenchmark {
@@ -48,7 +55,7 @@ async fn verify_no_fraud<T: Context>(ctx: &T, benchmark_id: &String) -> Protocol
     if ctx
         .get_frauds(FraudsFilter::BenchmarkId(benchmark_id.clone()), false)
         .await
-        .unwrap_or_else(|e| panic!("get_frauds error: {:?}", e))
+        .context("get_frauds")?
         .first()
         .is_some()
     {
@@ -67,7 +74,7 @@ async fn verify_proof_not_already_submitted<T: Context>(
     if ctx
         .get_proofs(ProofsFilter::BenchmarkId(benchmark_id.clone()), false)
         .await
-        .unwrap_or_else(|e| panic!("get_proofs error: {:?}", e))
+        .context("get_proofs")?
         .first()
         .is_some()
     {
@@ -112,6 +119,7 @@ async fn verify_solutions_are_valid<T: Context>(
     ctx: &T,
     benchmark: &Benchmark,
     solutions_data: &Vec<SolutionData>,
+    parallelism: usize,
 ) -> ProtocolResult<()> {
     let solutions_map: HashMap<u64, u32> = benchmark
         .solutions_meta_data()
@@ -119,7 +127,9 @@ async fn verify_solutions_are_valid<T: Context>(
         .map(|d| (d.nonce, d.solution_signature))
         .collect();
 
-    for d in solutions_data.iter() {
+    let mut sorted_solutions_data: Vec<&SolutionData> = solutions_data.iter().collect();
+    sorted_solutions_data.sort_by_key(|d| d.nonce);
+    for d in sorted_solutions_data {
         let submitted_signature = solutions_map[&d.nonce];
         let actual_signature = d.calc_solution_signature();
 
@@ -132,16 +142,21 @@ async fn verify_solutions_are_valid<T: Context>(
         }
     }
 
-    for d in solutions_data.iter() {
-        if ctx
-            .verify_solution(&benchmark.settings, d.nonce, &d.solution)
-            .await
-            .unwrap_or_else(|e| panic!("verify_solution error: {:?}", e))
-            .is_err()
-        {
-            return Err(ProtocolError::InvalidSolution { nonce: d.nonce });
-        }
-    }
-
-    Ok(())
+    let settings = &benchmark.settings;
+    verify_nonces(
+        solutions_data.iter().map(|d| (d.nonce, &d.solution)),
+        parallelism,
+        |nonce, solution| async move {
+            if ctx
+                .verify_solution(settings, nonce, solution)
+                .await
+                .context("verify_solution")?
+                .is_err()
+            {
+                return Err(ProtocolError::InvalidSolution { nonce });
+            }
+            Ok(())
+        },
+    )
+    .await
 }
@@ -0,0 +1,57 @@
+use crate::{context::*, error::*};
+use anyhow::Context as _;
+use logging_timer::time;
+use tig_structs::core::*;
+
+#[time]
+pub(crate) async fn execute<T: Context>(
+    ctx: &T,
+    player: &Player,
+    breakthrough_id: &String,
+    approve: bool,
+) -> ProtocolResult<()> {
+    let breakthrough = get_breakthrough_by_id(ctx, breakthrough_id).await?;
+    verify_voting_is_open(ctx, &breakthrough).await?;
+    ctx.add_vote(breakthrough_id, &player.id, approve)
+        .await
+        .context("add_vote")?;
+    Ok(())
+}
+
+#[time]
+async fn get_breakthrough_by_id<T: Context>(
+    ctx: &T,
+    breakthrough_id: &String,
+) -> ProtocolResult<Breakthrough> {
+    ctx.get_breakthroughs(BreakthroughsFilter::Id(breakthrough_id.clone()), None)
+        .await
+        .context("get_breakthroughs")?
+        .pop()
+        .filter(|b| b.state.is_some())
+        .ok_or_else(|| ProtocolError::InvalidBreakthrough {
+            breakthrough_id: breakthrough_id.clone(),
+        })
+}
+
+#[time]
+async fn verify_voting_is_open<T: Context>(
+    ctx: &T,
+    breakthrough: &Breakthrough,
+) -> ProtocolResult<()> {
+    let latest_block = ctx
+        .get_block(BlockFilter::Latest, false)
+        .await
+        .context("get_block")?
+        .ok_or(ProtocolError::NoLatestBlock)?;
+    let state = breakthrough.state();
+    let is_open = state.round_votes_tallied.is_none()
+        && state
+            .round_voting_starts
+            .is_some_and(|r| r <= latest_block.details.round);
+    if !is_open {
+        return Err(ProtocolError::VotingNotOpen {
+            breakthrough_id: breakthrough.id.clone(),
+        });
+    }
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use crate::{context::*, error::*};
+use anyhow::{anyhow, Context as _};
+use futures::{stream, Future, StreamExt};
 use logging_timer::time;
 use tig_structs::core::*;
 
@@ -6,15 +8,21 @@ use tig_structs::core::*;
 pub(crate) async fn execute<T: Context>(
     ctx: &T,
     benchmark_id: &String,
+    parallelism: usize,
 ) -> ProtocolResult<Result<(), String>> {
     let benchmark = get_benchmark_by_id(ctx, benchmark_id).await?;
     let proof = get_proof_by_benchmark_id(ctx, benchmark_id).await?;
     let mut verified = Ok(());
-    if let Err(e) = verify_solutions_with_algorithm(ctx, &benchmark, &proof).await {
-        ctx.add_fraud_to_mempool(benchmark_id, e.to_string())
-            .await
-            .unwrap_or_else(|e| panic!("add_fraud_to_mempool error: {:?}", e));
-        verified = Err(e.to_string());
+    match verify_solutions_with_algorithm(ctx, &benchmark, &proof, parallelism).await {
+        // a context failure is not the benchmarker's fault, so must not be flagged as fraud
+        Err(e @ ProtocolError::ContextFailure { .. }) => return Err(e),
+        Err(e) => {
+            ctx.add_fraud_to_mempool(benchmark_id, e.to_string())
+                .await
+                .context("add_fraud_to_mempool")?;
+            verified = Err(e.to_string());
+        }
+        Ok(()) => {}
     }
     Ok(verified)
 }
@@ -27,10 +35,12 @@ async fn get_benchmark_by_id<T: Context>(
     Ok(ctx
         .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
         .await
-        .unwrap_or_else(|e| panic!("get_benchmarks error: {:?}", e))
+        .context("get_benchmarks")?
         .first()
         .map(|x| x.to_owned())
-        .expect(format!("Expecting benchmark {} to exist", benchmark_id).as_str()))
+        .ok_or_else(|| ProtocolError::InvalidBenchmark {
+            benchmark_id: benchmark_id.clone(),
+        })?)
 }
 
 #[time]
@@ -41,10 +51,12 @@ async fn get_proof_by_benchmark_id<T: Context>(
     Ok(ctx
         .get_proofs(ProofsFilter::BenchmarkId(benchmark_id.clone()), true)
         .await
-        .unwrap_or_else(|e| panic!("get_proofs error: {:?}", e))
+        .context("get_proofs")?
         .first()
         .map(|x| x.to_owned())
-        .expect(format!("Expecting proof for benchmark {} to exist", benchmark_id).as_str()))
+        .ok_or_else(|| ProtocolError::InvalidProof {
+            benchmark_id: benchmark_id.clone(),
+        })?)
 }
 
 #[time]
@@ -52,33 +64,62 @@ async fn verify_solutions_with_algorithm<T: Context>(
     ctx: &T,
     benchmark: &Benchmark,
     proof: &Proof,
+    parallelism: usize,
 ) -> ProtocolResult<()> {
     let settings = &benchmark.settings;
     let wasm_vm_config = ctx
         .get_block(BlockFilter::Id(settings.block_id.clone()), false)
         .await
-        .unwrap_or_else(|e| panic!("get_block error: {:?}", e))
-        .expect(format!("Expecting block {} to exist", settings.block_id).as_str())
-        .config
-        .unwrap()
+        .context("get_block")?
+        .and_then(|b| b.config)
+        .ok_or_else(|| anyhow!("Expecting block {} to exist", settings.block_id))
+        .context("get_block")?
         .wasm_vm;
 
-    for solution_data in proof.solutions_data() {
-        if let Ok(actual_solution_data) = ctx
-            .compute_solution(settings, solution_data.nonce, &wasm_vm_config)
-            .await
-            .unwrap_or_else(|e| panic!("compute_solution error: {:?}", e))
-        {
-            if actual_solution_data == *solution_data {
-                continue;
+    verify_nonces(
+        proof.solutions_data().iter().map(|d| (d.nonce, d)),
+        parallelism,
+        |nonce, solution_data| {
+            let wasm_vm_config = &wasm_vm_config;
+            async move {
+                if let Ok(actual_solution_data) = ctx
+                    .compute_solution(settings, nonce, wasm_vm_config)
+                    .await
+                    .context("compute_solution")?
+                {
+                    if actual_solution_data == *solution_data {
+                        return Ok(());
+                    }
+                }
+                Err(ProtocolError::InvalidSolutionData {
+                    algorithm_id: settings.algorithm_id.clone(),
+                    nonce,
+                })
             }
-        }
+        },
+    )
+    .await
+}
 
-        return Err(ProtocolError::InvalidSolutionData {
-            algorithm_id: settings.algorithm_id.clone(),
-            nonce: solution_data.nonce,
-        });
+// Nonces are verified in ascending order with up to `parallelism` in flight. Results are consumed
+// in that order, so the error returned is always that of the lowest failing nonce, and nonces
+// after it are no longer polled. Nonces only run in parallel if the context verifies them off-thread
+pub(crate) async fn verify_nonces<'a, D: 'a, F, Fut>(
+    nonces: impl Iterator<Item = (u64, &'a D)>,
+    parallelism: usize,
+    verify: F,
+) -> ProtocolResult<()>
+where
+    F: Fn(u64, &'a D) -> Fut,
+    Fut: Future<Output = ProtocolResult<()>>,
+{
+    let mut nonces: Vec<(u64, &D)> = nonces.collect();
+    nonces.sort_by_key(|(nonce, _)| *nonce);
+    let mut results = stream::iter(nonces)
+        .map(|(nonce, d)| verify(nonce, d))
+        .buffered(parallelism.max(1));
+    while let Some(result) = results.next().await {
+        result?;
     }
-
     Ok(())
 }
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use tig_structs::{config::*, core::PreciseNumber};
+
+pub const PLAYER_ID: &str = "0x0000000000000000000000000000000000000001";
+pub const BURN_ADDRESS: &str = "0x000000000000000000000000000000000000dead";
+
+fn difficulty_parameter(name: &str, min_value: i32, max_value: i32) -> DifficultyParameter {
+    DifficultyParameter {
+        name: name.to_string(),
+        min_value,
+        max_value,
+    }
+}
+
+pub fn config() -> ProtocolConfig {
+    ProtocolConfig {
+        erc20: ERC20Config {
+            rpc_url: String::new(),
+            chain_id: String::new(),
+            token_address: String::new(),
+            burn_address: BURN_ADDRESS.to_string(),
+        },
+        benchmark_submissions: BenchmarkSubmissionsConfig {
+            min_num_solutions: 1,
+            submission_delay_multiplier: 1,
+            max_samples: 2,
+            lifespan_period: 120,
+        },
+        wasm_vm: WasmVMConfig {
+            max_memory: 1_000_000_000,
+            max_fuel: 1_000_000_000,
+        },
+        solution_signature: SolutionSignatureConfig {
+            max_percent_delta: None,
+            threshold_decay: None,
+            equilibrium_rate_multiplier: 1.0,
+            percent_error_multiplier: None,
+        },
+        qualifiers: QualifiersConfig {
+            cutoff_phase_in_period: Some(0),
+            cutoff_multiplier: 1.0,
+            total_qualifiers_threshold: 1000,
+            min_cutoff: Some(1000),
+        },
+        difficulty: DifficultyConfig {
+            max_scaling_factor: 1.8,
+            min_frontiers_gaps: None,
+            parameters: HashMap::from([
+                (
+                    "c001".to_string(),
+                    vec![
+                        difficulty_parameter("num_variables", 50, 1000),
+                        difficulty_parameter("clauses_to_variables_percent", 300, 500),
+                    ],
+                ),
+                (
+                    "c003".to_string(),
+                    vec![
+                        difficulty_parameter("num_items", 50, 1000),
+                        difficulty_parameter("better_than_baseline", 10, 1000),
+                    ],
+                ),
+            ]),
+        },
+        optimisable_proof_of_work: OptimisableProofOfWorkConfig {
+            imbalance_multiplier: 3.0,
+            enable_proof_of_deposit: None,
+            rolling_deposit_decay: None,
+        },
+        rounds: RoundsConfig {
+            blocks_per_round: 2,
+        },
+        algorithm_submissions: AlgorithmSubmissionsConfig {
+            submission_fee: PreciseNumber::from(10),
+            adoption_threshold: 0.25,
+            merge_points_threshold: 100,
+            push_delay: 1,
+            admin_address: None,
+        },
+        rewards: RewardsConfig {
+            distribution: DistributionConfig {
+                benchmarkers: 0.8,
+                optimisations: 0.15,
+                breakthroughs: 0.05,
+            },
+            schedule: vec![EmissionsConfig {
+                block_reward: 100.0,
+                round_start: 0,
+            }],
+        },
+        slashing: None,
+        breakthroughs: None,
+    }
+}
@@ -0,0 +1,1654 @@
+mod common;
+
+use anyhow::anyhow;
+use common::*;
+use std::{
+    collections::HashSet,
+    future::Future,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+use tig_protocol::{
+    context::*,
+    in_memory::{EthereumProvider, InMemoryContext, StubEthereum},
+    Protocol, ProtocolError,
+};
+use tig_structs::{
+    config::{BreakthroughsConfig, ProtocolConfig, SlashingConfig, VoteWeight, WasmVMConfig},
+    core::*,
+};
+
+const WASM: &[u8] = include_bytes!("../../tig-algorithms/wasm/knapsack/dynamic.wasm");
+
+async fn get_player<E: EthereumProvider>(
+    ctx: &InMemoryContext<E>,
+    block: Option<BlockFilter>,
+) -> Player {
+    ctx.get_players(PlayersFilter::Id(PLAYER_ID.to_string()), block)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+}
+
+async fn latest_block<E: EthereumProvider>(ctx: &InMemoryContext<E>) -> Block {
+    ctx.get_block(BlockFilter::Latest, true)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn setup() -> Protocol<InMemoryContext> {
+    setup_with_ethereum(StubEthereum::default()).await
+}
+
+async fn setup_with_ethereum<E: EthereumProvider>(eth: E) -> Protocol<InMemoryContext<E>> {
+    let protocol = Protocol::new(InMemoryContext::with_ethereum(config(), eth));
+    let ctx = &protocol.ctx;
+    ctx.add_player(
+        PLAYER_ID.to_string(),
+        PlayerDetails {
+            name: "player".to_string(),
+            is_multisig: false,
+        },
+    );
+    for name in ["satisfiability", "vehicle_routing", "knapsack"] {
+        ctx.add_challenge_to_mempool(ChallengeDetails {
+            name: name.to_string(),
+        })
+        .await
+        .unwrap();
+    }
+    protocol.add_block().await.unwrap();
+    for challenge_id in ["c001", "c003"] {
+        ctx.update_challenge_state(
+            &challenge_id.to_string(),
+            ChallengeState {
+                block_confirmed: Some(1),
+                round_active: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+    }
+    protocol
+}
+
+#[tokio::test]
+async fn test_mempool_and_confirmed_filters() {
+    let protocol = setup().await;
+    let ctx = &protocol.ctx;
+    assert_eq!(latest_block(ctx).await.details.height, 1);
+    assert_eq!(
+        ctx.get_challenges(ChallengesFilter::Confirmed, None)
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+    assert_eq!(
+        ctx.get_challenges(ChallengesFilter::Name("knapsack".to_string()), None)
+            .await
+            .unwrap()[0]
+            .id,
+        "c003"
+    );
+
+    let benchmark_id = ctx
+        .add_benchmark_to_mempool(
+            BenchmarkSettings {
+                player_id: PLAYER_ID.to_string(),
+                block_id: latest_block(ctx).await.id,
+                challenge_id: "c003".to_string(),
+                algorithm_id: "c003_a001".to_string(),
+                difficulty: vec![50, 10],
+            },
+            BenchmarkDetails {
+                block_started: 1,
+                num_solutions: 0,
+            },
+            Vec::new(),
+            SolutionData {
+                nonce: 0,
+                runtime_signature: 0,
+                fuel_consumed: 0,
+                solution: Solution::new(),
+            },
+        )
+        .await
+        .unwrap();
+    for (from_block_started, expected) in [(0, 1), (1, 1), (2, 0)] {
+        assert_eq!(
+            ctx.get_benchmarks(BenchmarksFilter::Mempool { from_block_started }, false)
+                .await
+                .unwrap()
+                .len(),
+            expected
+        );
+    }
+    let benchmark = ctx
+        .get_benchmarks(BenchmarksFilter::Id(benchmark_id), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert!(benchmark.solutions_meta_data.is_none());
+    assert!(ctx
+        .get_players(PlayersFilter::Benchmarkers, None)
+        .await
+        .unwrap()
+        .iter()
+        .any(|p| p.id == PLAYER_ID));
+}
+
+#[tokio::test]
+async fn test_benchmark_lifecycle() {
+    let protocol = setup().await;
+    let ctx = &protocol.ctx;
+    let player = get_player(ctx, None).await;
+
+    // submit an algorithm & its wasm
+    let tx_hash = "0x01".to_string();
+    ctx.eth.add_transaction(
+        tx_hash.clone(),
+        Transaction {
+            sender: PLAYER_ID.to_string(),
+            receiver: BURN_ADDRESS.to_string(),
+            amount: PreciseNumber::from(10),
+        },
+    );
+    let algorithm_id = protocol
+        .submit_algorithm(
+            &player,
+            AlgorithmDetails {
+                name: "dynamic".to_string(),
+                player_id: PLAYER_ID.to_string(),
+                challenge_id: "c003".to_string(),
+                tx_hash,
+            },
+            String::new(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(algorithm_id, "c003_a001");
+    ctx.add_wasm_to_mempool(
+        &algorithm_id,
+        WasmDetails {
+            compile_success: true,
+            download_url: None,
+            checksum: None,
+        },
+        Some(WASM.to_vec()),
+    )
+    .await
+    .unwrap();
+
+    // algorithm is pushed the round after it is submitted
+    while !latest_block(ctx)
+        .await
+        .data()
+        .active_algorithm_ids
+        .contains(&algorithm_id)
+    {
+        protocol.add_block().await.unwrap();
+    }
+
+    // benchmark the easiest difficulty
+    let block = latest_block(ctx).await;
+    let settings = BenchmarkSettings {
+        player_id: PLAYER_ID.to_string(),
+        block_id: block.id.clone(),
+        challenge_id: "c003".to_string(),
+        algorithm_id: algorithm_id.clone(),
+        difficulty: vec![50, 10],
+    };
+    let wasm_vm_config = block.config().wasm_vm.clone();
+    let mut solutions_data = Vec::new();
+    for nonce in 0..50 {
+        if let Ok(solution_data) = ctx
+            .compute_solution(&settings, nonce, &wasm_vm_config)
+            .await
+            .unwrap()
+        {
+            if ctx
+                .verify_solution(&settings, nonce, &solution_data.solution)
+                .await
+                .unwrap()
+                .is_ok()
+            {
+                solutions_data.push(solution_data);
+            }
+        }
+    }
+    assert!(!solutions_data.is_empty());
+    let (benchmark_id, verified) = protocol
+        .submit_benchmark(
+            &player,
+            settings,
+            solutions_data
+                .iter()
+                .map(|d| SolutionMetaData::from(d.clone()))
+                .collect(),
+            solutions_data[0].clone(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(verified, Ok(()));
+
+    // confirming the benchmark samples nonces to prove
+    protocol.add_block().await.unwrap();
+    let benchmark = ctx
+        .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let sampled_nonces = benchmark.state().sampled_nonces().clone();
+    assert_eq!(sampled_nonces.len(), solutions_data.len().min(2));
+    let proof = solutions_data
+        .iter()
+        .filter(|d| sampled_nonces.contains(&d.nonce))
+        .cloned()
+        .collect();
+    assert_eq!(
+        protocol
+            .submit_proof(&player, &benchmark_id, proof)
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(protocol.verify_proof(&benchmark_id).await.unwrap(), Ok(()));
+
+    // benchmark becomes active after its submission delay, earning the player influence
+    while !latest_block(ctx)
+        .await
+        .data()
+        .active_benchmark_ids
+        .contains(&benchmark_id)
+    {
+        protocol.add_block().await.unwrap();
+    }
+    let block = latest_block(ctx).await;
+    let player = get_player(ctx, Some(BlockFilter::Id(block.id.clone()))).await;
+    let player_data = player.block_data();
+    assert_eq!(
+        player_data.num_qualifiers_by_challenge()["c003"],
+        solutions_data.len() as u32
+    );
+    assert_eq!(*player_data.influence(), PreciseNumber::from(1));
+    assert!(*player_data.reward() > PreciseNumber::from(0));
+    let challenge = ctx
+        .get_challenges(
+            ChallengesFilter::Id("c003".to_string()),
+            Some(BlockFilter::Id(block.id)),
+        )
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(
+        *challenge.block_data().num_qualifiers(),
+        solutions_data.len() as u32
+    );
+}
+
+// An Ethereum RPC that goes down until `available` is set, and whose deposits are unavailable
+// until `deposits_available` is set
+#[derive(Default)]
+struct FlakyEthereum {
+    available: AtomicBool,
+    deposits_available: AtomicBool,
+    stub: StubEthereum,
+}
+
+impl EthereumProvider for FlakyEthereum {
+    fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction> {
+        self.stub.get_transaction(tx_hash)
+    }
+    fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>> {
+        self.stub.get_multisig_owners(address)
+    }
+    fn get_latest_eth_block_num(&self) -> ContextResult<String> {
+        if !self.available.load(Ordering::SeqCst) {
+            return Err(anyhow!("RPC unavailable"));
+        }
+        self.stub.get_latest_eth_block_num()
+    }
+    fn get_player_deposit(
+        &self,
+        eth_block_num: &String,
+        player_id: &String,
+    ) -> ContextResult<Option<PreciseNumber>> {
+        if !self.deposits_available.load(Ordering::SeqCst) {
+            return Err(anyhow!("Deposits unavailable"));
+        }
+        self.stub.get_player_deposit(eth_block_num, player_id)
+    }
+}
+
+#[tokio::test]
+async fn test_add_block_context_failure() {
+    let protocol = Protocol::new(InMemoryContext::with_ethereum(
+        config(),
+        FlakyEthereum::default(),
+    ));
+    let ctx = &protocol.ctx;
+    let error = protocol.add_block().await.unwrap_err();
+    assert!(matches!(error, ProtocolError::ContextFailure { .. }));
+    assert_eq!(
+        error.to_string(),
+        "Context failure in get_latest_eth_block_num: RPC unavailable"
+    );
+    let latest = ctx.get_block(BlockFilter::Latest, false).await.unwrap();
+    assert_eq!(latest.unwrap().details.height, 0);
+
+    ctx.eth.available.store(true, Ordering::SeqCst);
+    protocol.add_block().await.unwrap();
+    let latest = ctx.get_block(BlockFilter::Latest, false).await.unwrap();
+    assert_eq!(latest.unwrap().details.height, 1);
+}
+
+#[tokio::test]
+async fn test_add_block_rolls_back_context_failure() {
+    let mut config = config();
+    config.optimisable_proof_of_work.rolling_deposit_decay = Some(0.5);
+    let eth = FlakyEthereum::default();
+    eth.available.store(true, Ordering::SeqCst);
+    eth.deposits_available.store(true, Ordering::SeqCst);
+    let (protocol, benchmark_ids) = setup_benchmarks_with_ethereum(config, false, eth).await;
+    let ctx = &protocol.ctx;
+    let block = latest_block(ctx).await;
+    let benchmark_id = ctx
+        .add_benchmark_to_mempool(
+            BenchmarkSettings {
+                player_id: PLAYER_ID.to_string(),
+                block_id: block.id.clone(),
+                challenge_id: "c003".to_string(),
+                algorithm_id: "c003_a001".to_string(),
+                difficulty: vec![52, 10],
+            },
+            BenchmarkDetails {
+                block_started: block.details.height,
+                num_solutions: 1,
+            },
+            vec![SolutionMetaData {
+                nonce: 0,
+                solution_signature: 0,
+            }],
+            unverified_solution_data(0),
+        )
+        .await
+        .unwrap();
+    let mempool_benchmark_ids = || async {
+        ctx.get_benchmarks(
+            BenchmarksFilter::Mempool {
+                from_block_started: 0,
+            },
+            false,
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|b| b.id)
+        .collect::<Vec<String>>()
+    };
+
+    // deposits are fetched after the block is saved, so the failed block must be rolled back
+    ctx.eth.deposits_available.store(false, Ordering::SeqCst);
+    let error = protocol.add_block().await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Context failure in get_player_deposit: Deposits unavailable"
+    );
+    assert_eq!(latest_block(ctx).await.id, block.id);
+    assert_eq!(mempool_benchmark_ids().await, vec![benchmark_id.clone()]);
+
+    ctx.eth.deposits_available.store(true, Ordering::SeqCst);
+    protocol.add_block().await.unwrap();
+    let latest = latest_block(ctx).await;
+    assert_eq!(latest.details.height, block.details.height + 1);
+    assert!(latest.data().mempool_benchmark_ids.contains(&benchmark_id));
+    assert!(latest
+        .data()
+        .active_benchmark_ids
+        .contains(&benchmark_ids[0]));
+    assert!(mempool_benchmark_ids().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_block_missing_player() {
+    let protocol = setup().await;
+    let ctx = &protocol.ctx;
+    let algorithm_id = setup_algorithm(&protocol).await;
+    let block = latest_block(ctx).await;
+    // the player was never registered, so the benchmark's player is missing once it is active
+    let player_id = "0x0000000000000000000000000000000000000099".to_string();
+    let benchmark_id = ctx
+        .add_benchmark_to_mempool(
+            BenchmarkSettings {
+                player_id: player_id.clone(),
+                block_id: block.id.clone(),
+                challenge_id: "c003".to_string(),
+                algorithm_id,
+                difficulty: vec![50, 10],
+            },
+            BenchmarkDetails {
+                block_started: block.details.height,
+                num_solutions: 1,
+            },
+            vec![SolutionMetaData {
+                nonce: 0,
+                solution_signature: 0,
+            }],
+            unverified_solution_data(0),
+        )
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+    ctx.add_proof_to_mempool(&benchmark_id, vec![unverified_solution_data(0)])
+        .await
+        .unwrap();
+    let error = loop {
+        let height = latest_block(ctx).await.details.height;
+        assert!(height < block.details.height + 10);
+        if let Err(e) = protocol.add_block().await {
+            assert_eq!(latest_block(ctx).await.details.height, height);
+            break e;
+        }
+    };
+    assert!(matches!(
+        error,
+        ProtocolError::InvalidPlayer { player_id: id } if id == player_id
+    ));
+}
+
+const OTHER_PLAYER_ID: &str = "0x0000000000000000000000000000000000000002";
+const ADMIN_ADDRESS: &str = "0x00000000000000000000000000000000000000ad";
+
+fn unverified_solution_data(nonce: u64) -> SolutionData {
+    SolutionData {
+        nonce,
+        runtime_signature: 0,
+        fuel_consumed: 0,
+        solution: Solution::new(),
+    }
+}
+
+// Adds PLAYER_ID's knapsack algorithm without its wasm blob, and returns its id once it is active
+async fn setup_algorithm<E: EthereumProvider>(protocol: &Protocol<InMemoryContext<E>>) -> String {
+    let ctx = &protocol.ctx;
+    let algorithm_id = ctx
+        .add_algorithm_to_mempool(
+            AlgorithmDetails {
+                name: "dynamic".to_string(),
+                player_id: PLAYER_ID.to_string(),
+                challenge_id: "c003".to_string(),
+                tx_hash: "0x01".to_string(),
+            },
+            String::new(),
+        )
+        .await
+        .unwrap();
+    ctx.add_wasm_to_mempool(
+        &algorithm_id,
+        WasmDetails {
+            compile_success: true,
+            download_url: None,
+            checksum: None,
+        },
+        None,
+    )
+    .await
+    .unwrap();
+    while !latest_block(ctx)
+        .await
+        .data()
+        .active_algorithm_ids
+        .contains(&algorithm_id)
+    {
+        protocol.add_block().await.unwrap();
+    }
+    algorithm_id
+}
+
+// Both players benchmark knapsack identically with PLAYER_ID's algorithm. If `fraud` is set, PLAYER_ID's
+// second benchmark is fraudulent. Returns the benchmark ids once they are active
+async fn setup_benchmarks(
+    config: ProtocolConfig,
+    fraud: bool,
+) -> (Protocol<InMemoryContext>, Vec<String>) {
+    setup_benchmarks_with_ethereum(config, fraud, StubEthereum::default()).await
+}
+
+async fn setup_benchmarks_with_ethereum<E: EthereumProvider>(
+    config: ProtocolConfig,
+    fraud: bool,
+    eth: E,
+) -> (Protocol<InMemoryContext<E>>, Vec<String>) {
+    let protocol = setup_with_ethereum(eth).await;
+    let ctx = &protocol.ctx;
+    ctx.set_config(config);
+    ctx.add_player(
+        OTHER_PLAYER_ID.to_string(),
+        PlayerDetails {
+            name: "other_player".to_string(),
+            is_multisig: false,
+        },
+    );
+    let algorithm_id = setup_algorithm(&protocol).await;
+
+    // add_block does not verify solutions, so benchmarks are added straight to the mempool
+    let block = latest_block(ctx).await;
+    let mut benchmark_ids = Vec::new();
+    for (player_id, difficulty) in [
+        (PLAYER_ID, vec![50, 10]),
+        (PLAYER_ID, vec![51, 10]),
+        (OTHER_PLAYER_ID, vec![50, 10]),
+        (OTHER_PLAYER_ID, vec![51, 10]),
+    ] {
+        benchmark_ids.push(
+            ctx.add_benchmark_to_mempool(
+                BenchmarkSettings {
+                    player_id: player_id.to_string(),
+                    block_id: block.id.clone(),
+                    challenge_id: "c003".to_string(),
+                    algorithm_id: algorithm_id.clone(),
+                    difficulty,
+                },
+                BenchmarkDetails {
+                    block_started: block.details.height,
+                    num_solutions: 5,
+                },
+                (0..5)
+                    .map(|nonce| SolutionMetaData {
+                        nonce,
+                        solution_signature: 0,
+                    })
+                    .collect(),
+                unverified_solution_data(0),
+            )
+            .await
+            .unwrap(),
+        );
+    }
+    protocol.add_block().await.unwrap();
+    for benchmark_id in benchmark_ids.iter() {
+        let benchmark = ctx
+            .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let solutions_data = benchmark
+            .state()
+            .sampled_nonces()
+            .iter()
+            .map(|&nonce| unverified_solution_data(nonce))
+            .collect();
+        ctx.add_proof_to_mempool(benchmark_id, solutions_data)
+            .await
+            .unwrap();
+    }
+    if fraud {
+        ctx.add_fraud_to_mempool(&benchmark_ids[1], "invalid solution".to_string())
+            .await
+            .unwrap();
+    }
+    while !latest_block(ctx)
+        .await
+        .data()
+        .active_benchmark_ids
+        .contains(&benchmark_ids[0])
+    {
+        protocol.add_block().await.unwrap();
+    }
+    (protocol, benchmark_ids)
+}
+
+#[tokio::test]
+async fn test_submit_proof_reports_lowest_failing_nonce() {
+    let protocol = setup().await;
+    let ctx = &protocol.ctx;
+    let player = get_player(ctx, None).await;
+    let algorithm_id = setup_algorithm(&protocol).await;
+    let block = latest_block(ctx).await;
+    let benchmark_id = ctx
+        .add_benchmark_to_mempool(
+            BenchmarkSettings {
+                player_id: PLAYER_ID.to_string(),
+                block_id: block.id.clone(),
+                challenge_id: "c003".to_string(),
+                algorithm_id,
+                difficulty: vec![50, 10],
+            },
+            BenchmarkDetails {
+                block_started: block.details.height,
+                num_solutions: 10,
+            },
+            (0..10)
+                .map(|nonce| SolutionMetaData::from(unverified_solution_data(nonce)))
+                .collect(),
+            unverified_solution_data(0),
+        )
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+    let benchmark = ctx
+        .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let sampled_nonces = benchmark.state().sampled_nonces().clone();
+    assert_eq!(sampled_nonces.len(), 2);
+
+    // every sampled nonce is invalid, and the proof lists them highest first
+    let mut proof: Vec<SolutionData> = sampled_nonces
+        .iter()
+        .map(|&nonce| unverified_solution_data(nonce))
+        .collect();
+    proof.sort_by_key(|d| std::cmp::Reverse(d.nonce));
+    let expected = ProtocolError::InvalidSolution {
+        nonce: *sampled_nonces.iter().min().unwrap(),
+    }
+    .to_string();
+    assert_eq!(
+        protocol
+            .submit_proof(&player, &benchmark_id, proof)
+            .await
+            .unwrap(),
+        Err(expected.clone())
+    );
+    let fraud = ctx
+        .get_frauds(FraudsFilter::BenchmarkId(benchmark_id), true)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(fraud.allegation, Some(expected));
+}
+
+// Counts the solutions being verified or computed at once by the context it wraps. Each one yields
+// before running, so that the others in flight get polled however quickly it would finish
+struct CountingContext {
+    inner: InMemoryContext,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl CountingContext {
+    async fn counted<R>(&self, work: impl Future<Output = R>) -> R {
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::task::yield_now().await;
+        let result = work.await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+}
+
+macro_rules! delegate {
+    ($(async fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            async fn $name(&self $(, $arg: $ty)*) -> $ret {
+                self.inner.$name($($arg),*).await
+            }
+        )*
+    };
+}
+
+impl Context for CountingContext {
+    delegate! {
+        async fn get_algorithms(&self, filter: AlgorithmsFilter, block_data: Option<BlockFilter>, include_data: bool) -> ContextResult<Vec<Algorithm>>;
+        async fn get_benchmarks(&self, filter: BenchmarksFilter, include_data: bool) -> ContextResult<Vec<Benchmark>>;
+        async fn get_block(&self, filter: BlockFilter, include_data: bool) -> ContextResult<Option<Block>>;
+        async fn get_breakthroughs(&self, filter: BreakthroughsFilter, block_data: Option<BlockFilter>) -> ContextResult<Vec<Breakthrough>>;
+        async fn get_challenges(&self, filter: ChallengesFilter, block_data: Option<BlockFilter>) -> ContextResult<Vec<Challenge>>;
+        async fn get_config(&self) -> ContextResult<ProtocolConfig>;
+        async fn get_frauds(&self, filter: FraudsFilter, include_data: bool) -> ContextResult<Vec<Fraud>>;
+        async fn get_players(&self, filter: PlayersFilter, block_data: Option<BlockFilter>) -> ContextResult<Vec<Player>>;
+        async fn get_proofs(&self, filter: ProofsFilter, include_data: bool) -> ContextResult<Vec<Proof>>;
+        async fn get_votes(&self, breakthrough_id: &String) -> ContextResult<Vec<Vote>>;
+        async fn get_wasms(&self, filter: WasmsFilter, include_data: bool) -> ContextResult<Vec<Wasm>>;
+        async fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction>;
+        async fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>>;
+        async fn get_latest_eth_block_num(&self) -> ContextResult<String>;
+        async fn get_player_deposit(&self, eth_block_num: &String, player_id: &String) -> ContextResult<Option<PreciseNumber>>;
+        async fn begin_transaction(&self) -> ContextResult<()>;
+        async fn commit_transaction(&self) -> ContextResult<()>;
+        async fn rollback_transaction(&self) -> ContextResult<()>;
+        async fn add_block(&self, details: BlockDetails, data: BlockData, config: ProtocolConfig) -> ContextResult<String>;
+        async fn add_challenge_to_mempool(&self, details: ChallengeDetails) -> ContextResult<String>;
+        async fn add_algorithm_to_mempool(&self, details: AlgorithmDetails, code: String) -> ContextResult<String>;
+        async fn add_breakthrough_to_mempool(&self, details: BreakthroughDetails) -> ContextResult<String>;
+        async fn add_benchmark_to_mempool(&self, settings: BenchmarkSettings, details: BenchmarkDetails, solutions_metadata: Vec<SolutionMetaData>, solution_data: SolutionData) -> ContextResult<String>;
+        async fn add_proof_to_mempool(&self, benchmark_id: &String, solutions_data: Vec<SolutionData>) -> ContextResult<()>;
+        async fn add_fraud_to_mempool(&self, benchmark_id: &String, allegation: String) -> ContextResult<()>;
+        async fn add_wasm_to_mempool(&self, algorithm_id: &String, details: WasmDetails, wasm_blob: Option<Vec<u8>>) -> ContextResult<()>;
+        async fn add_vote(&self, breakthrough_id: &String, player_id: &String, approve: bool) -> ContextResult<()>;
+        async fn update_challenge_state(&self, challenge_id: &String, state: ChallengeState) -> ContextResult<()>;
+        async fn update_challenge_block_data(&self, challenge_id: &String, block_id: &String, block_data: ChallengeBlockData) -> ContextResult<()>;
+        async fn update_algorithm_state(&self, algorithm_id: &String, state: AlgorithmState) -> ContextResult<()>;
+        async fn update_algorithm_block_data(&self, algorithm_id: &String, block_id: &String, block_data: AlgorithmBlockData) -> ContextResult<()>;
+        async fn update_benchmark_state(&self, benchmark_id: &String, state: BenchmarkState) -> ContextResult<()>;
+        async fn update_breakthrough_state(&self, breakthrough_id: &String, state: BreakthroughState) -> ContextResult<()>;
+        async fn update_breakthrough_block_data(&self, breakthrough_id: &String, block_id: &String, block_data: BreakthroughBlockData) -> ContextResult<()>;
+        async fn update_proof_state(&self, benchmark_id: &String, state: ProofState) -> ContextResult<()>;
+        async fn update_fraud_state(&self, benchmark_id: &String, state: FraudState) -> ContextResult<()>;
+        async fn update_player_block_data(&self, player_id: &String, block_id: &String, block_data: PlayerBlockData) -> ContextResult<()>;
+        async fn update_wasm_state(&self, algorithm_id: &String, state: WasmState) -> ContextResult<()>;
+    }
+
+    async fn verify_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        solution: &Solution,
+    ) -> ContextResult<anyhow::Result<()>> {
+        self.counted(self.inner.verify_solution(settings, nonce, solution))
+            .await
+    }
+
+    async fn compute_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        wasm_vm_config: &WasmVMConfig,
+    ) -> ContextResult<anyhow::Result<SolutionData>> {
+        self.counted(self.inner.compute_solution(settings, nonce, wasm_vm_config))
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_submit_proof_verifies_in_parallel() {
+    let protocol = setup().await;
+    let ctx = &protocol.ctx;
+    let mut config = config();
+    config.benchmark_submissions.max_samples = 4;
+    ctx.set_config(config);
+    let player = get_player(ctx, None).await;
+    let algorithm_id = setup_algorithm(&protocol).await;
+    let block = latest_block(ctx).await;
+    let benchmark_id = ctx
+        .add_benchmark_to_mempool(
+            BenchmarkSettings {
+                player_id: PLAYER_ID.to_string(),
+                block_id: block.id.clone(),
+                challenge_id: "c003".to_string(),
+                algorithm_id,
+                difficulty: vec![50, 10],
+            },
+            BenchmarkDetails {
+                block_started: block.details.height,
+                num_solutions: 10,
+            },
+            (0..10)
+                .map(|nonce| SolutionMetaData::from(unverified_solution_data(nonce)))
+                .collect(),
+            unverified_solution_data(0),
+        )
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+    let benchmark = ctx
+        .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let proof: Vec<SolutionData> = benchmark
+        .state()
+        .sampled_nonces()
+        .iter()
+        .map(|&nonce| unverified_solution_data(nonce))
+        .collect();
+    let parallelism = 3;
+    assert!(proof.len() > parallelism);
+
+    // several nonces are in flight at once, but never more than the parallelism
+    let protocol = Protocol::with_verification_parallelism(
+        CountingContext {
+            inner: protocol.ctx,
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        },
+        parallelism,
+    );
+    protocol
+        .submit_proof(&player, &benchmark_id, proof)
+        .await
+        .unwrap()
+        .unwrap_err();
+    let max_in_flight = protocol.ctx.max_in_flight.load(Ordering::SeqCst);
+    assert!(max_in_flight > 1);
+    assert!(max_in_flight <= parallelism);
+}
+
+// Yields once a block has begun its transaction, so that other tasks run in the middle of adding
+// it, and fails to roll back unless `rollback_available` is set
+struct TransactionContext {
+    inner: InMemoryContext<FlakyEthereum>,
+    rollback_available: AtomicBool,
+}
+
+impl Context for TransactionContext {
+    delegate! {
+        async fn get_algorithms(&self, filter: AlgorithmsFilter, block_data: Option<BlockFilter>, include_data: bool) -> ContextResult<Vec<Algorithm>>;
+        async fn get_benchmarks(&self, filter: BenchmarksFilter, include_data: bool) -> ContextResult<Vec<Benchmark>>;
+        async fn get_block(&self, filter: BlockFilter, include_data: bool) -> ContextResult<Option<Block>>;
+        async fn get_breakthroughs(&self, filter: BreakthroughsFilter, block_data: Option<BlockFilter>) -> ContextResult<Vec<Breakthrough>>;
+        async fn get_challenges(&self, filter: ChallengesFilter, block_data: Option<BlockFilter>) -> ContextResult<Vec<Challenge>>;
+        async fn get_frauds(&self, filter: FraudsFilter, include_data: bool) -> ContextResult<Vec<Fraud>>;
+        async fn get_players(&self, filter: PlayersFilter, block_data: Option<BlockFilter>) -> ContextResult<Vec<Player>>;
+        async fn get_proofs(&self, filter: ProofsFilter, include_data: bool) -> ContextResult<Vec<Proof>>;
+        async fn get_votes(&self, breakthrough_id: &String) -> ContextResult<Vec<Vote>>;
+        async fn get_wasms(&self, filter: WasmsFilter, include_data: bool) -> ContextResult<Vec<Wasm>>;
+        async fn verify_solution(&self, settings: &BenchmarkSettings, nonce: u64, solution: &Solution) -> ContextResult<anyhow::Result<()>>;
+        async fn compute_solution(&self, settings: &BenchmarkSettings, nonce: u64, wasm_vm_config: &WasmVMConfig) -> ContextResult<anyhow::Result<SolutionData>>;
+        async fn get_transaction(&self, tx_hash: &String) -> ContextResult<Transaction>;
+        async fn get_multisig_owners(&self, address: &String) -> ContextResult<Vec<String>>;
+        async fn get_latest_eth_block_num(&self) -> ContextResult<String>;
+        async fn get_player_deposit(&self, eth_block_num: &String, player_id: &String) -> ContextResult<Option<PreciseNumber>>;
+        async fn begin_transaction(&self) -> ContextResult<()>;
+        async fn commit_transaction(&self) -> ContextResult<()>;
+        async fn add_block(&self, details: BlockDetails, data: BlockData, config: ProtocolConfig) -> ContextResult<String>;
+        async fn add_challenge_to_mempool(&self, details: ChallengeDetails) -> ContextResult<String>;
+        async fn add_algorithm_to_mempool(&self, details: AlgorithmDetails, code: String) -> ContextResult<String>;
+        async fn add_breakthrough_to_mempool(&self, details: BreakthroughDetails) -> ContextResult<String>;
+        async fn add_benchmark_to_mempool(&self, settings: BenchmarkSettings, details: BenchmarkDetails, solutions_metadata: Vec<SolutionMetaData>, solution_data: SolutionData) -> ContextResult<String>;
+        async fn add_proof_to_mempool(&self, benchmark_id: &String, solutions_data: Vec<SolutionData>) -> ContextResult<()>;
+        async fn add_fraud_to_mempool(&self, benchmark_id: &String, allegation: String) -> ContextResult<()>;
+        async fn add_wasm_to_mempool(&self, algorithm_id: &String, details: WasmDetails, wasm_blob: Option<Vec<u8>>) -> ContextResult<()>;
+        async fn add_vote(&self, breakthrough_id: &String, player_id: &String, approve: bool) -> ContextResult<()>;
+        async fn update_challenge_state(&self, challenge_id: &String, state: ChallengeState) -> ContextResult<()>;
+        async fn update_challenge_block_data(&self, challenge_id: &String, block_id: &String, block_data: ChallengeBlockData) -> ContextResult<()>;
+        async fn update_algorithm_state(&self, algorithm_id: &String, state: AlgorithmState) -> ContextResult<()>;
+        async fn update_algorithm_block_data(&self, algorithm_id: &String, block_id: &String, block_data: AlgorithmBlockData) -> ContextResult<()>;
+        async fn update_benchmark_state(&self, benchmark_id: &String, state: BenchmarkState) -> ContextResult<()>;
+        async fn update_breakthrough_state(&self, breakthrough_id: &String, state: BreakthroughState) -> ContextResult<()>;
+        async fn update_breakthrough_block_data(&self, breakthrough_id: &String, block_id: &String, block_data: BreakthroughBlockData) -> ContextResult<()>;
+        async fn update_proof_state(&self, benchmark_id: &String, state: ProofState) -> ContextResult<()>;
+        async fn update_fraud_state(&self, benchmark_id: &String, state: FraudState) -> ContextResult<()>;
+        async fn update_player_block_data(&self, player_id: &String, block_id: &String, block_data: PlayerBlockData) -> ContextResult<()>;
+        async fn update_wasm_state(&self, algorithm_id: &String, state: WasmState) -> ContextResult<()>;
+    }
+
+    // read just after add_block begins its transaction
+    async fn get_config(&self) -> ContextResult<ProtocolConfig> {
+        tokio::task::yield_now().await;
+        self.inner.get_config().await
+    }
+
+    async fn rollback_transaction(&self) -> ContextResult<()> {
+        if !self.rollback_available.load(Ordering::SeqCst) {
+            return Err(anyhow!("Connection lost"));
+        }
+        self.inner.rollback_transaction().await
+    }
+}
+
+#[tokio::test]
+async fn test_add_block_rollback_failure_keeps_error() {
+    let protocol = Protocol::new(TransactionContext {
+        inner: InMemoryContext::with_ethereum(config(), FlakyEthereum::default()),
+        rollback_available: AtomicBool::new(false),
+    });
+    let error = protocol.add_block().await.unwrap_err();
+    let ProtocolError::RollbackFailure {
+        error: inner,
+        rollback_error,
+    } = &error
+    else {
+        panic!("Expected RollbackFailure, got {:?}", error);
+    };
+    assert!(matches!(**inner, ProtocolError::ContextFailure { .. }));
+    assert_eq!(
+        format!("{:#}", rollback_error),
+        "rollback_transaction: Connection lost"
+    );
+    assert_eq!(
+        error.to_string(),
+        "Context failure in get_latest_eth_block_num: RPC unavailable. Rolling back also failed: rollback_transaction: Connection lost"
+    );
+}
+
+#[tokio::test]
+async fn test_submission_during_failed_block_is_kept() {
+    let mut config = config();
+    config.algorithm_submissions.admin_address = Some(PLAYER_ID.to_string());
+    let eth = FlakyEthereum::default();
+    eth.available.store(true, Ordering::SeqCst);
+    let (protocol, _) = setup_benchmarks_with_ethereum(config, false, eth).await;
+    let protocol = Protocol::new(TransactionContext {
+        inner: protocol.ctx,
+        rollback_available: AtomicBool::new(true),
+    });
+    let ctx = &protocol.ctx;
+    let player = get_player(&ctx.inner, None).await;
+    let algorithm_id = "c003_a001".to_string();
+
+    // the ban is submitted while the block is in its transaction, which then rolls back
+    ctx.inner.eth.available.store(false, Ordering::SeqCst);
+    let (added, banned) = tokio::join!(
+        protocol.add_block(),
+        protocol.ban_algorithm(&player, &algorithm_id, "plagiarised".to_string())
+    );
+    assert!(added.is_err());
+    banned.unwrap();
+    let algorithm = ctx
+        .get_algorithms(AlgorithmsFilter::Id(algorithm_id), None, false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert!(algorithm.state().banned);
+}
+
+// Returns the fraudulent benchmark id, with its fraud confirmed by the latest block once the
+// player has been paid for it
+async fn setup_fraud(slashing: SlashingConfig) -> (Protocol<InMemoryContext>, String) {
+    let mut config = config();
+    config.slashing = Some(slashing);
+    let (protocol, benchmark_ids) = setup_benchmarks(config, false).await;
+    let ctx = &protocol.ctx;
+    for _ in 0..2 {
+        protocol.add_block().await.unwrap();
+    }
+    ctx.add_fraud_to_mempool(&benchmark_ids[1], "invalid solution".to_string())
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+    (protocol, benchmark_ids[1].clone())
+}
+
+// Returns the rewards paid to the player in the `window` blocks before `height`
+async fn get_paid_rewards(ctx: &InMemoryContext, height: u32, window: u32) -> PreciseNumber {
+    let mut paid_rewards = PreciseNumber::from(0);
+    for height in height - window..height {
+        let player = get_player(ctx, Some(BlockFilter::Height(height))).await;
+        if let Some(reward) = player.block_data.and_then(|d| d.reward) {
+            paid_rewards = paid_rewards + reward;
+        }
+    }
+    paid_rewards
+}
+
+#[tokio::test]
+async fn test_fraud_slashing() {
+    let (protocol, fraud_benchmark_id) = setup_fraud(SlashingConfig {
+        window: 4,
+        fraction: 0.5,
+        ban_period: None,
+    })
+    .await;
+    let ctx = &protocol.ctx;
+    let block = latest_block(ctx).await;
+    let fraud = ctx
+        .get_frauds(FraudsFilter::BenchmarkId(fraud_benchmark_id.clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(*fraud.state().block_confirmed(), block.details.height);
+
+    let player = get_player(ctx, Some(BlockFilter::Id(block.id.clone()))).await;
+    let other_player = ctx
+        .get_players(
+            PlayersFilter::Id(OTHER_PLAYER_ID.to_string()),
+            Some(BlockFilter::Id(block.id.clone())),
+        )
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let (data, other_data) = (player.block_data(), other_player.block_data());
+    assert_eq!(
+        *data.slashed_benchmark_ids(),
+        HashSet::from([fraud_benchmark_id])
+    );
+    assert!(other_data.slashed_benchmark_ids().is_empty());
+    // half of the rewards paid within the window are slashed
+    let paid_rewards = get_paid_rewards(ctx, block.details.height, 4).await;
+    assert!(paid_rewards > PreciseNumber::from(0));
+    assert_eq!(
+        *data.slashed_reward(),
+        paid_rewards * PreciseNumber::from_f64(0.5)
+    );
+    assert_eq!(other_data.slashed_reward, None);
+    // the slash is taken back from the player's round earnings
+    let prev_round_earnings = if block.details.height % 2 == 0 {
+        PreciseNumber::from(0)
+    } else {
+        let prev_player = get_player(
+            ctx,
+            Some(BlockFilter::Id(block.details.prev_block_id.clone())),
+        )
+        .await;
+        *prev_player.block_data().round_earnings()
+    };
+    let round_earnings = prev_round_earnings + *data.reward();
+    assert_eq!(
+        *data.round_earnings(),
+        round_earnings - (*data.slashed_reward()).min(round_earnings)
+    );
+
+    // the player is slashed once, and the fraudulent benchmark stops qualifying
+    protocol.add_block().await.unwrap();
+    let player = get_player(ctx, Some(BlockFilter::Latest)).await;
+    let data = player.block_data();
+    assert!(data.slashed_benchmark_ids().is_empty());
+    assert_eq!(data.slashed_reward, None);
+    assert_eq!(data.num_qualifiers_by_challenge()["c003"], 5);
+}
+
+#[tokio::test]
+async fn test_fraud_slashing_inactive_player() {
+    let mut config = config();
+    config.benchmark_submissions.lifespan_period = 4;
+    config.slashing = Some(SlashingConfig {
+        window: 10,
+        fraction: 0.5,
+        ban_period: None,
+    });
+    let (protocol, benchmark_ids) = setup_benchmarks(config, false).await;
+    let ctx = &protocol.ctx;
+    while latest_block(ctx)
+        .await
+        .data()
+        .active_player_ids
+        .contains(PLAYER_ID)
+    {
+        protocol.add_block().await.unwrap();
+    }
+
+    // the player's new benchmark is found fraudulent before it is active
+    let benchmark = ctx
+        .get_benchmarks(BenchmarksFilter::Id(benchmark_ids[0].clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let block = latest_block(ctx).await;
+    let fraud_benchmark_id = ctx
+        .add_benchmark_to_mempool(
+            BenchmarkSettings {
+                block_id: block.id.clone(),
+                ..benchmark.settings.clone()
+            },
+            BenchmarkDetails {
+                block_started: block.details.height,
+                num_solutions: 5,
+            },
+            (0..5)
+                .map(|nonce| SolutionMetaData {
+                    nonce,
+                    solution_signature: 0,
+                })
+                .collect(),
+            unverified_solution_data(0),
+        )
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+    let benchmark = ctx
+        .get_benchmarks(BenchmarksFilter::Id(fraud_benchmark_id.clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let solutions_data = benchmark
+        .state()
+        .sampled_nonces()
+        .iter()
+        .map(|&nonce| unverified_solution_data(nonce))
+        .collect();
+    ctx.add_proof_to_mempool(&fraud_benchmark_id, solutions_data)
+        .await
+        .unwrap();
+    ctx.add_fraud_to_mempool(&fraud_benchmark_id, "invalid solution".to_string())
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+
+    let block = latest_block(ctx).await;
+    assert!(!block.data().active_player_ids.contains(PLAYER_ID));
+    let player = get_player(ctx, Some(BlockFilter::Id(block.id.clone()))).await;
+    let data = player.block_data();
+    assert_eq!(
+        *data.slashed_benchmark_ids(),
+        HashSet::from([fraud_benchmark_id])
+    );
+    assert_eq!(data.reward, None);
+    // half of the rewards paid within the window are slashed
+    let paid_rewards = get_paid_rewards(ctx, block.details.height, 10).await;
+    assert!(paid_rewards > PreciseNumber::from(0));
+    assert_eq!(
+        *data.slashed_reward(),
+        paid_rewards * PreciseNumber::from_f64(0.5)
+    );
+    assert_eq!(protocol.replay_block(&block.id).await.unwrap(), vec![]);
+}
+
+#[tokio::test]
+async fn test_fraud_ban() {
+    let (protocol, _) = setup_fraud(SlashingConfig {
+        window: 0,
+        fraction: 0.5,
+        ban_period: Some(10),
+    })
+    .await;
+    let ctx = &protocol.ctx;
+    let player = get_player(ctx, Some(BlockFilter::Latest)).await;
+    let data = player.block_data();
+    assert_eq!(*data.slashed_reward(), PreciseNumber::from(0));
+    assert_eq!(data.num_qualifiers_by_challenge().get("c003"), None);
+    assert_eq!(*data.influence(), PreciseNumber::from(0));
+    assert_eq!(*data.reward(), PreciseNumber::from(0));
+}
+
+#[tokio::test]
+async fn test_ban_algorithm() {
+    let mut config = config();
+    config.algorithm_submissions.admin_address = Some(ADMIN_ADDRESS.to_string());
+    let (protocol, _) = setup_benchmarks(config, false).await;
+    let ctx = &protocol.ctx;
+    let algorithm_id = "c003_a001".to_string();
+    let player = get_player(ctx, None).await;
+    assert!(matches!(
+        protocol
+            .ban_algorithm(&player, &algorithm_id, "plagiarised".to_string())
+            .await,
+        Err(ProtocolError::UnauthorisedPlayer { .. })
+    ));
+
+    // owners of the admin multisig cannot ban on their own
+    ctx.eth
+        .set_multisig_owners(ADMIN_ADDRESS.to_string(), vec![PLAYER_ID.to_string()]);
+    assert!(matches!(
+        protocol
+            .ban_algorithm(&player, &algorithm_id, "plagiarised".to_string())
+            .await,
+        Err(ProtocolError::UnauthorisedPlayer { .. })
+    ));
+
+    let admin = Player {
+        id: ADMIN_ADDRESS.to_string(),
+        details: PlayerDetails {
+            name: ADMIN_ADDRESS.to_string(),
+            is_multisig: true,
+        },
+        block_data: None,
+    };
+    protocol
+        .ban_algorithm(&admin, &algorithm_id, "plagiarised".to_string())
+        .await
+        .unwrap();
+    let algorithm = ctx
+        .get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert!(algorithm.state().banned);
+    assert_eq!(
+        *algorithm.state().block_banned(),
+        latest_block(ctx).await.details.height + 1
+    );
+    assert_eq!(algorithm.state().ban_reason(), "plagiarised");
+
+    // from the next block, the algorithm is inactive & its benchmarks no longer qualify
+    protocol.add_block().await.unwrap();
+    let block = latest_block(ctx).await;
+    assert!(!block.data().active_algorithm_ids.contains(&algorithm_id));
+    let algorithm = ctx
+        .get_algorithms(
+            AlgorithmsFilter::Id(algorithm_id),
+            Some(BlockFilter::Id(block.id.clone())),
+            false,
+        )
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(algorithm.block_data, None);
+    let player = get_player(ctx, Some(BlockFilter::Id(block.id))).await;
+    assert_eq!(
+        player
+            .block_data()
+            .num_qualifiers_by_challenge()
+            .get("c003"),
+        None
+    );
+    assert_eq!(*player.block_data().reward(), PreciseNumber::from(0));
+
+    // replaying the block before the ban keeps the algorithm active
+    for height in block.details.height - 1..=block.details.height {
+        let block = ctx
+            .get_block(BlockFilter::Height(height), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(protocol.replay_block(&block.id).await.unwrap(), vec![]);
+    }
+}
+
+#[tokio::test]
+async fn test_merge_algorithm_pushed_in_earlier_round() {
+    let mut config = config();
+    let (protocol, _) = setup_benchmarks(config.clone(), false).await;
+    let ctx = &protocol.ctx;
+    let algorithm_id = "c003_a001".to_string();
+    let get_algorithm = || async {
+        ctx.get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let round_pushed = get_algorithm().await.state().round_pushed.unwrap();
+
+    // move into a later round before the algorithm can earn enough merge points
+    while latest_block(ctx).await.details.round <= round_pushed {
+        protocol.add_block().await.unwrap();
+    }
+    config.algorithm_submissions.merge_points_threshold = 1;
+    ctx.set_config(config);
+    while get_algorithm().await.state().round_merged.is_none() {
+        let block = latest_block(ctx).await;
+        assert!(block.details.round <= round_pushed + 2);
+        protocol.add_block().await.unwrap();
+    }
+    let block = latest_block(ctx).await;
+    assert!(block.details.round > round_pushed);
+    assert_eq!(
+        get_algorithm().await.state().round_merged,
+        Some(block.details.round + 1)
+    );
+    assert_eq!(protocol.replay_block(&block.id).await.unwrap(), vec![]);
+}
+
+#[tokio::test]
+async fn test_round_earnings() {
+    let (protocol, _) = setup_benchmarks(config(), false).await;
+    let ctx = &protocol.ctx;
+    let algorithm_id = "c003_a001".to_string();
+    // blocks_per_round is 2, so a round starts at every even height
+    while latest_block(ctx).await.details.height % 2 != 0 {
+        protocol.add_block().await.unwrap();
+    }
+    let mut earnings = Vec::new();
+    for _ in 0..3 {
+        let block_id = latest_block(ctx).await.id;
+        let player = get_player(ctx, Some(BlockFilter::Id(block_id.clone()))).await;
+        let algorithm = ctx
+            .get_algorithms(
+                AlgorithmsFilter::Id(algorithm_id.clone()),
+                Some(BlockFilter::Id(block_id)),
+                false,
+            )
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        earnings.push((player.block_data.unwrap(), algorithm.block_data.unwrap()));
+        protocol.add_block().await.unwrap();
+    }
+
+    let (player_data, algorithm_data) = &earnings[0];
+    assert!(*player_data.reward() > PreciseNumber::from(0));
+    assert!(*algorithm_data.reward() > PreciseNumber::from(0));
+    assert_eq!(player_data.round_earnings(), player_data.reward());
+    assert_eq!(algorithm_data.round_earnings(), algorithm_data.reward());
+    // earnings accumulate over the round
+    let (next_player_data, next_algorithm_data) = &earnings[1];
+    assert_eq!(
+        *next_player_data.round_earnings(),
+        *player_data.reward() + *next_player_data.reward()
+    );
+    assert_eq!(
+        *next_algorithm_data.round_earnings(),
+        *algorithm_data.reward() + *next_algorithm_data.reward()
+    );
+    // and reset at the start of the next round
+    let (player_data, algorithm_data) = &earnings[2];
+    assert_eq!(player_data.round_earnings(), player_data.reward());
+    assert_eq!(algorithm_data.round_earnings(), algorithm_data.reward());
+}
+
+#[tokio::test]
+async fn test_round_earnings_carried_over_skipped_block() {
+    let mut config = config();
+    config.rounds.blocks_per_round = 4;
+    let (protocol, _) = setup_benchmarks(config.clone(), false).await;
+    let ctx = &protocol.ctx;
+    while latest_block(ctx).await.details.height % 4 != 3 {
+        protocol.add_block().await.unwrap();
+    }
+    protocol.add_block().await.unwrap();
+    let first = get_player(ctx, Some(BlockFilter::Latest)).await;
+
+    // every benchmark expires for one block, so the player has no block data mid-round
+    let mut expired_config = config.clone();
+    expired_config.benchmark_submissions.lifespan_period = 0;
+    ctx.set_config(expired_config);
+    protocol.add_block().await.unwrap();
+    let skipped = get_player(ctx, Some(BlockFilter::Latest)).await;
+    assert!(skipped.block_data.is_none());
+
+    ctx.set_config(config);
+    protocol.add_block().await.unwrap();
+    let player = get_player(ctx, Some(BlockFilter::Latest)).await;
+    let (first_data, data) = (first.block_data(), player.block_data());
+    assert!(*first_data.reward() > PreciseNumber::from(0));
+    assert_eq!(
+        *data.round_earnings(),
+        *first_data.reward() + *data.reward()
+    );
+}
+
+#[tokio::test]
+async fn test_benchmarkers_reward_without_breakthroughs() {
+    // breakthroughs are disabled, so the breakthroughs share is not paid to benchmarkers
+    let mut config = config();
+    config.rewards.distribution.breakthroughs = 0.5;
+    let (protocol, _) = setup_benchmarks(config, false).await;
+    let block_id = latest_block(&protocol.ctx).await.id;
+    let players = protocol
+        .ctx
+        .get_players(PlayersFilter::Benchmarkers, Some(BlockFilter::Id(block_id)))
+        .await
+        .unwrap();
+    assert_eq!(players.len(), 2);
+    let total_reward = players.iter().fold(PreciseNumber::from(0), |acc, p| {
+        acc + *p.block_data().reward()
+    });
+    // block_reward * distribution.benchmarkers = 100 * 0.8
+    assert_eq!(total_reward, PreciseNumber::from(80));
+}
+
+#[tokio::test]
+async fn test_replay_block() {
+    let (protocol, _) = setup_fraud(SlashingConfig {
+        window: 4,
+        fraction: 0.5,
+        ban_period: Some(2),
+    })
+    .await;
+    let ctx = &protocol.ctx;
+    for _ in 0..2 {
+        protocol.add_block().await.unwrap();
+    }
+    let latest = latest_block(ctx).await;
+    for height in 1..=latest.details.height {
+        let block = ctx
+            .get_block(BlockFilter::Height(height), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(protocol.replay_block(&block.id).await.unwrap(), vec![]);
+    }
+    let genesis = ctx
+        .get_block(BlockFilter::Height(0), false)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        protocol.replay_block(&genesis.id).await,
+        Err(ProtocolError::InvalidBlock { .. })
+    ));
+
+    // tampering with published block data is reported field by field
+    let player = get_player(ctx, Some(BlockFilter::Id(latest.id.clone()))).await;
+    let mut data = player.block_data.unwrap();
+    let reward = *data.reward();
+    data.reward = Some(reward + PreciseNumber::from(1));
+    ctx.update_player_block_data(&player.id, &latest.id, data)
+        .await
+        .unwrap();
+    let discrepancies = protocol.replay_block(&latest.id).await.unwrap();
+    assert_eq!(discrepancies.len(), 1);
+    assert_eq!(discrepancies[0].entity, "player");
+    assert_eq!(discrepancies[0].id, PLAYER_ID);
+    assert_eq!(discrepancies[0].field, "reward");
+    assert_eq!(
+        discrepancies[0].recomputed,
+        serde_json::to_value(reward).unwrap()
+    );
+
+    // tampering with state set by a block is reported by that block, and changes the entities
+    // later blocks derive as active
+    let benchmark_id = latest
+        .data()
+        .active_benchmark_ids
+        .iter()
+        .next()
+        .unwrap()
+        .clone();
+    let proof = ctx
+        .get_proofs(ProofsFilter::BenchmarkId(benchmark_id.clone()), false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let mut state = proof.state.unwrap();
+    let block_confirmed = *state.block_confirmed();
+    state.submission_delay = Some(*state.submission_delay() + 100);
+    ctx.update_proof_state(&benchmark_id, state).await.unwrap();
+    let block = ctx
+        .get_block(BlockFilter::Height(block_confirmed), false)
+        .await
+        .unwrap()
+        .unwrap();
+    let discrepancies = protocol.replay_block(&block.id).await.unwrap();
+    assert!(discrepancies.iter().any(|d| d.entity == "proof"
+        && d.id == benchmark_id
+        && d.field == "state.submission_delay"));
+    let discrepancies = protocol.replay_block(&latest.id).await.unwrap();
+    let discrepancy = discrepancies
+        .iter()
+        .find(|d| d.entity == "block" && d.field == "active_benchmark_ids")
+        .unwrap();
+    assert!(!discrepancy
+        .recomputed
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::to_value(&benchmark_id).unwrap()));
+}
+
+async fn get_breakthrough(
+    ctx: &InMemoryContext,
+    breakthrough_id: &String,
+    block: Option<BlockFilter>,
+) -> Breakthrough {
+    ctx.get_breakthroughs(BreakthroughsFilter::Id(breakthrough_id.clone()), block)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+}
+
+// PLAYER_ID claims a breakthrough for its knapsack algorithm, which is then merged. Returns the
+// breakthrough id once voting has opened
+async fn setup_breakthrough() -> (Protocol<InMemoryContext>, String) {
+    let protocol = setup().await;
+    let ctx = &protocol.ctx;
+    let mut config = config();
+    config.breakthroughs = Some(BreakthroughsConfig {
+        vote_period: 1,
+        approval_threshold: 0.5,
+        vote_weight: VoteWeight::Deposit,
+    });
+    ctx.set_config(config);
+    ctx.add_player(
+        OTHER_PLAYER_ID.to_string(),
+        PlayerDetails {
+            name: "other_player".to_string(),
+            is_multisig: false,
+        },
+    );
+    let algorithm_id = ctx
+        .add_algorithm_to_mempool(
+            AlgorithmDetails {
+                name: "dynamic".to_string(),
+                player_id: PLAYER_ID.to_string(),
+                challenge_id: "c003".to_string(),
+                tx_hash: "0x01".to_string(),
+            },
+            String::new(),
+        )
+        .await
+        .unwrap();
+    protocol.add_block().await.unwrap();
+
+    let details = BreakthroughDetails {
+        name: "dynamic".to_string(),
+        player_id: PLAYER_ID.to_string(),
+        challenge_id: "c003".to_string(),
+        algorithm_id: algorithm_id.clone(),
+    };
+    let other_player = ctx
+        .get_players(PlayersFilter::Id(OTHER_PLAYER_ID.to_string()), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert!(matches!(
+        protocol
+            .submit_breakthrough(&other_player, details.clone())
+            .await,
+        Err(ProtocolError::InvalidSubmittingPlayer { .. })
+    ));
+    let player = get_player(ctx, None).await;
+    let breakthrough_id = protocol
+        .submit_breakthrough(&player, details.clone())
+        .await
+        .unwrap();
+    assert_eq!(breakthrough_id, "c003_b001");
+    assert!(matches!(
+        protocol.submit_breakthrough(&player, details).await,
+        Err(ProtocolError::DuplicateBreakthrough { .. })
+    ));
+
+    // voting only opens once the algorithm is merged
+    protocol.add_block().await.unwrap();
+    assert!(matches!(
+        protocol.submit_vote(&player, &breakthrough_id, true).await,
+        Err(ProtocolError::VotingNotOpen { .. })
+    ));
+    let mut algorithm = ctx
+        .get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let state = algorithm.state.as_mut().unwrap();
+    state.round_merged = Some(latest_block(ctx).await.details.round);
+    ctx.update_algorithm_state(&algorithm_id, state.clone())
+        .await
+        .unwrap();
+    while get_breakthrough(ctx, &breakthrough_id, None)
+        .await
+        .state()
+        .round_voting_starts
+        .is_none()
+    {
+        protocol.add_block().await.unwrap();
+    }
+    (protocol, breakthrough_id)
+}
+
+async fn tally_votes(protocol: &Protocol<InMemoryContext>, breakthrough_id: &String) {
+    while get_breakthrough(&protocol.ctx, breakthrough_id, None)
+        .await
+        .state()
+        .round_votes_tallied
+        .is_none()
+    {
+        protocol.add_block().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_breakthrough_approved() {
+    let (protocol, breakthrough_id) = setup_breakthrough().await;
+    let ctx = &protocol.ctx;
+    ctx.eth
+        .set_player_deposit(PLAYER_ID.to_string(), PreciseNumber::from(30));
+    ctx.eth
+        .set_player_deposit(OTHER_PLAYER_ID.to_string(), PreciseNumber::from(10));
+    let player = get_player(ctx, None).await;
+    let other_player = ctx
+        .get_players(PlayersFilter::Id(OTHER_PLAYER_ID.to_string()), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    protocol
+        .submit_vote(&other_player, &breakthrough_id, true)
+        .await
+        .unwrap();
+    // a player's latest vote replaces their previous one
+    protocol
+        .submit_vote(&other_player, &breakthrough_id, false)
+        .await
+        .unwrap();
+    protocol
+        .submit_vote(&player, &breakthrough_id, true)
+        .await
+        .unwrap();
+    assert_eq!(ctx.get_votes(&breakthrough_id).await.unwrap().len(), 2);
+    tally_votes(&protocol, &breakthrough_id).await;
+    let block = latest_block(ctx).await;
+    assert_eq!(protocol.replay_block(&block.id).await.unwrap(), vec![]);
+
+    let state = get_breakthrough(ctx, &breakthrough_id, None)
+        .await
+        .state()
+        .clone();
+    assert_eq!(*state.votes_for(), PreciseNumber::from(30));
+    assert_eq!(*state.votes_against(), PreciseNumber::from(10));
+    assert_eq!(state.round_active, state.round_votes_tallied);
+    assert!(matches!(
+        protocol.submit_vote(&player, &breakthrough_id, true).await,
+        Err(ProtocolError::VotingNotOpen { .. })
+    ));
+
+    // the breakthrough earns the breakthroughs share of one of the two active challenges
+    protocol.add_block().await.unwrap();
+    let breakthrough = get_breakthrough(ctx, &breakthrough_id, Some(BlockFilter::Latest)).await;
+    assert_eq!(
+        *breakthrough.block_data().reward(),
+        PreciseNumber::from_f64(2.5)
+    );
+}
+
+#[tokio::test]
+async fn test_breakthrough_rejected() {
+    let (protocol, breakthrough_id) = setup_breakthrough().await;
+    let ctx = &protocol.ctx;
+    ctx.eth
+        .set_player_deposit(PLAYER_ID.to_string(), PreciseNumber::from(10));
+    ctx.eth
+        .set_player_deposit(OTHER_PLAYER_ID.to_string(), PreciseNumber::from(30));
+    let player = get_player(ctx, None).await;
+    let other_player = ctx
+        .get_players(PlayersFilter::Id(OTHER_PLAYER_ID.to_string()), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    protocol
+        .submit_vote(&player, &breakthrough_id, true)
+        .await
+        .unwrap();
+    protocol
+        .submit_vote(&other_player, &breakthrough_id, false)
+        .await
+        .unwrap();
+    tally_votes(&protocol, &breakthrough_id).await;
+
+    protocol.add_block().await.unwrap();
+    let breakthrough = get_breakthrough(ctx, &breakthrough_id, Some(BlockFilter::Latest)).await;
+    assert_eq!(breakthrough.state().round_active, None);
+    assert_eq!(breakthrough.block_data, None);
+}
@@ -0,0 +1,242 @@
+mod common;
+
+use common::*;
+use std::{fs, path::PathBuf};
+use tig_protocol::{context::*, sqlite::SqliteContext, Protocol};
+use tig_structs::core::*;
+
+fn db_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tig-protocol-{}.sqlite", name));
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = fs::remove_file(format!("{}{}", path.display(), suffix));
+    }
+    path
+}
+
+#[tokio::test]
+async fn test_block_filters() {
+    let protocol = Protocol::new(SqliteContext::open(db_path("block_filters"), config()).unwrap());
+    for _ in 0..3 {
+        protocol.add_block().await.unwrap();
+    }
+    let ctx = &protocol.ctx;
+    let latest = ctx
+        .get_block(BlockFilter::Latest, true)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.details.height, 3);
+    assert!(latest.data.is_some());
+    assert_eq!(
+        ctx.get_block(BlockFilter::Id(latest.id.clone()), false)
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+        None
+    );
+    // blocks_per_round is 2, so round 2 is heights 2 & 3
+    assert_eq!(
+        ctx.get_block(BlockFilter::Round(2), false)
+            .await
+            .unwrap()
+            .unwrap()
+            .id,
+        latest.id
+    );
+    let block1 = ctx
+        .get_block(BlockFilter::Height(1), false)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(block1.details.round, 1);
+    assert_eq!(
+        ctx.get_block(BlockFilter::Round(1), false)
+            .await
+            .unwrap()
+            .unwrap()
+            .id,
+        block1.id
+    );
+    assert!(ctx
+        .get_block(BlockFilter::Height(4), false)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_rollback_transaction() {
+    let ctx = SqliteContext::open(db_path("rollback"), config()).unwrap();
+    let genesis = ctx
+        .get_block(BlockFilter::Latest, true)
+        .await
+        .unwrap()
+        .unwrap();
+    ctx.begin_transaction().await.unwrap();
+    ctx.add_challenge_to_mempool(ChallengeDetails {
+        name: "knapsack".to_string(),
+    })
+    .await
+    .unwrap();
+    ctx.add_block(
+        BlockDetails {
+            prev_block_id: genesis.id.clone(),
+            height: 1,
+            round: 1,
+            eth_block_num: None,
+        },
+        genesis.data.clone().unwrap(),
+        config(),
+    )
+    .await
+    .unwrap();
+    ctx.rollback_transaction().await.unwrap();
+
+    let latest = ctx
+        .get_block(BlockFilter::Latest, false)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.id, genesis.id);
+    assert!(ctx
+        .get_challenges(ChallengesFilter::Mempool, None)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_state_persists_across_reopen() {
+    let path = db_path("reopen");
+    {
+        let protocol = Protocol::new(SqliteContext::open(&path, config()).unwrap());
+        let ctx = &protocol.ctx;
+        ctx.add_player(
+            PLAYER_ID.to_string(),
+            PlayerDetails {
+                name: "player".to_string(),
+                is_multisig: false,
+            },
+        )
+        .unwrap();
+        for name in ["satisfiability", "vehicle_routing", "knapsack"] {
+            ctx.add_challenge_to_mempool(ChallengeDetails {
+                name: name.to_string(),
+            })
+            .await
+            .unwrap();
+        }
+        protocol.add_block().await.unwrap();
+        ctx.update_challenge_state(
+            &"c003".to_string(),
+            ChallengeState {
+                block_confirmed: Some(1),
+                round_active: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let mut config = config();
+    config.rounds.blocks_per_round = 10;
+    let ctx = SqliteContext::open(&path, config).unwrap();
+    let latest = ctx
+        .get_block(BlockFilter::Latest, false)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.details.height, 1);
+    assert_eq!(latest.config().rounds.blocks_per_round, 2);
+    assert_eq!(ctx.get_config().await.unwrap().rounds.blocks_per_round, 10);
+
+    let challenges = ctx
+        .get_challenges(ChallengesFilter::Confirmed, Some(BlockFilter::Latest))
+        .await
+        .unwrap();
+    assert_eq!(challenges.len(), 3);
+    let knapsack = ctx
+        .get_challenges(ChallengesFilter::Name("knapsack".to_string()), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(knapsack.id, "c003");
+    assert_eq!(knapsack.state().round_active, Some(1));
+    assert_eq!(
+        ctx.get_players(PlayersFilter::Id(PLAYER_ID.to_string()), None)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(ctx
+        .update_challenge_state(
+            &"c004".to_string(),
+            ChallengeState {
+                block_confirmed: None,
+                round_active: None,
+            },
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_breakthroughs_and_votes() {
+    let ctx = SqliteContext::open(db_path("breakthroughs"), config()).unwrap();
+    ctx.add_challenge_to_mempool(ChallengeDetails {
+        name: "satisfiability".to_string(),
+    })
+    .await
+    .unwrap();
+    let algorithm_id = ctx
+        .add_algorithm_to_mempool(
+            AlgorithmDetails {
+                name: "algorithm".to_string(),
+                player_id: PLAYER_ID.to_string(),
+                challenge_id: "c001".to_string(),
+                tx_hash: "0x01".to_string(),
+            },
+            String::new(),
+        )
+        .await
+        .unwrap();
+    let breakthrough_id = ctx
+        .add_breakthrough_to_mempool(BreakthroughDetails {
+            name: "breakthrough".to_string(),
+            player_id: PLAYER_ID.to_string(),
+            challenge_id: "c001".to_string(),
+            algorithm_id: algorithm_id.clone(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(breakthrough_id, "c001_b001");
+    assert_eq!(
+        ctx.get_breakthroughs(BreakthroughsFilter::AlgorithmId(algorithm_id), None)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(ctx
+        .get_breakthroughs(BreakthroughsFilter::Confirmed, None)
+        .await
+        .unwrap()
+        .is_empty());
+
+    for approve in [false, true] {
+        ctx.add_vote(&breakthrough_id, &PLAYER_ID.to_string(), approve)
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        ctx.get_votes(&breakthrough_id).await.unwrap(),
+        vec![Vote {
+            breakthrough_id,
+            player_id: PLAYER_ID.to_string(),
+            approve: true,
+        }]
+    );
+}
@@ -0,0 +1,94 @@
+use anyhow::{anyhow, Result};
+use futures::{
+    future::{self, LocalBoxFuture},
+    FutureExt,
+};
+use std::{panic::AssertUnwindSafe, thread, time::Duration};
+use tig_protocol::{context::Context, Protocol};
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::{self, Instant, Interval},
+};
+
+type Job<T> = Box<dyn for<'a> FnOnce(&'a Protocol<T>) -> LocalBoxFuture<'a, ()> + Send>;
+
+// Context futures are not Send, so the protocol lives on its own thread and runs one job at a
+// time. This also means submissions never interleave with adding a block. A job that panics is
+// dropped, so the thread carries on with the next one
+pub struct ProtocolHandle<T: Context> {
+    jobs: mpsc::UnboundedSender<Job<T>>,
+}
+
+impl<T: Context> Clone for ProtocolHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+impl<T: Context + Send + 'static> ProtocolHandle<T> {
+    /// Moves `protocol` onto its own thread. If `block_interval` is set, a block is added at
+    /// that interval
+    pub fn spawn(protocol: Protocol<T>, block_interval: Option<Duration>) -> Self {
+        let (jobs, mut receiver) = mpsc::unbounded_channel::<Job<T>>();
+        thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_time()
+                .build()
+                .expect("Failed to start protocol runtime");
+            runtime.block_on(async move {
+                let mut interval =
+                    block_interval.map(|period| time::interval_at(Instant::now() + period, period));
+                loop {
+                    tokio::select! {
+                        job = receiver.recv() => match job {
+                            Some(job) => {
+                                if AssertUnwindSafe(job(&protocol)).catch_unwind().await.is_err() {
+                                    eprintln!("Protocol job panicked");
+                                }
+                            }
+                            None => break,
+                        },
+                        _ = tick(&mut interval) => {
+                            match AssertUnwindSafe(protocol.add_block()).catch_unwind().await {
+                                Ok(Ok(block_id)) => println!("Added block {}", block_id),
+                                Ok(Err(e)) => eprintln!("Failed to add block: {}", e),
+                                Err(_) => eprintln!("Failed to add block: panicked"),
+                            }
+                        }
+                    }
+                }
+            });
+        });
+        Self { jobs }
+    }
+}
+
+impl<T: Context> ProtocolHandle<T> {
+    /// Runs `f` on the protocol thread and returns its result. Errors if `f` panics or the
+    /// protocol thread has stopped
+    pub async fn call<R: Send + 'static>(
+        &self,
+        f: impl for<'a> FnOnce(&'a Protocol<T>) -> LocalBoxFuture<'a, R> + Send + 'static,
+    ) -> Result<R> {
+        let (sender, receiver) = oneshot::channel();
+        self.jobs
+            .send(Box::new(move |protocol| {
+                Box::pin(async move {
+                    let _ = sender.send(f(protocol).await);
+                })
+            }))
+            .map_err(|_| anyhow!("Protocol thread has stopped"))?;
+        receiver.await.map_err(|_| anyhow!("Protocol job panicked"))
+    }
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => future::pending().await,
+    }
+}
@@ -0,0 +1,4 @@
+mod handle;
+mod routes;
+pub use handle::ProtocolHandle;
+pub use routes::{routes, ApiKeys, RegisterPlayer};
@@ -0,0 +1,135 @@
+use anyhow::{anyhow, Result};
+use clap::{arg, ArgAction, Command};
+use std::{fs, path::PathBuf, time::Duration};
+use tig_challenges::registry;
+use tig_protocol::{context::*, in_memory::InMemoryContext, sqlite::SqliteContext, Protocol};
+use tig_server::{routes, ApiKeys, ProtocolHandle, RegisterPlayer};
+use tig_structs::{config::ProtocolConfig, core::*};
+use tig_utils::dejsonify;
+
+fn cli() -> Command {
+    Command::new("tig-server")
+        .about("Serves the TIG API for a private network running its own protocol config")
+        .arg_required_else_help(true)
+        .arg(
+            arg!(<CONFIG> "Path to protocol config json file")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--db [DB] "Path to SQLite database. Defaults to an in-memory chain")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--port [PORT] "Port to serve the API on")
+                .default_value("3000")
+                .value_parser(clap::value_parser!(u16)),
+        )
+        .arg(
+            arg!(--"block-interval" [SECONDS] "Seconds between blocks. 0 disables adding blocks")
+                .default_value("10")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(
+            arg!(--"api-key" [API_KEY] "Api key of a player, as KEY=PLAYER_ID. Can be repeated")
+                .action(ArgAction::Append),
+        )
+}
+
+#[tokio::main]
+async fn main() {
+    let matches = cli().get_matches();
+
+    if let Err(e) = run(
+        matches.get_one::<PathBuf>("CONFIG").unwrap().clone(),
+        matches.get_one::<PathBuf>("db").cloned(),
+        *matches.get_one::<u16>("port").unwrap(),
+        *matches.get_one::<u64>("block-interval").unwrap(),
+        matches
+            .get_many::<String>("api-key")
+            .unwrap_or_default()
+            .cloned()
+            .collect(),
+    )
+    .await
+    {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run(
+    config_path: PathBuf,
+    db_path: Option<PathBuf>,
+    port: u16,
+    block_interval: u64,
+    api_keys: Vec<String>,
+) -> Result<()> {
+    let config = fs::read_to_string(&config_path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", config_path.display(), e))?;
+    let config = dejsonify::<ProtocolConfig>(&config)
+        .map_err(|e| anyhow!("Failed to parse protocol config: {}", e))?;
+    let api_keys = api_keys
+        .iter()
+        .map(|s| match s.split_once('=') {
+            Some((key, player_id)) => Ok((key.to_string(), player_id.to_string())),
+            None => Err(anyhow!("Invalid api key '{}'. Expecting KEY=PLAYER_ID", s)),
+        })
+        .collect::<Result<ApiKeys>>()?;
+    let block_interval = (block_interval > 0).then(|| Duration::from_secs(block_interval));
+
+    match db_path {
+        Some(path) => {
+            let ctx = SqliteContext::open(path, config)?;
+            for player_id in api_keys.values() {
+                ctx.add_player(player_id.clone(), player_details(player_id))?;
+            }
+            serve(ctx, api_keys, port, block_interval).await
+        }
+        None => {
+            let ctx = InMemoryContext::new(config);
+            for player_id in api_keys.values() {
+                ctx.add_player(player_id.clone(), player_details(player_id));
+            }
+            serve(ctx, api_keys, port, block_interval).await
+        }
+    }
+}
+
+fn player_details(player_id: &str) -> PlayerDetails {
+    PlayerDetails {
+        name: player_id.to_string(),
+        is_multisig: false,
+    }
+}
+
+async fn serve<T: Context + RegisterPlayer + Send + 'static>(
+    ctx: T,
+    api_keys: ApiKeys,
+    port: u16,
+    block_interval: Option<Duration>,
+) -> Result<()> {
+    // A fresh chain has no challenges. They are confirmed in the next block
+    let has_challenges = !ctx
+        .get_challenges(ChallengesFilter::Confirmed, None)
+        .await?
+        .is_empty()
+        || !ctx
+            .get_challenges(ChallengesFilter::Mempool, None)
+            .await?
+            .is_empty();
+    if !has_challenges {
+        for challenge in registry::all() {
+            ctx.add_challenge_to_mempool(ChallengeDetails {
+                name: challenge.name().to_string(),
+            })
+            .await?;
+        }
+    }
+
+    let protocol = ProtocolHandle::spawn(Protocol::new(ctx), block_interval);
+    println!("Serving on port {}", port);
+    warp::serve(routes(protocol, api_keys))
+        .run(([0, 0, 0, 0], port))
+        .await;
+    Ok(())
+}
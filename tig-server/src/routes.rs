@@ -0,0 +1,509 @@
+use crate::ProtocolHandle;
+use futures::future::LocalBoxFuture;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tig_protocol::{
+    context::*,
+    in_memory::{EthereumProvider, InMemoryContext},
+    sqlite::SqliteContext,
+    Protocol, ProtocolError,
+};
+use tig_structs::{api::*, core::*};
+use tig_utils::{md5_from_bytes, recover_address_from_msg_and_sig};
+use warp::{
+    filters::BoxedFilter,
+    http::StatusCode,
+    reply::{Reply, Response},
+    Filter, Rejection,
+};
+
+type ApiResult<T> = Result<T, (StatusCode, String)>;
+
+// Maps an api key to the id of its player
+pub type ApiKeys = HashMap<String, String>;
+
+/// Players are registered off-chain, so contexts that can be served must be able to add the
+/// player of a newly requested api key
+pub trait RegisterPlayer {
+    fn register_player(&self, id: String, details: PlayerDetails) -> ContextResult<()>;
+}
+
+impl<E: EthereumProvider> RegisterPlayer for InMemoryContext<E> {
+    fn register_player(&self, id: String, details: PlayerDetails) -> ContextResult<()> {
+        self.add_player(id, details);
+        Ok(())
+    }
+}
+
+impl<E: EthereumProvider> RegisterPlayer for SqliteContext<E> {
+    fn register_player(&self, id: String, details: PlayerDetails) -> ContextResult<()> {
+        self.add_player(id, details)
+    }
+}
+
+#[derive(Deserialize)]
+struct GetBlockQuery {
+    id: Option<String>,
+    height: Option<u32>,
+    round: Option<u32>,
+    #[serde(default)]
+    include_data: bool,
+}
+
+#[derive(Deserialize)]
+struct GetPlayersQuery {
+    block_id: String,
+    player_type: String,
+}
+
+/// The GET & POST routes of swagger.yaml, served from `protocol`. Submissions must have an
+/// `x-api-key` header in `api_keys`, otherwise they are rejected with a 401. Keys issued by
+/// `request-api-key` are added to `api_keys`
+pub fn routes<T: Context + RegisterPlayer + 'static>(
+    protocol: ProtocolHandle<T>,
+    api_keys: ApiKeys,
+) -> BoxedFilter<(Response,)> {
+    let api_keys = Arc::new(RwLock::new(api_keys));
+    let with_protocol = warp::any().map(move || protocol.clone());
+    let with_api_keys = {
+        let api_keys = api_keys.clone();
+        warp::any().map(move || api_keys.clone())
+    };
+    let with_player_id =
+        warp::header::optional::<String>("x-api-key").map(move |api_key: Option<String>| {
+            api_key.and_then(|key| api_keys.read().unwrap().get(&key).cloned())
+        });
+
+    let get_block = warp::path!("get-block")
+        .and(warp::get())
+        .and(with_protocol.clone())
+        .and(warp::query::<GetBlockQuery>())
+        .and_then(|protocol, query| respond(protocol, move |p| Box::pin(get_block(p, query))));
+    let get_challenges = warp::path!("get-challenges")
+        .and(warp::get())
+        .and(with_protocol.clone())
+        .and(warp::query::<GetChallengesReq>())
+        .and_then(|protocol, req| respond(protocol, move |p| Box::pin(get_challenges(p, req))));
+    let get_algorithms = warp::path!("get-algorithms")
+        .and(warp::get())
+        .and(with_protocol.clone())
+        .and(warp::query::<GetAlgorithmsReq>())
+        .and_then(|protocol, req| respond(protocol, move |p| Box::pin(get_algorithms(p, req))));
+    let get_players = warp::path!("get-players")
+        .and(warp::get())
+        .and(with_protocol.clone())
+        .and(warp::query::<GetPlayersQuery>())
+        .and_then(|protocol, query| respond(protocol, move |p| Box::pin(get_players(p, query))));
+    let get_benchmarks = warp::path!("get-benchmarks")
+        .and(warp::get())
+        .and(with_protocol.clone())
+        .and(warp::query::<GetBenchmarksReq>())
+        .and_then(|protocol, req| respond(protocol, move |p| Box::pin(get_benchmarks(p, req))));
+    let get_benchmark_data = warp::path!("get-benchmark-data")
+        .and(warp::get())
+        .and(with_protocol.clone())
+        .and(warp::query::<GetBenchmarkDataReq>())
+        .and_then(|protocol, req| respond(protocol, move |p| Box::pin(get_benchmark_data(p, req))));
+    let request_api_key = warp::path!("request-api-key")
+        .and(warp::post())
+        .and(with_protocol.clone())
+        .and(with_api_keys)
+        .and(warp::body::json::<RequestApiKeyReq>())
+        .and_then(|protocol, api_keys, req| {
+            respond(protocol, move |p| {
+                Box::pin(request_api_key(p, api_keys, req))
+            })
+        });
+    let submit_algorithm = warp::path!("submit-algorithm")
+        .and(warp::post())
+        .and(with_protocol.clone())
+        .and(with_player_id.clone())
+        .and(warp::body::json::<SubmitAlgorithmReq>())
+        .and_then(|protocol, player_id, req| {
+            respond(protocol, move |p| {
+                Box::pin(submit_algorithm(p, player_id, req))
+            })
+        });
+    let submit_benchmark = warp::path!("submit-benchmark")
+        .and(warp::post())
+        .and(with_protocol.clone())
+        .and(with_player_id.clone())
+        .and(warp::body::json::<SubmitBenchmarkReq>())
+        .and_then(|protocol, player_id, req| {
+            respond(protocol, move |p| {
+                Box::pin(submit_benchmark(p, player_id, req))
+            })
+        });
+    let submit_proof = warp::path!("submit-proof")
+        .and(warp::post())
+        .and(with_protocol)
+        .and(with_player_id)
+        .and(warp::body::json::<SubmitProofReq>())
+        .and_then(|protocol, player_id, req| {
+            respond(protocol, move |p| Box::pin(submit_proof(p, player_id, req)))
+        });
+
+    get_block
+        .or(get_challenges)
+        .unify()
+        .or(get_algorithms)
+        .unify()
+        .or(get_players)
+        .unify()
+        .or(get_benchmarks)
+        .unify()
+        .or(get_benchmark_data)
+        .unify()
+        .or(request_api_key)
+        .unify()
+        .or(submit_algorithm)
+        .unify()
+        .or(submit_benchmark)
+        .unify()
+        .or(submit_proof)
+        .unify()
+        .boxed()
+}
+
+async fn respond<T: Context, R: Serialize + Send + 'static>(
+    protocol: ProtocolHandle<T>,
+    f: impl for<'a> FnOnce(&'a Protocol<T>) -> LocalBoxFuture<'a, ApiResult<R>> + Send + 'static,
+) -> Result<Response, Rejection> {
+    Ok(match protocol.call(f).await {
+        Ok(Ok(resp)) => warp::reply::json(&resp).into_response(),
+        Ok(Err((status, msg))) => warp::reply::with_status(msg, status).into_response(),
+        Err(e) => warp::reply::with_status(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR)
+            .into_response(),
+    })
+}
+
+// A context failure is the server's fault, whereas any other error is the request's
+fn protocol_error(e: ProtocolError) -> (StatusCode, String) {
+    match e {
+        ProtocolError::ContextFailure { .. } => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        _ => (StatusCode::BAD_REQUEST, e.to_string()),
+    }
+}
+
+fn context_failure(error: ContextError) -> (StatusCode, String) {
+    protocol_error(ProtocolError::ContextFailure { error })
+}
+
+async fn get_block_by_id<T: Context>(protocol: &Protocol<T>, block_id: &str) -> ApiResult<Block> {
+    protocol
+        .ctx
+        .get_block(BlockFilter::Id(block_id.to_string()), false)
+        .await
+        .map_err(context_failure)?
+        .ok_or_else(|| {
+            protocol_error(ProtocolError::InvalidBlock {
+                block_id: block_id.to_string(),
+            })
+        })
+}
+
+async fn get_player<T: Context>(
+    protocol: &Protocol<T>,
+    player_id: Option<String>,
+) -> ApiResult<Player> {
+    let unauthorised = || (StatusCode::UNAUTHORIZED, "Invalid api key".to_string());
+    let player_id = player_id.ok_or_else(unauthorised)?;
+    protocol
+        .ctx
+        .get_players(PlayersFilter::Id(player_id), None)
+        .await
+        .map_err(context_failure)?
+        .pop()
+        .ok_or_else(unauthorised)
+}
+
+async fn get_block<T: Context>(
+    protocol: &Protocol<T>,
+    query: GetBlockQuery,
+) -> ApiResult<GetBlockResp> {
+    let filter = match query {
+        GetBlockQuery { id: Some(id), .. } => BlockFilter::Id(id),
+        GetBlockQuery {
+            height: Some(height),
+            ..
+        } => BlockFilter::Height(height),
+        GetBlockQuery {
+            round: Some(round), ..
+        } => BlockFilter::Round(round),
+        _ => BlockFilter::Latest,
+    };
+    let block = protocol
+        .ctx
+        .get_block(filter, query.include_data)
+        .await
+        .map_err(context_failure)?;
+    Ok(GetBlockResp { block })
+}
+
+async fn get_challenges<T: Context>(
+    protocol: &Protocol<T>,
+    req: GetChallengesReq,
+) -> ApiResult<GetChallengesResp> {
+    let block = get_block_by_id(protocol, &req.block_id).await?;
+    let challenges = protocol
+        .ctx
+        .get_challenges(
+            ChallengesFilter::Confirmed,
+            Some(BlockFilter::Id(block.id.clone())),
+        )
+        .await
+        .map_err(context_failure)?;
+    Ok(GetChallengesResp {
+        block_id: block.id,
+        block_details: block.details,
+        challenges,
+    })
+}
+
+async fn get_algorithms<T: Context>(
+    protocol: &Protocol<T>,
+    req: GetAlgorithmsReq,
+) -> ApiResult<GetAlgorithmsResp> {
+    let block = get_block_by_id(protocol, &req.block_id).await?;
+    let algorithms = protocol
+        .ctx
+        .get_algorithms(
+            AlgorithmsFilter::Confirmed,
+            Some(BlockFilter::Id(block.id.clone())),
+            false,
+        )
+        .await
+        .map_err(context_failure)?;
+    let wasms = protocol
+        .ctx
+        .get_wasms(WasmsFilter::Confirmed, false)
+        .await
+        .map_err(context_failure)?;
+    Ok(GetAlgorithmsResp {
+        block_id: block.id,
+        block_details: block.details,
+        algorithms,
+        wasms,
+    })
+}
+
+async fn get_players<T: Context>(
+    protocol: &Protocol<T>,
+    query: GetPlayersQuery,
+) -> ApiResult<GetPlayersResp> {
+    let filter = match PlayerType::from_string(query.player_type) {
+        Ok(PlayerType::Benchmarker) => PlayersFilter::Benchmarkers,
+        Ok(PlayerType::Innovator) => PlayersFilter::Innovators,
+        Err(e) => return Err((StatusCode::BAD_REQUEST, e)),
+    };
+    let block = get_block_by_id(protocol, &query.block_id).await?;
+    let players = protocol
+        .ctx
+        .get_players(filter, Some(BlockFilter::Id(block.id.clone())))
+        .await
+        .map_err(context_failure)?;
+    Ok(GetPlayersResp {
+        block_id: block.id,
+        block_details: block.details,
+        players,
+    })
+}
+
+// Benchmarks started within the lifespan period of the block, as older ones can no longer be
+// proven
+async fn get_benchmarks<T: Context>(
+    protocol: &Protocol<T>,
+    req: GetBenchmarksReq,
+) -> ApiResult<GetBenchmarksResp> {
+    let block = get_block_by_id(protocol, &req.block_id).await?;
+    let config = protocol.ctx.get_config().await.map_err(context_failure)?;
+    let from_block_started = block
+        .details
+        .height
+        .saturating_sub(config.benchmark_submissions.lifespan_period);
+    let benchmarks: Vec<Benchmark> = protocol
+        .ctx
+        .get_benchmarks(BenchmarksFilter::Confirmed { from_block_started }, false)
+        .await
+        .map_err(context_failure)?
+        .into_iter()
+        .filter(|b| b.settings.player_id == req.player_id)
+        .collect();
+    let benchmark_ids: Vec<&String> = benchmarks.iter().map(|b| &b.id).collect();
+    let proofs = protocol
+        .ctx
+        .get_proofs(ProofsFilter::Confirmed { from_block_started }, false)
+        .await
+        .map_err(context_failure)?
+        .into_iter()
+        .filter(|p| benchmark_ids.contains(&&p.benchmark_id))
+        .collect();
+    let frauds = protocol
+        .ctx
+        .get_frauds(FraudsFilter::Confirmed { from_block_started }, false)
+        .await
+        .map_err(context_failure)?
+        .into_iter()
+        .filter(|f| benchmark_ids.contains(&&f.benchmark_id))
+        .collect();
+    Ok(GetBenchmarksResp {
+        block_id: block.id,
+        block_details: block.details,
+        benchmarks,
+        proofs,
+        frauds,
+    })
+}
+
+async fn get_benchmark_data<T: Context>(
+    protocol: &Protocol<T>,
+    req: GetBenchmarkDataReq,
+) -> ApiResult<GetBenchmarkDataResp> {
+    let benchmark = protocol
+        .ctx
+        .get_benchmarks(BenchmarksFilter::Id(req.benchmark_id.clone()), true)
+        .await
+        .map_err(context_failure)?
+        .pop();
+    let proof = protocol
+        .ctx
+        .get_proofs(ProofsFilter::BenchmarkId(req.benchmark_id.clone()), true)
+        .await
+        .map_err(context_failure)?
+        .pop();
+    let fraud = protocol
+        .ctx
+        .get_frauds(FraudsFilter::BenchmarkId(req.benchmark_id), true)
+        .await
+        .map_err(context_failure)?
+        .pop();
+    Ok(GetBenchmarkDataResp {
+        benchmark,
+        proof,
+        fraud,
+    })
+}
+
+// There is no Ethereum provider to look up the owners of a multisig, so only addresses that
+// signed the message themselves are issued a key
+async fn request_api_key<T: Context + RegisterPlayer>(
+    protocol: &Protocol<T>,
+    api_keys: Arc<RwLock<ApiKeys>>,
+    req: RequestApiKeyReq,
+) -> ApiResult<RequestApiKeyResp> {
+    let bad_request = |msg: String| (StatusCode::BAD_REQUEST, msg);
+    if req.gnosis_safe_setup_tx_hash.is_some() {
+        return Err(bad_request(
+            "Multisig addresses are not supported".to_string(),
+        ));
+    }
+    if req.address != req.address.to_lowercase() {
+        return Err(bad_request(format!(
+            "Address '{}' must be lowercase",
+            req.address
+        )));
+    }
+    let msg = format!(
+        "I am signing this message to prove that I control address {}",
+        req.address
+    );
+    let signer = recover_address_from_msg_and_sig(&msg, &req.signature)
+        .map_err(|e| bad_request(format!("Invalid signature: {}", e)))?;
+    if signer != req.address {
+        return Err(bad_request(format!(
+            "Signature is from '{}' instead of '{}'",
+            signer, req.address
+        )));
+    }
+
+    // a player that requests again gets the same key
+    let existing_key = api_keys
+        .read()
+        .unwrap()
+        .iter()
+        .find(|(_, player_id)| **player_id == req.address)
+        .map(|(api_key, _)| api_key.clone());
+    if let Some(api_key) = existing_key {
+        return Ok(RequestApiKeyResp { api_key });
+    }
+    let is_registered = !protocol
+        .ctx
+        .get_players(PlayersFilter::Id(req.address.clone()), None)
+        .await
+        .map_err(context_failure)?
+        .is_empty();
+    if !is_registered {
+        protocol
+            .ctx
+            .register_player(
+                req.address.clone(),
+                PlayerDetails {
+                    name: req.address.clone(),
+                    is_multisig: false,
+                },
+            )
+            .map_err(context_failure)?;
+    }
+    let api_key = md5_from_bytes(&rand::random::<[u8; 32]>());
+    api_keys
+        .write()
+        .unwrap()
+        .insert(api_key.clone(), req.address);
+    Ok(RequestApiKeyResp { api_key })
+}
+
+async fn submit_algorithm<T: Context>(
+    protocol: &Protocol<T>,
+    player_id: Option<String>,
+    req: SubmitAlgorithmReq,
+) -> ApiResult<SubmitAlgorithmResp> {
+    let player = get_player(protocol, player_id).await?;
+    let details = AlgorithmDetails {
+        name: req.name,
+        player_id: player.id.clone(),
+        challenge_id: req.challenge_id,
+        tx_hash: req.tx_hash,
+    };
+    let algorithm_id = protocol
+        .submit_algorithm(&player, details, req.code)
+        .await
+        .map_err(protocol_error)?;
+    Ok(SubmitAlgorithmResp { algorithm_id })
+}
+
+async fn submit_benchmark<T: Context>(
+    protocol: &Protocol<T>,
+    player_id: Option<String>,
+    req: SubmitBenchmarkReq,
+) -> ApiResult<SubmitBenchmarkResp> {
+    let player = get_player(protocol, player_id).await?;
+    let (benchmark_id, verified) = protocol
+        .submit_benchmark(
+            &player,
+            req.settings,
+            req.solutions_meta_data,
+            req.solution_data,
+        )
+        .await
+        .map_err(protocol_error)?;
+    Ok(SubmitBenchmarkResp {
+        benchmark_id,
+        verified,
+    })
+}
+
+async fn submit_proof<T: Context>(
+    protocol: &Protocol<T>,
+    player_id: Option<String>,
+    req: SubmitProofReq,
+) -> ApiResult<SubmitProofResp> {
+    let player = get_player(protocol, player_id).await?;
+    let verified = protocol
+        .submit_proof(&player, &req.benchmark_id, req.solutions_data)
+        .await
+        .map_err(protocol_error)?;
+    Ok(SubmitProofResp { verified })
+}
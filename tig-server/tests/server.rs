@@ -0,0 +1,252 @@
+use std::{collections::HashMap, net::SocketAddr};
+use tig_api::*;
+use tig_protocol::{context::*, in_memory::InMemoryContext, Protocol};
+use tig_server::{routes, ProtocolHandle};
+use tig_structs::{config::ProtocolConfig, core::*};
+use tig_utils::dejsonify;
+use web3::signing::{hash_message, Key, SecretKey, SecretKeyRef};
+
+const API_KEY: &str = "api_key";
+const PLAYER_ID: &str = "0x0000000000000000000000000000000000000001";
+
+fn config() -> ProtocolConfig {
+    dejsonify(include_str!("../example_config.json")).unwrap()
+}
+
+async fn setup() -> (ProtocolHandle<InMemoryContext>, SocketAddr) {
+    let ctx = InMemoryContext::new(config());
+    ctx.add_player(
+        PLAYER_ID.to_string(),
+        PlayerDetails {
+            name: "player".to_string(),
+            is_multisig: false,
+        },
+    );
+    for name in ["satisfiability", "vehicle_routing", "knapsack"] {
+        ctx.add_challenge_to_mempool(ChallengeDetails {
+            name: name.to_string(),
+        })
+        .await
+        .unwrap();
+    }
+    let protocol = ProtocolHandle::spawn(Protocol::new(ctx), None);
+    let api_keys = HashMap::from([(API_KEY.to_string(), PLAYER_ID.to_string())]);
+    let (addr, server) =
+        warp::serve(routes(protocol.clone(), api_keys)).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+    (protocol, addr)
+}
+
+fn api(addr: SocketAddr, api_key: &str) -> Api {
+    Api::new(format!("http://{}", addr), api_key.to_string())
+}
+
+async fn latest_block(api: &Api) -> Block {
+    api.get_block(GetBlockReq {
+        id: None,
+        round: None,
+        height: None,
+        include_data: false,
+    })
+    .await
+    .unwrap()
+    .block
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_routes() {
+    let (protocol, addr) = setup().await;
+    let api = api(addr, API_KEY);
+
+    let genesis = latest_block(&api).await;
+    assert_eq!(genesis.details.height, 0);
+    let resp = api
+        .get_challenges(GetChallengesReq {
+            block_id: genesis.id.clone(),
+        })
+        .await
+        .unwrap();
+    assert!(resp.challenges.is_empty());
+
+    protocol
+        .call(|p| Box::pin(async move { p.add_block().await.unwrap() }))
+        .await
+        .unwrap();
+    let block = latest_block(&api).await;
+    assert_eq!(block.details.height, 1);
+    let resp = api
+        .get_block(GetBlockReq {
+            id: None,
+            round: None,
+            height: Some(0),
+            include_data: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.block.unwrap().id, genesis.id);
+
+    let resp = api
+        .get_challenges(GetChallengesReq {
+            block_id: block.id.clone(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.block_id, block.id);
+    let mut challenge_ids: Vec<String> = resp.challenges.into_iter().map(|c| c.id).collect();
+    challenge_ids.sort();
+    assert_eq!(challenge_ids, vec!["c001", "c002", "c003"]);
+
+    let resp = api
+        .get_players(GetPlayersReq {
+            block_id: block.id.clone(),
+            player_type: PlayerType::Benchmarker,
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.block_id, block.id);
+
+    let resp = api
+        .get_algorithms(GetAlgorithmsReq {
+            block_id: block.id.clone(),
+        })
+        .await
+        .unwrap();
+    assert!(resp.algorithms.is_empty());
+    assert!(resp.wasms.is_empty());
+
+    let resp = api
+        .get_benchmarks(GetBenchmarksReq {
+            block_id: block.id.clone(),
+            player_id: PLAYER_ID.to_string(),
+        })
+        .await
+        .unwrap();
+    assert!(resp.benchmarks.is_empty());
+
+    let resp = api
+        .get_benchmark_data(GetBenchmarkDataReq {
+            benchmark_id: "missing".to_string(),
+        })
+        .await
+        .unwrap();
+    assert!(resp.benchmark.is_none());
+
+    let err = api
+        .get_challenges(GetChallengesReq {
+            block_id: "missing".to_string(),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("400"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_submissions_require_api_key() {
+    let (_, addr) = setup().await;
+
+    let err = api(addr, "invalid")
+        .submit_proof(SubmitProofReq {
+            benchmark_id: "missing".to_string(),
+            solutions_data: Vec::new(),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("401"), "{}", err);
+
+    let err = api(addr, API_KEY)
+        .submit_proof(SubmitProofReq {
+            benchmark_id: "missing".to_string(),
+            solutions_data: Vec::new(),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("400"), "{}", err);
+}
+
+fn sign(key: &SecretKey, msg: &str) -> String {
+    let signature = SecretKeyRef::new(key)
+        .sign(hash_message(msg).as_bytes(), None)
+        .unwrap();
+    let mut bytes = signature.r.as_bytes().to_vec();
+    bytes.extend(signature.s.as_bytes());
+    bytes.push(signature.v as u8);
+    format!(
+        "0x{}",
+        bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>()
+    )
+}
+
+#[tokio::test]
+async fn test_request_api_key() {
+    let (_, addr) = setup().await;
+    let key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+    let address = format!("{:?}", SecretKeyRef::new(&key).address());
+    let msg = format!(
+        "I am signing this message to prove that I control address {}",
+        address
+    );
+    let req = RequestApiKeyReq {
+        signature: sign(&key, &msg),
+        address: address.clone(),
+        gnosis_safe_setup_tx_hash: None,
+    };
+
+    let api_key = api(addr, "")
+        .request_api_key(req.clone())
+        .await
+        .unwrap()
+        .api_key;
+    assert_eq!(api_key.len(), 32);
+    let resp = api(addr, "").request_api_key(req.clone()).await.unwrap();
+    assert_eq!(resp.api_key, api_key);
+
+    // the issued key is accepted for submissions
+    let err = api(addr, &api_key)
+        .submit_proof(SubmitProofReq {
+            benchmark_id: "missing".to_string(),
+            solutions_data: Vec::new(),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("400"), "{}", err);
+
+    let other_key = SecretKey::from_slice(&[2u8; 32]).unwrap();
+    for req in [
+        RequestApiKeyReq {
+            signature: sign(&other_key, &msg),
+            ..req.clone()
+        },
+        RequestApiKeyReq {
+            signature: "0x1234".to_string(),
+            ..req.clone()
+        },
+        RequestApiKeyReq {
+            address: address.to_uppercase(),
+            ..req.clone()
+        },
+        RequestApiKeyReq {
+            gnosis_safe_setup_tx_hash: Some("0x1234".to_string()),
+            ..req.clone()
+        },
+    ] {
+        let err = api(addr, "").request_api_key(req).await.unwrap_err();
+        assert!(err.to_string().contains("400"), "{}", err);
+    }
+}
+
+#[tokio::test]
+async fn test_protocol_survives_panicking_job() {
+    let (protocol, addr) = setup().await;
+
+    let result = protocol
+        .call(|_| Box::pin(async move { panic!("job panicked") }))
+        .await;
+    assert!(result.is_err());
+
+    let block = latest_block(&api(addr, API_KEY)).await;
+    assert_eq!(block.details.height, 0);
+}
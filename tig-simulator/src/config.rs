@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tig_structs::config::ProtocolConfig;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SimulationConfig {
+    pub protocol: ProtocolConfig,
+    // challenge names in order of id, i.e. the first challenge is c001
+    pub challenges: Vec<String>,
+    #[serde(default)]
+    pub algorithms: Vec<AlgorithmConfig>,
+    pub players: Vec<PlayerConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AlgorithmConfig {
+    pub name: String,
+    pub challenge: String,
+    // name of the player that submits the algorithm
+    pub innovator: String,
+    // the algorithm is submitted once the chain reaches this height
+    #[serde(default)]
+    pub submit_height: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlayerConfig {
+    pub name: String,
+    #[serde(default)]
+    pub deposit: f64,
+    // keyed by challenge name
+    #[serde(default)]
+    pub benchmarks: BTreeMap<String, BenchmarkerConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BenchmarkerConfig {
+    pub algorithm: String,
+    // expected number of solutions found per block, before filtering by solution signature
+    pub solutions_per_block: f64,
+}
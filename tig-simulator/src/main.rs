@@ -0,0 +1,81 @@
+mod config;
+mod output;
+mod simulator;
+use anyhow::{anyhow, Result};
+use clap::{arg, Command};
+use config::SimulationConfig;
+use output::{OutputFormat, RecordWriter};
+use simulator::Simulator;
+use std::{fs, io, path::PathBuf};
+use tig_utils::dejsonify;
+
+fn cli() -> Command {
+    Command::new("tig-simulator")
+        .about("Simulates the protocol over many blocks with synthetic players")
+        .arg_required_else_help(true)
+        .arg(
+            arg!(<CONFIG> "Path to simulation config json file")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--blocks [BLOCKS] "Number of blocks to simulate")
+                .default_value("1000")
+                .value_parser(clap::value_parser!(u32)),
+        )
+        .arg(
+            arg!(--seed [SEED] "Seed for the synthetic players")
+                .default_value("0")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(
+            arg!(--format [FORMAT] "Output format, 'csv' or 'json'")
+                .default_value("csv")
+                .value_parser(clap::value_parser!(OutputFormat)),
+        )
+        .arg(
+            arg!(--output [OUTPUT] "Path to output file. Defaults to stdout")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let matches = cli().get_matches();
+
+    if let Err(e) = run(
+        matches.get_one::<PathBuf>("CONFIG").unwrap().clone(),
+        *matches.get_one::<u32>("blocks").unwrap(),
+        *matches.get_one::<u64>("seed").unwrap(),
+        *matches.get_one::<OutputFormat>("format").unwrap(),
+        matches.get_one::<PathBuf>("output").cloned(),
+    )
+    .await
+    {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run(
+    config_path: PathBuf,
+    num_blocks: u32,
+    seed: u64,
+    format: OutputFormat,
+    output_path: Option<PathBuf>,
+) -> Result<()> {
+    let config = fs::read_to_string(&config_path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", config_path.display(), e))?;
+    let config = dejsonify::<SimulationConfig>(&config)
+        .map_err(|e| anyhow!("Failed to parse simulation config: {}", e))?;
+    let writer: Box<dyn io::Write> = match output_path {
+        Some(path) => Box::new(io::BufWriter::new(fs::File::create(path)?)),
+        None => Box::new(io::BufWriter::new(io::stdout())),
+    };
+    let mut writer = RecordWriter::new(writer, format)?;
+
+    let mut simulator = Simulator::new(config, seed).await?;
+    for _ in 0..num_blocks {
+        writer.write(&simulator.step().await?)?;
+    }
+    writer.flush()
+}
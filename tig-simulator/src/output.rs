@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::{collections::BTreeMap, io::Write, str::FromStr};
+use tig_structs::core::*;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BlockRecord {
+    pub height: u32,
+    pub round: u32,
+    // keyed by id
+    pub challenges: BTreeMap<String, ChallengeBlockData>,
+    pub players: BTreeMap<String, PlayerBlockData>,
+    pub algorithms: BTreeMap<String, AlgorithmBlockData>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    // one row per block, entity & metric
+    Csv,
+    // one json object per block per line
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "Unknown output format '{}'. Expecting 'csv' or 'json'",
+                s
+            )),
+        }
+    }
+}
+
+pub struct RecordWriter<W: Write> {
+    writer: W,
+    format: OutputFormat,
+}
+
+impl<W: Write> RecordWriter<W> {
+    pub fn new(mut writer: W, format: OutputFormat) -> Result<Self> {
+        if format == OutputFormat::Csv {
+            writeln!(writer, "height,round,entity,id,metric,value")?;
+        }
+        Ok(Self { writer, format })
+    }
+
+    pub fn write(&mut self, record: &BlockRecord) -> Result<()> {
+        let mut record = serde_json::to_value(record)?;
+        for entity in ["challenges", "players", "algorithms"] {
+            normalise(&mut record[entity]);
+        }
+        match self.format {
+            OutputFormat::Json => writeln!(self.writer, "{}", record)?,
+            OutputFormat::Csv => {
+                for entity in ["challenge", "player", "algorithm"] {
+                    let entities = record[format!("{}s", entity)]
+                        .as_object()
+                        .ok_or_else(|| anyhow!("Expecting {}s to be an object", entity))?;
+                    for (id, block_data) in entities.iter() {
+                        let mut metrics = Vec::new();
+                        flatten("", block_data, &mut metrics);
+                        for (metric, value) in metrics {
+                            writeln!(
+                                self.writer,
+                                "{},{},{},{},{},{}",
+                                record["height"],
+                                record["round"],
+                                entity,
+                                escape(id),
+                                escape(&metric),
+                                escape(&value)
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+// block data contains hash maps & sets (e.g. frontiers), which are sorted to make the output
+// reproducible
+fn normalise(value: &mut Value) {
+    match value {
+        Value::Object(obj) => {
+            obj.sort_keys();
+            obj.values_mut().for_each(normalise);
+        }
+        Value::Array(arr) if arr.iter().all(|v| v.is_array()) => {
+            arr.sort_by_key(|v| v.to_string());
+        }
+        _ => {}
+    }
+}
+
+// nested objects become dotted metric names, e.g. num_qualifiers_by_challenge.c001. Frontiers are
+// kept as json
+fn flatten(prefix: &str, value: &Value, metrics: &mut Vec<(String, String)>) {
+    match value {
+        Value::Null => {}
+        Value::Object(obj) => {
+            for (key, value) in obj.iter() {
+                let name = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&name, value, metrics);
+            }
+        }
+        Value::String(s) => metrics.push((prefix.to_string(), s.clone())),
+        _ => metrics.push((prefix.to_string(), value.to_string())),
+    }
+}
+
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{
+    config::{AlgorithmConfig, SimulationConfig},
+    output::BlockRecord,
+};
+use anyhow::{anyhow, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rand_distr::{Distribution, Poisson};
+use std::collections::{BTreeMap, HashMap};
+use tig_protocol::{context::*, in_memory::InMemoryContext, Protocol};
+use tig_structs::{
+    config::{MinMaxDifficulty, Point},
+    core::*,
+};
+
+// Drives add_block with synthetic players. Benchmarks & proofs are added straight to the mempool,
+// as add_block does not re-verify solutions
+pub struct Simulator {
+    protocol: Protocol<InMemoryContext>,
+    config: SimulationConfig,
+    rng: StdRng,
+    // keyed by challenge name
+    challenge_ids: HashMap<String, String>,
+    // keyed by (challenge name, algorithm name)
+    algorithm_ids: HashMap<(String, String), String>,
+    // benchmarks submitted in the previous block, which are proven once sampled
+    unproven_benchmark_ids: Vec<String>,
+}
+
+impl Simulator {
+    pub async fn new(config: SimulationConfig, seed: u64) -> Result<Self> {
+        for player in config.players.iter() {
+            for (challenge, benchmarker) in player.benchmarks.iter() {
+                if !config.challenges.contains(challenge) {
+                    return Err(anyhow!(
+                        "Player '{}' benchmarks non-existent challenge '{}'",
+                        player.name,
+                        challenge
+                    ));
+                }
+                if !config
+                    .algorithms
+                    .iter()
+                    .any(|a| a.challenge == *challenge && a.name == benchmarker.algorithm)
+                {
+                    return Err(anyhow!(
+                        "Player '{}' uses non-existent algorithm '{}' for challenge '{}'",
+                        player.name,
+                        benchmarker.algorithm,
+                        challenge
+                    ));
+                }
+            }
+        }
+
+        // add_block panics if every active challenge is in its cutoff phase in period, which is
+        // the case until an algorithm has been pushed for a round
+        if !config.algorithms.iter().any(|a| a.submit_height == 0) {
+            return Err(anyhow!(
+                "At least one algorithm must have submit_height 0 to be pushed at genesis"
+            ));
+        }
+
+        let ctx = InMemoryContext::new(config.protocol.clone());
+        for player in config.players.iter() {
+            ctx.add_player(
+                player.name.clone(),
+                PlayerDetails {
+                    name: player.name.clone(),
+                    is_multisig: false,
+                },
+            );
+            ctx.eth
+                .set_player_deposit(player.name.clone(), PreciseNumber::from_f64(player.deposit));
+        }
+        let mut challenge_ids = HashMap::new();
+        for name in config.challenges.iter() {
+            let challenge_id = ctx
+                .add_challenge_to_mempool(ChallengeDetails { name: name.clone() })
+                .await?;
+            if !config
+                .protocol
+                .difficulty
+                .parameters
+                .contains_key(&challenge_id)
+            {
+                return Err(anyhow!(
+                    "Protocol config is missing difficulty parameters for challenge '{}' ({})",
+                    name,
+                    challenge_id
+                ));
+            }
+            challenge_ids.insert(name.clone(), challenge_id);
+        }
+        Ok(Self {
+            protocol: Protocol::new(ctx),
+            config,
+            rng: StdRng::seed_from_u64(seed),
+            challenge_ids,
+            algorithm_ids: HashMap::new(),
+            unproven_benchmark_ids: Vec::new(),
+        })
+    }
+
+    // Adds the next block, returning the block data of every active challenge, player & algorithm
+    pub async fn step(&mut self) -> Result<BlockRecord> {
+        let ctx = &self.protocol.ctx;
+        let latest_block = ctx
+            .get_block(BlockFilter::Latest, false)
+            .await?
+            .ok_or_else(|| anyhow!("No latest block"))?;
+        let height = latest_block.details.height + 1;
+        ctx.eth.set_latest_eth_block_num(height as u64);
+
+        let algorithms: Vec<AlgorithmConfig> = self
+            .config
+            .algorithms
+            .iter()
+            .filter(|a| a.submit_height == latest_block.details.height)
+            .cloned()
+            .collect();
+        for algorithm in algorithms {
+            self.submit_algorithm(algorithm).await?;
+        }
+
+        let block_id = self.protocol.add_block().await?;
+        let ctx = &self.protocol.ctx;
+        if height == 1 {
+            for challenge_id in self.challenge_ids.values() {
+                ctx.update_challenge_state(
+                    challenge_id,
+                    ChallengeState {
+                        block_confirmed: Some(1),
+                        round_active: Some(1),
+                    },
+                )
+                .await?;
+            }
+            // algorithms submitted before the first block are treated as pushed at genesis
+            for algorithm_id in self.algorithm_ids.values() {
+                let mut state = ctx
+                    .get_algorithms(AlgorithmsFilter::Id(algorithm_id.clone()), None, false)
+                    .await?
+                    .pop()
+                    .and_then(|a| a.state)
+                    .ok_or_else(|| anyhow!("Algorithm {} not confirmed", algorithm_id))?;
+                state.round_pushed = Some(0);
+                ctx.update_algorithm_state(algorithm_id, state).await?;
+            }
+        }
+        let block = ctx
+            .get_block(BlockFilter::Id(block_id), true)
+            .await?
+            .ok_or_else(|| anyhow!("Block {} not found", height))?;
+
+        self.submit_proofs().await?;
+        self.submit_benchmarks(&block).await?;
+        self.record(&block).await
+    }
+
+    async fn submit_algorithm(&mut self, algorithm: AlgorithmConfig) -> Result<()> {
+        let ctx = &self.protocol.ctx;
+        let challenge_id = self
+            .challenge_ids
+            .get(&algorithm.challenge)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Algorithm '{}' is for non-existent challenge '{}'",
+                    algorithm.name,
+                    algorithm.challenge
+                )
+            })?;
+        if ctx
+            .get_players(PlayersFilter::Id(algorithm.innovator.clone()), None)
+            .await?
+            .is_empty()
+        {
+            ctx.add_player(
+                algorithm.innovator.clone(),
+                PlayerDetails {
+                    name: algorithm.innovator.clone(),
+                    is_multisig: false,
+                },
+            );
+        }
+        let algorithm_id = ctx
+            .add_algorithm_to_mempool(
+                AlgorithmDetails {
+                    name: algorithm.name.clone(),
+                    player_id: algorithm.innovator.clone(),
+                    challenge_id: challenge_id.clone(),
+                    tx_hash: format!("{}_{}", algorithm.challenge, algorithm.name),
+                },
+                String::new(),
+            )
+            .await?;
+        ctx.add_wasm_to_mempool(
+            &algorithm_id,
+            WasmDetails {
+                compile_success: true,
+                download_url: None,
+                checksum: None,
+            },
+            None,
+        )
+        .await?;
+        self.algorithm_ids
+            .insert((algorithm.challenge, algorithm.name), algorithm_id);
+        Ok(())
+    }
+
+    async fn submit_proofs(&mut self) -> Result<()> {
+        let ctx = &self.protocol.ctx;
+        for benchmark_id in self.unproven_benchmark_ids.drain(..) {
+            let benchmark = ctx
+                .get_benchmarks(BenchmarksFilter::Id(benchmark_id.clone()), false)
+                .await?
+                .pop()
+                .ok_or_else(|| anyhow!("Benchmark {} not found", benchmark_id))?;
+            let solutions_data = benchmark
+                .state()
+                .sampled_nonces()
+                .iter()
+                .map(|&nonce| SolutionData {
+                    nonce,
+                    runtime_signature: 0,
+                    fuel_consumed: 0,
+                    solution: Solution::new(),
+                })
+                .collect();
+            ctx.add_proof_to_mempool(&benchmark_id, solutions_data)
+                .await?;
+        }
+        Ok(())
+    }
+
+    // Each player submits one benchmark per challenge, at a difficulty on the scaled frontier.
+    // Only the solutions under the solution signature threshold are submitted
+    async fn submit_benchmarks(&mut self, block: &Block) -> Result<()> {
+        let ctx = &self.protocol.ctx;
+        let block_data = block.data();
+        let config = block.config();
+        for player in self.config.players.iter() {
+            for (challenge, benchmarker) in player.benchmarks.iter() {
+                let challenge_id = &self.challenge_ids[challenge];
+                let algorithm_id = match self
+                    .algorithm_ids
+                    .get(&(challenge.clone(), benchmarker.algorithm.clone()))
+                {
+                    Some(algorithm_id) => algorithm_id,
+                    None => continue, // not submitted yet
+                };
+                if !block_data.active_challenge_ids.contains(challenge_id)
+                    || !block_data.active_algorithm_ids.contains(algorithm_id)
+                {
+                    continue;
+                }
+                let challenge_data = ctx
+                    .get_challenges(
+                        ChallengesFilter::Id(challenge_id.clone()),
+                        Some(BlockFilter::Id(block.id.clone())),
+                    )
+                    .await?
+                    .pop()
+                    .and_then(|c| c.block_data);
+                let threshold = challenge_data
+                    .as_ref()
+                    .map_or(u32::MAX, |d| *d.solution_signature_threshold());
+
+                let expected_solutions =
+                    benchmarker.solutions_per_block * threshold as f64 / u32::MAX as f64;
+                let num_solutions = match Poisson::new(expected_solutions) {
+                    Ok(dist) => dist.sample(&mut self.rng) as u32,
+                    Err(_) => 0,
+                };
+                if num_solutions == 0
+                    || num_solutions < config.benchmark_submissions.min_num_solutions
+                {
+                    continue;
+                }
+
+                let difficulty_parameters = &config.difficulty.parameters[challenge_id];
+                let min_difficulty = difficulty_parameters.min_difficulty();
+                let max_difficulty = difficulty_parameters.max_difficulty();
+                // sorted so that runs are reproducible for a given seed
+                let mut frontier: Vec<&Point> = challenge_data
+                    .as_ref()
+                    .and_then(|d| d.scaled_frontier.as_ref())
+                    .map_or(Vec::new(), |f| f.iter().collect());
+                frontier.sort();
+                let difficulty =
+                    frontier
+                        .choose(&mut self.rng)
+                        .map_or(min_difficulty.clone(), |d| {
+                            (0..d.len())
+                                .map(|i| d[i].clamp(min_difficulty[i], max_difficulty[i]))
+                                .collect()
+                        });
+
+                let benchmark_id = ctx
+                    .add_benchmark_to_mempool(
+                        BenchmarkSettings {
+                            player_id: player.name.clone(),
+                            block_id: block.id.clone(),
+                            challenge_id: challenge_id.clone(),
+                            algorithm_id: algorithm_id.clone(),
+                            difficulty,
+                        },
+                        BenchmarkDetails {
+                            block_started: block.details.height,
+                            num_solutions,
+                        },
+                        (0..num_solutions as u64)
+                            .map(|nonce| SolutionMetaData {
+                                nonce,
+                                solution_signature: self.rng.gen_range(0..=threshold),
+                            })
+                            .collect(),
+                        SolutionData {
+                            nonce: 0,
+                            runtime_signature: 0,
+                            fuel_consumed: 0,
+                            solution: Solution::new(),
+                        },
+                    )
+                    .await?;
+                self.unproven_benchmark_ids.push(benchmark_id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn record(&self, block: &Block) -> Result<BlockRecord> {
+        let ctx = &self.protocol.ctx;
+        let block_filter = Some(BlockFilter::Id(block.id.clone()));
+        let challenges: BTreeMap<String, ChallengeBlockData> = ctx
+            .get_challenges(ChallengesFilter::Confirmed, block_filter.clone())
+            .await?
+            .into_iter()
+            .filter_map(|c| c.block_data.map(|d| (c.id, d)))
+            .collect();
+        let players: BTreeMap<String, PlayerBlockData> = ctx
+            .get_players(PlayersFilter::Benchmarkers, block_filter.clone())
+            .await?
+            .into_iter()
+            .filter_map(|p| p.block_data.map(|d| (p.id, d)))
+            .collect();
+        let algorithms: BTreeMap<String, AlgorithmBlockData> = ctx
+            .get_algorithms(AlgorithmsFilter::Confirmed, block_filter, false)
+            .await?
+            .into_iter()
+            .filter_map(|a| a.block_data.map(|d| (a.id, d)))
+            .collect();
+        Ok(BlockRecord {
+            height: block.details.height,
+            round: block.details.round,
+            challenges,
+            players,
+            algorithms,
+        })
+    }
+}
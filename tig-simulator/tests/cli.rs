@@ -0,0 +1,73 @@
+use serde_json::Value;
+use std::process::Command;
+
+const CONFIG_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/example_config.json");
+
+fn simulate(config_path: &str, args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_tig-simulator"))
+        .arg(config_path)
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn test_csv_output() {
+    let (success, stdout, stderr) = simulate(CONFIG_PATH, &["--blocks", "10"]);
+    assert!(success, "{}", stderr);
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("height,round,entity,id,metric,value"));
+    let rows: Vec<Vec<&str>> = lines.map(|l| l.splitn(6, ',').collect()).collect();
+    assert!(rows.iter().all(|r| r.len() == 6));
+    assert_eq!(rows.last().unwrap()[0], "10");
+    for (entity, metric) in [
+        ("player", "influence"),
+        ("player", "reward"),
+        ("algorithm", "adoption"),
+        ("challenge", "num_qualifiers"),
+    ] {
+        assert!(
+            rows.iter()
+                .any(|r| r[0] == "10" && r[2] == entity && r[4] == metric),
+            "missing {} {}",
+            entity,
+            metric
+        );
+    }
+}
+
+#[test]
+fn test_json_output_is_deterministic() {
+    let args = ["--blocks", "10", "--format", "json", "--seed", "42"];
+    let (success, stdout, stderr) = simulate(CONFIG_PATH, &args);
+    assert!(success, "{}", stderr);
+    let records: Vec<Value> = stdout
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(records.len(), 10);
+    assert_eq!(records[9]["height"], 10);
+    assert_eq!(records[9]["players"].as_object().unwrap().len(), 3);
+    assert_eq!(simulate(CONFIG_PATH, &args).1, stdout);
+}
+
+#[test]
+fn test_invalid_config() {
+    let mut config: Value =
+        serde_json::from_str(&std::fs::read_to_string(CONFIG_PATH).unwrap()).unwrap();
+    config["players"][0]["benchmarks"]["knapsack"]["algorithm"] = "unknown".into();
+    let path = std::env::temp_dir().join("tig-simulator-invalid_config.json");
+    std::fs::write(&path, config.to_string()).unwrap();
+    let (success, _, stderr) = simulate(path.to_str().unwrap(), &["--blocks", "1"]);
+    assert!(!success);
+    assert!(
+        stderr.contains("non-existent algorithm 'unknown'"),
+        "{}",
+        stderr
+    );
+}
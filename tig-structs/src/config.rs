@@ -16,6 +16,8 @@ serializable_struct_with_getters! {
         rounds: RoundsConfig,
         algorithm_submissions: AlgorithmSubmissionsConfig,
         rewards: RewardsConfig,
+        slashing: Option<SlashingConfig>,
+        breakthroughs: Option<BreakthroughsConfig>,
     }
 }
 serializable_struct_with_getters! {
@@ -100,6 +102,8 @@ serializable_struct_with_getters! {
         adoption_threshold: f64,
         merge_points_threshold: u32,
         push_delay: u32,
+        // address allowed to ban algorithms. A multisig has to sign as itself
+        admin_address: Option<String>,
     }
 }
 serializable_struct_with_getters! {
@@ -115,6 +119,31 @@ serializable_struct_with_getters! {
         breakthroughs: f64,
     }
 }
+serializable_struct_with_getters! {
+    SlashingConfig {
+        // number of blocks before a fraud is confirmed whose rewards to the player are slashed
+        window: u32,
+        // fraction of the player's rewards within the window that is slashed. Between 0 and 1
+        fraction: f64,
+        // number of blocks after a fraud is confirmed that the player's benchmarks cannot qualify
+        ban_period: Option<u32>,
+    }
+}
+serializable_struct_with_getters! {
+    BreakthroughsConfig {
+        // number of rounds that voting on a breakthrough stays open, starting from the round its algorithm is merged
+        vote_period: u32,
+        // fraction of the vote weight that must approve a breakthrough. Between 0 and 1
+        approval_threshold: f64,
+        vote_weight: VoteWeight,
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteWeight {
+    Deposit,
+    Influence,
+}
 serializable_struct_with_getters! {
     EmissionsConfig {
         block_reward: f64,
@@ -24,6 +24,14 @@ serializable_struct_with_getters! {
         solution_data: Option<SolutionData>,
     }
 }
+serializable_struct_with_getters! {
+    Breakthrough {
+        id: String,
+        details: BreakthroughDetails,
+        state: Option<BreakthroughState>,
+        block_data: Option<BreakthroughBlockData>,
+    }
+}
 serializable_struct_with_getters! {
     Block {
         id: String,
@@ -61,6 +69,13 @@ serializable_struct_with_getters! {
         allegation: Option<String>,
     }
 }
+serializable_struct_with_getters! {
+    Vote {
+        breakthrough_id: String,
+        player_id: String,
+        approve: bool,
+    }
+}
 serializable_struct_with_getters! {
     Wasm {
         algorithm_id: String,
@@ -86,6 +101,8 @@ serializable_struct_with_getters! {
         round_pushed: Option<u32>,
         round_merged: Option<u32>,
         banned: bool,
+        block_banned: Option<u32>,
+        ban_reason: Option<String>,
     }
 }
 serializable_struct_with_getters! {
@@ -144,6 +161,32 @@ impl From<SolutionData> for SolutionMetaData {
     }
 }
 
+// Breakthrough child structs
+serializable_struct_with_getters! {
+    BreakthroughDetails {
+        name: String,
+        player_id: String,
+        challenge_id: String,
+        algorithm_id: String,
+    }
+}
+serializable_struct_with_getters! {
+    BreakthroughState {
+        block_confirmed: Option<u32>,
+        round_submitted: Option<u32>,
+        round_voting_starts: Option<u32>,
+        round_votes_tallied: Option<u32>,
+        votes_for: Option<PreciseNumber>,
+        votes_against: Option<PreciseNumber>,
+        round_active: Option<u32>,
+    }
+}
+serializable_struct_with_getters! {
+    BreakthroughBlockData {
+        reward: Option<PreciseNumber>,
+    }
+}
+
 // Block child structs
 serializable_struct_with_getters! {
     BlockDetails {
@@ -210,6 +253,8 @@ serializable_struct_with_getters! {
         influence: Option<PreciseNumber>,
         reward: Option<PreciseNumber>,
         round_earnings: Option<PreciseNumber>,
+        slashed_benchmark_ids: Option<HashSet<String>>,
+        slashed_reward: Option<PreciseNumber>,
     }
 }
 
@@ -0,0 +1,182 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use tig_utils::{Frontier, FrontierOps, ParetoCompare, Point, PointOps};
+
+// The previous quadratic implementation, kept as a baseline
+fn naive_pareto_frontier(points: &Frontier) -> Frontier {
+    let mut frontier = points.clone();
+    for point in points.iter() {
+        if !frontier.contains(point) {
+            continue;
+        }
+        let mut dominated_points = HashSet::new();
+        for other_point in frontier.iter() {
+            match point.pareto_compare(other_point) {
+                ParetoCompare::ADominatesB => {
+                    dominated_points.insert(other_point.clone());
+                }
+                ParetoCompare::BDominatesA => {
+                    dominated_points.insert(point.clone());
+                    break;
+                }
+                ParetoCompare::Equal => {}
+            }
+        }
+        frontier = frontier.difference(&dominated_points).cloned().collect();
+    }
+    frontier
+}
+
+// The previous pareto_algorithm of tig-protocol, verbatim, kept as a baseline. It only supports
+// 2 dimensions, peeling one frontier at a time from the hardest point of each bucket
+fn find_smallest_range_dimension(points: &Frontier) -> usize {
+    (0..2)
+        .min_by_key(|&d| {
+            let (min, max) = points
+                .iter()
+                .map(|p| p[d])
+                .fold((i32::MAX, i32::MIN), |(min, max), val| {
+                    (min.min(val), max.max(val))
+                });
+            max - min
+        })
+        .unwrap()
+}
+
+fn pareto_algorithm(points: Frontier, only_one: bool) -> Vec<Frontier> {
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let dimension = find_smallest_range_dimension(&points);
+    let sort_dimension = 1 - dimension;
+
+    let mut buckets: HashMap<i32, Vec<Point>> = HashMap::new();
+    for point in points {
+        buckets.entry(point[dimension]).or_default().push(point);
+    }
+    for (_, group) in buckets.iter_mut() {
+        // sort descending
+        group.sort_unstable_by(|a, b| b[sort_dimension].cmp(&a[sort_dimension]));
+    }
+    let mut result = Vec::new();
+    while !buckets.is_empty() {
+        let points: HashSet<Point> = buckets.values().map(|group| group[0].clone()).collect();
+        let frontier = points.pareto_frontier();
+        for point in frontier.iter() {
+            let bucket = buckets.get_mut(&point[dimension]).unwrap();
+            bucket.remove(0);
+            if bucket.is_empty() {
+                buckets.remove(&point[dimension]);
+            }
+        }
+        result.push(frontier);
+        if only_one {
+            break;
+        }
+    }
+    result
+}
+
+// Difficulties of benchmarks, which cluster along an anti-diagonal like real frontiers
+fn random_points(num_points: usize, num_dimensions: usize, seed: u64) -> Frontier {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut points = Frontier::new();
+    while points.len() < num_points {
+        let mut point: Vec<i32> = (0..num_dimensions)
+            .map(|_| rng.gen_range(0..1000))
+            .collect();
+        let total: i32 = point.iter().sum();
+        let target = 500 * num_dimensions as i32 + rng.gen_range(-200..200);
+        point
+            .iter_mut()
+            .for_each(|x| *x = *x * target / total.max(1));
+        points.insert(point);
+    }
+    points
+}
+
+// Points whose values have the same sum, so every point is on the frontier
+fn frontier_points(num_points: usize, num_dimensions: usize, seed: u64) -> Frontier {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let total = 100_000;
+    let mut points = Frontier::new();
+    while points.len() < num_points {
+        let mut point: Vec<i32> = (1..num_dimensions)
+            .map(|_| rng.gen_range(0..=total))
+            .collect();
+        let last = total - point.iter().sum::<i32>();
+        if last >= 0 {
+            point.push(last);
+            points.insert(point);
+        }
+    }
+    points
+}
+
+fn bench_pareto_frontier(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pareto_frontier");
+    group.sample_size(10);
+    for num_dimensions in [2, 3, 4] {
+        for num_points in [1_000, 5_000] {
+            for (name, points) in [
+                ("random", random_points(num_points, num_dimensions, 0)),
+                (
+                    "all_frontier",
+                    frontier_points(num_points, num_dimensions, 0),
+                ),
+            ] {
+                let parameter = format!("{}d/{}/{}", num_dimensions, num_points, name);
+                // the baseline is quadratic in the size of the frontier
+                if name == "random" || num_points <= 1_000 {
+                    assert_eq!(points.pareto_frontier(), naive_pareto_frontier(&points));
+                    group.bench_with_input(
+                        BenchmarkId::new("naive", &parameter),
+                        &points,
+                        |b, p| b.iter(|| naive_pareto_frontier(p)),
+                    );
+                }
+                group.bench_with_input(BenchmarkId::new("sorted", &parameter), &points, |b, p| {
+                    b.iter(|| p.pareto_frontier())
+                });
+            }
+        }
+    }
+    group.finish();
+}
+
+fn bench_pareto_frontiers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pareto_frontiers");
+    group.sample_size(10);
+    for num_dimensions in [2, 3, 4] {
+        for num_points in [1_000, 5_000] {
+            for (name, points) in [
+                ("random", random_points(num_points, num_dimensions, 0)),
+                (
+                    "all_frontier",
+                    frontier_points(num_points, num_dimensions, 0),
+                ),
+            ] {
+                let parameter = format!("{}d/{}/{}", num_dimensions, num_points, name);
+                if num_dimensions == 2 {
+                    assert_eq!(
+                        points.pareto_frontiers(),
+                        pareto_algorithm(points.clone(), false)
+                    );
+                    group.bench_with_input(
+                        BenchmarkId::new("buckets", &parameter),
+                        &points,
+                        |b, p| b.iter(|| pareto_algorithm(p.clone(), false)),
+                    );
+                }
+                group.bench_with_input(BenchmarkId::new("sorted", &parameter), &points, |b, p| {
+                    b.iter(|| p.pareto_frontiers())
+                });
+            }
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_pareto_frontier, bench_pareto_frontiers);
+criterion_main!(benches);
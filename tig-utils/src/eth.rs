@@ -24,7 +24,9 @@ mod web3_feature {
             hex::decode(sig.trim_start_matches("0x"))
                 .map_err(|e| web3::Error::InvalidResponse(e.to_string()))?,
         )?;
-        let (signature, recovery_id) = recovery.as_signature().unwrap();
+        let (signature, recovery_id) = recovery
+            .as_signature()
+            .ok_or_else(|| anyhow!("Invalid signature recovery id"))?;
         let address = recover(hash_msg.as_bytes(), &signature, recovery_id)?;
         Ok(format!("0x{}", address.encode_hex::<String>()))
     }
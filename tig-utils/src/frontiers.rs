@@ -1,6 +1,7 @@
 use rand::Rng;
-use std::cmp::min;
-use std::collections::HashSet;
+use std::cmp::{min, Ordering, Reverse};
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Range;
 
 pub type Point = Vec<i32>;
 pub type Frontier<P = Point> = HashSet<P>;
@@ -34,6 +35,7 @@ pub trait FrontierOps {
     type Point;
 
     fn pareto_frontier(&self) -> Frontier<Self::Point>;
+    fn pareto_frontiers(&self) -> Vec<Frontier<Self::Point>>;
     fn extend(&self, min_point: &Self::Point, max_point: &Self::Point) -> Frontier<Self::Point>;
     fn scale(
         &self,
@@ -110,30 +112,35 @@ impl FrontierOps for Frontier {
     type Point = Point;
 
     fn pareto_frontier(&self) -> Frontier<Self::Point> {
-        let mut frontier = self.clone();
-
-        for point in self.iter() {
-            if !frontier.contains(point) {
-                continue;
-            }
-
-            let mut dominated_points = HashSet::new();
-            for other_point in frontier.iter() {
-                match point.pareto_compare(other_point) {
-                    ParetoCompare::ADominatesB => {
-                        dominated_points.insert(other_point.clone());
-                    }
-                    ParetoCompare::BDominatesA => {
-                        dominated_points.insert(point.clone());
-                        break;
-                    }
-                    ParetoCompare::Equal => {}
-                }
+        // sorted descending, a point can only be dominated by points before it
+        let mut sorted_points: Vec<&Point> = self.iter().collect();
+        sorted_points.sort_unstable_by(|a, b| b.cmp(a));
+        let frontier = match sorted_points.first().map(|p| p.len()) {
+            Some(2) => sweep_pareto_frontier(&sorted_points),
+            _ => divide_and_conquer_pareto_frontier(&sorted_points),
+        };
+        frontier.into_iter().cloned().collect()
+    }
+    fn pareto_frontiers(&self) -> Vec<Frontier<Self::Point>> {
+        // peeled hardest first. A point is on the frontier after the last frontier of the points
+        // dominating it
+        let mut sorted_points: Vec<&Point> = self.iter().collect();
+        sorted_points.sort_unstable_by(|a, b| b.cmp(a));
+        let ranks = match sorted_points.first().map(|p| p.len()) {
+            None => return Vec::new(),
+            Some(2) => sweep_pareto_ranks(&sorted_points),
+            Some(num_dimensions) => {
+                let mut ranks = vec![0; sorted_points.len()];
+                let idxs = (0..sorted_points.len()).collect();
+                assign_ranks(&sorted_points, &mut ranks, idxs, num_dimensions - 1);
+                ranks
             }
-            frontier = frontier.difference(&dominated_points).cloned().collect();
+        };
+        let mut frontiers = vec![Frontier::new(); ranks.iter().max().unwrap() + 1];
+        for (point, rank) in sorted_points.into_iter().zip(ranks) {
+            frontiers[rank].insert(point.clone());
         }
-
-        frontier
+        frontiers
     }
     fn extend(&self, min_point: &Self::Point, max_point: &Self::Point) -> Frontier<Self::Point> {
         let mut frontier = self.clone();
@@ -173,19 +180,12 @@ impl FrontierOps for Frontier {
         }
     }
     fn sample<R: Rng>(&self, rng: &mut R) -> Self::Point {
-        // FIXME only works for 2 dimensional points
-        // Potential strategy for >2d: triangulate -> sample triangle -> sample point in triangle
-        match self.iter().next() {
+        let num_dimensions = match self.iter().next() {
             None => panic!("Frontier is empty"),
-            Some(point) => {
-                if point.len() != 2 {
-                    panic!("Only 2 dimensional points are supported");
-                }
-            }
+            Some(point) => point.len(),
         };
         // randomly pick a dimension
-        let dim = (rng.next_u32() % 2) as usize;
-        let dim2 = (dim + 1) % 2;
+        let dim = (rng.next_u32() % num_dimensions as u32) as usize;
 
         // sort points by that dimension
         let mut sorted_points: Vec<&Point> = self.iter().collect();
@@ -196,24 +196,257 @@ impl FrontierOps for Frontier {
         let max_v = sorted_points.last().unwrap()[dim];
         let rand_v = rng.gen_range(min_v..=max_v);
 
-        // interpolate value in the other dimension
-        match sorted_points.binary_search_by(|point| point[dim].cmp(&rand_v)) {
-            Ok(idx) => sorted_points[idx].clone(),
-            Err(idx) => {
-                let a = sorted_points[idx - 1];
-                let b = sorted_points[idx];
-                let ratio = (rand_v - a[dim]) as f64 / (b[dim] - a[dim]) as f64;
-                let rand_v2 = (a[dim2] as f64 + ratio * (b[dim2] - a[dim2]) as f64).ceil() as i32;
-                // a is smaller than b in dim, but larger in dim2
-                if rand_v2 == a[dim2] {
-                    a.clone()
+        // in 2 dimensions, a frontier has a single point for each value, so no tie is picked at random
+        let mut pick = |points: &[&Point]| -> Point {
+            match points.len() {
+                1 => points[0].clone(),
+                n => points[rng.gen_range(0..n)].clone(),
+            }
+        };
+        let lower = sorted_points.partition_point(|point| point[dim] < rand_v);
+        let upper = sorted_points.partition_point(|point| point[dim] <= rand_v);
+        if lower < upper {
+            return pick(&sorted_points[lower..upper]);
+        }
+
+        // interpolate values in the other dimensions between the nearest points on either side
+        let (a_v, b_v) = (sorted_points[lower - 1][dim], sorted_points[upper][dim]);
+        let a = pick(&sorted_points[sorted_points.partition_point(|p| p[dim] < a_v)..lower]);
+        let b = pick(&sorted_points[upper..sorted_points.partition_point(|p| p[dim] <= b_v)]);
+        let ratio = (rand_v - a[dim]) as f64 / (b[dim] - a[dim]) as f64;
+        let point: Point = (0..num_dimensions)
+            .map(|i| {
+                if i == dim {
+                    rand_v
                 } else {
-                    (0..2)
-                        .into_iter()
-                        .map(|i| if i == dim { rand_v } else { rand_v2 })
-                        .collect()
+                    (a[i] as f64 + ratio * (b[i] - a[i]) as f64).ceil() as i32
                 }
+            })
+            .collect();
+        // a is smaller than b in dim, but not in the other dimensions
+        if (0..num_dimensions).all(|i| i == dim || point[i] == a[i]) {
+            a
+        } else {
+            point
+        }
+    }
+}
+
+// In 2 dimensions, a point is dominated if a point before it is at least as large in the second
+// dimension. O(n) after sorting
+fn sweep_pareto_frontier<'a>(sorted_points: &[&'a Point]) -> Vec<&'a Point> {
+    let mut frontier = Vec::new();
+    let mut max_v: Option<i32> = None;
+    for &point in sorted_points {
+        if !matches!(max_v, Some(v) if v >= point[1]) {
+            max_v = Some(point[1]);
+            frontier.push(point);
+        }
+    }
+    frontier
+}
+
+// Kung et al's divide & conquer. Points of the first half are at least as large in the first
+// dimension as points of the second half, so merging only compares the remaining dimensions.
+// O(n log^(d-1) n) for d >= 3 dimensions
+fn divide_and_conquer_pareto_frontier<'a>(sorted_points: &[&'a Point]) -> Vec<&'a Point> {
+    // points of the frontier have rank 0, and dominated points a higher rank
+    let mut ranks = vec![0; sorted_points.len()];
+    divide_and_conquer_frontier_idxs(sorted_points, &mut ranks, 0..sorted_points.len())
+        .into_iter()
+        .map(|idx| sorted_points[idx])
+        .collect()
+}
+
+fn divide_and_conquer_frontier_idxs(
+    sorted_points: &[&Point],
+    ranks: &mut [usize],
+    idxs: Range<usize>,
+) -> Vec<usize> {
+    if idxs.len() <= 1 {
+        return idxs.collect();
+    }
+    let mid = idxs.start + idxs.len() / 2;
+    let mut frontier = divide_and_conquer_frontier_idxs(sorted_points, ranks, idxs.start..mid);
+    let second_frontier = divide_and_conquer_frontier_idxs(sorted_points, ranks, mid..idxs.end);
+    let num_dimensions = sorted_points[0].len();
+    update_ranks(
+        sorted_points,
+        ranks,
+        &frontier,
+        second_frontier.clone(),
+        1..num_dimensions,
+    );
+    frontier.extend(second_frontier.into_iter().filter(|&idx| ranks[idx] == 0));
+    frontier
+}
+
+// In 2 dimensions, each point is on the first frontier whose points so far are all lower in the
+// second dimension. O(n log n) after sorting
+fn sweep_pareto_ranks(sorted_points: &[&Point]) -> Vec<usize> {
+    // the highest second dimension of each frontier, which decreases from one frontier to the next
+    let mut max_vs: Vec<i32> = Vec::new();
+    sorted_points
+        .iter()
+        .map(|point| {
+            let rank = max_vs.partition_point(|&v| v >= point[1]);
+            if rank == max_vs.len() {
+                max_vs.push(point[1]);
+            } else {
+                max_vs[rank] = point[1];
+            }
+            rank
+        })
+        .collect()
+}
+
+// Jensen's divide & conquer non-dominated sort, with ties handled as by Buzdalov & Shalyto.
+// Ranks the points of `idxs`, which are equal after `dim` and in descending order. A point can
+// only be dominated by a point before it. Splits on the median of `dim`, ranking the larger part
+// first as it cannot be dominated by the smaller part. O(n log^(d-1) n)
+fn assign_ranks(points: &[&Point], ranks: &mut [usize], idxs: Vec<usize>, dim: usize) {
+    if idxs.len() <= 1 {
+        return;
+    }
+    match dim {
+        0 => {
+            for i in 1..idxs.len() {
+                ranks[idxs[i]] = ranks[idxs[i]].max(ranks[idxs[i - 1]] + 1);
+            }
+            return;
+        }
+        1 => {
+            let mut staircase = Staircase::default();
+            for idx in idxs {
+                if let Some(rank) = staircase.max_rank(points[idx][1]) {
+                    ranks[idx] = ranks[idx].max(rank + 1);
+                }
+                staircase.insert(points[idx][1], ranks[idx]);
             }
+            return;
+        }
+        _ => {}
+    }
+    let mut values: Vec<i32> = idxs.iter().map(|&i| points[i][dim]).collect();
+    values.sort_unstable();
+    let split_v = values[values.len() / 2];
+    let mut larger = Vec::new();
+    let mut equal = Vec::new();
+    let mut smaller = Vec::new();
+    for idx in idxs {
+        match points[idx][dim].cmp(&split_v) {
+            Ordering::Greater => larger.push(idx),
+            Ordering::Equal => equal.push(idx),
+            Ordering::Less => smaller.push(idx),
+        }
+    }
+    if larger.is_empty() && smaller.is_empty() {
+        return assign_ranks(points, ranks, equal, dim - 1);
+    }
+    assign_ranks(points, ranks, larger.clone(), dim);
+    update_ranks(points, ranks, &larger, equal.clone(), 0..dim);
+    assign_ranks(points, ranks, equal.clone(), dim - 1);
+    let mut larger_or_equal = larger;
+    larger_or_equal.extend(equal);
+    larger_or_equal.sort_unstable();
+    update_ranks(points, ranks, &larger_or_equal, smaller.clone(), 0..dim);
+    assign_ranks(points, ranks, smaller, dim);
+}
+
+// Raises the ranks of `lower_idxs` above those of the ranked `upper_idxs` that dominate them. Every
+// upper point is at least as large as every lower point outside of `dims`. Splits on the median of
+// the last of `dims`, as only the larger upper part can dominate the larger lower part, and the
+// larger upper part is at least as large as the smaller lower part in that dimension
+fn update_ranks(
+    points: &[&Point],
+    ranks: &mut [usize],
+    upper_idxs: &[usize],
+    lower_idxs: Vec<usize>,
+    dims: Range<usize>,
+) {
+    if upper_idxs.is_empty() || lower_idxs.is_empty() {
+        return;
+    }
+    if dims.len() <= 2 {
+        return sweep_update_ranks(points, ranks, upper_idxs, lower_idxs, dims);
+    }
+
+    let dim = dims.end - 1;
+    let mut values: Vec<i32> = upper_idxs
+        .iter()
+        .chain(lower_idxs.iter())
+        .map(|&i| points[i][dim])
+        .collect();
+    values.sort_unstable();
+    // both parts must be non-empty
+    let split_v = match values[values.len() / 2] {
+        v if v > values[0] => v,
+        _ => match values.iter().find(|&&v| v > values[0]) {
+            Some(&v) => v,
+            None => return update_ranks(points, ranks, upper_idxs, lower_idxs, dims.start..dim),
+        },
+    };
+    let (upper_hi, upper_lo): (Vec<usize>, Vec<usize>) =
+        upper_idxs.iter().partition(|&&i| points[i][dim] >= split_v);
+    let (lower_hi, lower_lo): (Vec<usize>, Vec<usize>) = lower_idxs
+        .into_iter()
+        .partition(|&i| points[i][dim] >= split_v);
+    update_ranks(points, ranks, &upper_hi, lower_hi, dims.clone());
+    update_ranks(points, ranks, &upper_hi, lower_lo.clone(), dims.start..dim);
+    update_ranks(points, ranks, &upper_lo, lower_lo, dims);
+}
+
+// With at most 2 of `dims` left, a lower point is dominated by the upper points at least as large in
+// the first that are also at least as large in the second
+fn sweep_update_ranks(
+    points: &[&Point],
+    ranks: &mut [usize],
+    upper_idxs: &[usize],
+    mut lower_idxs: Vec<usize>,
+    dims: Range<usize>,
+) {
+    // missing dimensions are equal for every point
+    let value = |idx: usize, n: usize| dims.clone().nth(n).map_or(0, |dim| points[idx][dim]);
+    let mut upper_idxs = upper_idxs.to_vec();
+    upper_idxs.sort_by_key(|&i| Reverse(value(i, 0)));
+    lower_idxs.sort_by_key(|&i| Reverse(value(i, 0)));
+    // upper points come first on ties, as they dominate lower points that are equal in `dims`
+    let mut staircase = Staircase::default();
+    let mut upper_idxs = upper_idxs.into_iter().peekable();
+    for idx in lower_idxs {
+        while let Some(upper_idx) = upper_idxs.next_if(|&i| value(i, 0) >= value(idx, 0)) {
+            staircase.insert(value(upper_idx, 1), ranks[upper_idx]);
+        }
+        if let Some(rank) = staircase.max_rank(value(idx, 1)) {
+            ranks[idx] = ranks[idx].max(rank + 1);
+        }
+    }
+}
+
+// The highest rank of the points at least as large as a value, kept as the points whose rank is
+// higher than that of every larger point
+#[derive(Default)]
+struct Staircase(BTreeMap<i32, usize>);
+
+impl Staircase {
+    fn max_rank(&self, v: i32) -> Option<usize> {
+        self.0.range(v..).next().map(|(_, &rank)| rank)
+    }
+
+    fn insert(&mut self, v: i32, rank: usize) {
+        if self.max_rank(v).is_some_and(|r| r >= rank) {
+            return;
+        }
+        let lower: Vec<i32> = self
+            .0
+            .range(..=v)
+            .rev()
+            .take_while(|(_, &r)| r <= rank)
+            .map(|(&v, _)| v)
+            .collect();
+        for v in lower {
+            self.0.remove(&v);
         }
+        self.0.insert(v, rank);
     }
 }
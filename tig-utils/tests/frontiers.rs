@@ -1,3 +1,5 @@
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
 use tig_utils::{Frontier, FrontierOps, ParetoCompare, PointCompareFrontiers, PointOps};
 
 #[test]
@@ -36,6 +38,34 @@ fn test_pareto_frontier() {
     );
 }
 
+#[test]
+fn test_pareto_frontiers() {
+    let points: Frontier = vec![
+        vec![3, 1],
+        vec![1, 0],
+        vec![0, 1],
+        vec![1, 1],
+        vec![0, 0],
+        vec![2, 2],
+        vec![2, 1],
+        vec![1, 3],
+    ]
+    .into_iter()
+    .collect();
+    let frontiers: Vec<Frontier> = vec![
+        vec![vec![2, 2], vec![3, 1], vec![1, 3]],
+        vec![vec![2, 1]],
+        vec![vec![1, 1]],
+        vec![vec![1, 0], vec![0, 1]],
+        vec![vec![0, 0]],
+    ]
+    .into_iter()
+    .map(|frontier| frontier.into_iter().collect())
+    .collect();
+    assert_eq!(points.pareto_frontiers(), frontiers);
+    assert_eq!(Frontier::new().pareto_frontiers(), Vec::<Frontier>::new());
+}
+
 #[test]
 fn test_scale_point() {
     // ceil((x - min + 1) * multiplier)
@@ -106,3 +136,85 @@ fn test_within() {
         PointCompareFrontiers::Below
     );
 }
+
+#[test]
+fn test_sample_2d() {
+    let frontier: Frontier = vec![vec![0, 10], vec![2, 7], vec![5, 3], vec![9, 0]]
+        .into_iter()
+        .collect();
+    let mut rng = StdRng::seed_from_u64(0);
+    let samples: Vec<Vec<i32>> = (0..8).map(|_| frontier.sample(&mut rng)).collect();
+    assert_eq!(
+        samples,
+        vec![
+            vec![2, 7],
+            vec![2, 7],
+            vec![0, 10],
+            vec![2, 7],
+            vec![2, 7],
+            vec![2, 7],
+            vec![8, 1],
+            vec![5, 3]
+        ]
+    );
+}
+
+// 1 to 4 dimensional points with a few duplicate values in each dimension
+fn points() -> impl Strategy<Value = Frontier> {
+    (1usize..=4).prop_flat_map(|num_dimensions| {
+        prop::collection::hash_set(prop::collection::vec(0i32..20, num_dimensions), 1..200)
+    })
+}
+
+proptest! {
+    #[test]
+    fn prop_pareto_frontier(points in points()) {
+        let frontier = points.pareto_frontier();
+        prop_assert!(!frontier.is_empty());
+        for point in points.iter() {
+            let is_dominated = points
+                .iter()
+                .any(|other| other.pareto_compare(point) == ParetoCompare::ADominatesB);
+            prop_assert_eq!(frontier.contains(point), !is_dominated);
+        }
+    }
+
+    // each frontier is the pareto frontier of the points not in an earlier frontier
+    #[test]
+    fn prop_pareto_frontiers(points in points()) {
+        let mut remaining = points.clone();
+        for frontier in points.pareto_frontiers().iter() {
+            prop_assert_eq!(frontier, &remaining.pareto_frontier());
+            remaining = remaining.difference(frontier).cloned().collect();
+        }
+        prop_assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn prop_sample_within_bounds(points in points(), seed in any::<u64>()) {
+        let frontier = points.pareto_frontier();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let sample = frontier.sample(&mut rng);
+        prop_assert_eq!(sample.len(), frontier.iter().next().unwrap().len());
+        for (i, v) in sample.iter().enumerate() {
+            prop_assert!(frontier.iter().any(|p| p[i] <= *v));
+            prop_assert!(frontier.iter().any(|p| p[i] >= *v));
+        }
+    }
+
+    #[test]
+    fn prop_extend(points in points()) {
+        let num_dimensions = points.iter().next().unwrap().len();
+        let (min_point, max_point) = (vec![0; num_dimensions], vec![20; num_dimensions]);
+        let frontier = points.pareto_frontier();
+        let extended = frontier.extend(&min_point, &max_point);
+        prop_assert!(extended.is_superset(&frontier));
+        prop_assert!(extended.len() <= frontier.len() + num_dimensions);
+        for point in extended.iter() {
+            prop_assert!(point
+                .iter()
+                .zip(min_point.iter().zip(max_point.iter()))
+                .all(|(v, (min, max))| min <= v && v <= max));
+        }
+    }
+}
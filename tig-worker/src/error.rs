@@ -0,0 +1,61 @@
+use wasmi::{
+    core::TrapCode,
+    errors::{ErrorKind, MemoryError},
+};
+
+#[derive(Debug, PartialEq)]
+pub enum WorkerError {
+    InvalidModule { reason: String },
+    InvalidSettings { reason: String },
+    MalformedSolution { reason: String },
+    MemoryLimitExceeded,
+    MissingExport { name: String },
+    OutOfFuel,
+    SolutionDecompressionFailed { reason: String },
+    Trap { message: String },
+}
+
+impl WorkerError {
+    /// Classifies an error raised while the guest is running (including its start function)
+    pub(crate) fn from_execution(e: wasmi::Error) -> Self {
+        match (e.as_trap_code(), e.kind()) {
+            (Some(TrapCode::OutOfFuel), _) => WorkerError::OutOfFuel,
+            (Some(TrapCode::GrowthOperationLimited), _)
+            | (_, ErrorKind::Memory(MemoryError::OutOfBoundsGrowth)) => {
+                WorkerError::MemoryLimitExceeded
+            }
+            _ => WorkerError::Trap {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkerError::InvalidModule { reason } => {
+                write!(f, "Invalid wasm module: {}", reason)
+            }
+            WorkerError::InvalidSettings { reason } => {
+                write!(f, "Invalid benchmark settings: {}", reason)
+            }
+            WorkerError::MalformedSolution { reason } => {
+                write!(f, "Malformed solution encoding: {}", reason)
+            }
+            WorkerError::MemoryLimitExceeded => write!(f, "Exceeded max memory"),
+            WorkerError::MissingExport { name } => {
+                write!(f, "Wasm module does not export '{}'", name)
+            }
+            WorkerError::OutOfFuel => write!(f, "Ran out of fuel"),
+            WorkerError::SolutionDecompressionFailed { reason } => {
+                write!(f, "Failed to decompress solution: {}", reason)
+            }
+            WorkerError::Trap { message } => write!(f, "Wasm trapped: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for WorkerError {}
+
+pub type WorkerResult<T> = std::result::Result<T, WorkerError>;
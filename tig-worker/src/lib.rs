@@ -1,2 +1,5 @@
+mod error;
+pub mod serve;
 mod worker;
+pub use error::*;
 pub use worker::*;
@@ -1,7 +1,22 @@
+mod error;
+mod serve;
 mod worker;
 use clap::{arg, Command};
-use std::{fs, path::PathBuf};
-use tig_structs::core::BenchmarkSettings;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+use tig_challenges::registry;
+use tig_structs::{api::GetBenchmarkDataResp, core::BenchmarkSettings};
 use tig_utils::{dejsonify, jsonify};
 
 fn cli() -> Command {
@@ -28,6 +43,74 @@ fn cli() -> Command {
                         .value_parser(clap::value_parser!(u64)),
                 ),
         )
+        .subcommand(
+            Command::new("compute_batch")
+                .about("Computes solutions for a batch of nonces in parallel")
+                .arg(
+                    arg!(<SETTINGS> "Settings json string or path to json file")
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(
+                    arg!(<NONCES> "Nonce range (e.g. 0..100) or comma separated list of nonces")
+                        .value_parser(parse_nonces),
+                )
+                .arg(arg!(<WASM> "Path to a wasm file").value_parser(clap::value_parser!(PathBuf)))
+                .arg(
+                    arg!(--fuel [FUEL] "Optional maximum fuel parameter for WASM VM")
+                        .default_value("1000000000")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    arg!(--mem [MEM] "Optional maximum memory parameter for WASM VM")
+                        .default_value("1000000000")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    arg!(--workers [WORKERS] "Optional number of worker threads. Defaults to the number of available cpus")
+                        .value_parser(clap::value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("generate_instance")
+                .about("Generates the challenge instance for a nonce")
+                .arg(
+                    arg!(<SETTINGS> "Settings json string or path to json file")
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(arg!(<NONCE> "Nonce value").value_parser(clap::value_parser!(u64)))
+                .arg(
+                    arg!(--format [FORMAT] "Output format. bincode is the input passed to the algorithm's WASM")
+                        .default_value("json")
+                        .value_parser(["json", "bincode", "native"]),
+                ),
+        )
+        .subcommand(
+            Command::new("serve")
+                .about("Serves newline-delimited JSON-RPC requests over stdin/stdout"),
+        )
+        .subcommand(
+            Command::new("verify_proof")
+                .about("Verifies every solution in a benchmark's proof")
+                .arg(
+                    arg!(<BENCHMARK_DATA> "get-benchmark-data response json string or path to json file")
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(arg!(<WASM> "Path to a wasm file").value_parser(clap::value_parser!(PathBuf)))
+                .arg(
+                    arg!(--fuel [FUEL] "Optional maximum fuel parameter for WASM VM")
+                        .default_value("1000000000")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    arg!(--mem [MEM] "Optional maximum memory parameter for WASM VM")
+                        .default_value("1000000000")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    arg!(--workers [WORKERS] "Optional number of worker threads. Defaults to the number of available cpus")
+                        .value_parser(clap::value_parser!(usize)),
+                ),
+        )
         .subcommand(
             Command::new("verify_solution")
                 .about("Verifies a solution")
@@ -54,6 +137,27 @@ fn main() {
             *sub_m.get_one::<u64>("mem").unwrap(),
             *sub_m.get_one::<u64>("fuel").unwrap(),
         ),
+        Some(("compute_batch", sub_m)) => compute_batch(
+            sub_m.get_one::<String>("SETTINGS").unwrap().clone(),
+            sub_m.get_one::<Vec<u64>>("NONCES").unwrap().clone(),
+            sub_m.get_one::<PathBuf>("WASM").unwrap().clone(),
+            *sub_m.get_one::<u64>("mem").unwrap(),
+            *sub_m.get_one::<u64>("fuel").unwrap(),
+            sub_m.get_one::<usize>("workers").cloned(),
+        ),
+        Some(("generate_instance", sub_m)) => generate_instance(
+            sub_m.get_one::<String>("SETTINGS").unwrap().clone(),
+            *sub_m.get_one::<u64>("NONCE").unwrap(),
+            sub_m.get_one::<String>("format").unwrap().clone(),
+        ),
+        Some(("serve", _)) => serve(),
+        Some(("verify_proof", sub_m)) => verify_proof(
+            sub_m.get_one::<String>("BENCHMARK_DATA").unwrap().clone(),
+            sub_m.get_one::<PathBuf>("WASM").unwrap().clone(),
+            *sub_m.get_one::<u64>("mem").unwrap(),
+            *sub_m.get_one::<u64>("fuel").unwrap(),
+            sub_m.get_one::<usize>("workers").cloned(),
+        ),
         Some(("verify_solution", sub_m)) => verify_solution(
             sub_m.get_one::<String>("SETTINGS").unwrap().clone(),
             *sub_m.get_one::<u64>("NONCE").unwrap(),
@@ -108,9 +212,396 @@ fn compute_solution(
             std::process::exit(1);
         }
         Err(e) => {
+            eprintln!("Error computing solution for nonce {}: {}", nonce, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn parse_nonces(s: &str) -> Result<Vec<u64>, String> {
+    if let Some((start, end)) = s.split_once("..") {
+        let start = start
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid start of nonce range: {}", start))?;
+        let end = end
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid end of nonce range: {}", end))?;
+        Ok((start..end).collect())
+    } else {
+        s.split(',')
+            .map(|n| {
+                n.trim()
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid nonce: {}", n))
+            })
+            .collect()
+    }
+}
+
+fn resolve_num_workers(num_workers: Option<usize>) -> usize {
+    num_workers
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+        .max(1)
+}
+
+// Runs `f` for each nonce across `num_workers` threads, passing results to `on_result` as they complete
+fn for_each_nonce_parallel<T, F, G>(nonces: &[u64], num_workers: usize, f: F, mut on_result: G)
+where
+    T: Send,
+    F: Fn(u64) -> Option<T> + Sync,
+    G: FnMut(T),
+{
+    let next_idx = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<T>();
+    thread::scope(|s| {
+        for _ in 0..num_workers {
+            let sender = sender.clone();
+            let (f, next_idx) = (&f, &next_idx);
+            s.spawn(move || {
+                while let Some(&nonce) = nonces.get(next_idx.fetch_add(1, Ordering::Relaxed)) {
+                    if let Some(result) = f(nonce) {
+                        if sender.send(result).is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+        drop(sender);
+        for result in receiver {
+            on_result(result);
+        }
+    });
+}
+
+#[derive(Serialize)]
+struct NonceFailure {
+    nonce: u64,
+    error: String,
+}
+
+enum NonceResult {
+    Solution(worker::SolutionData),
+    NoSolution(worker::SolutionData),
+    InvalidSolution(worker::SolutionData, String),
+    Error(u64, error::WorkerError),
+}
+
+fn compute_batch(
+    mut settings: String,
+    nonces: Vec<u64>,
+    wasm_path: PathBuf,
+    max_memory: u64,
+    max_fuel: u64,
+    num_workers: Option<usize>,
+) {
+    if settings.ends_with(".json") {
+        settings = fs::read_to_string(&settings).unwrap_or_else(|_| {
+            eprintln!("Failed to read settings file: {}", settings);
+            std::process::exit(1);
+        });
+    }
+    let settings = dejsonify::<BenchmarkSettings>(&settings).unwrap_or_else(|_| {
+        eprintln!("Failed to parse settings");
+        std::process::exit(1);
+    });
+
+    let wasm = fs::read(&wasm_path).unwrap_or_else(|_| {
+        eprintln!("Failed to read wasm file: {}", wasm_path.display());
+        std::process::exit(1);
+    });
+    let executor = worker::WasmExecutor::new(wasm.as_slice()).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    let num_workers = resolve_num_workers(num_workers);
+
+    let start = Instant::now();
+    let mut num_solutions = 0;
+    let mut num_no_solutions = 0;
+    let mut num_invalid = 0;
+    let mut num_errors = 0;
+    let mut fuel_consumed = Vec::<u64>::new();
+    for_each_nonce_parallel(
+        &nonces,
+        num_workers,
+        |nonce| match executor.compute_solution(&settings, nonce, max_memory, max_fuel) {
+            Ok(Some(solution_data)) if solution_data.solution.len() == 0 => {
+                Some(NonceResult::NoSolution(solution_data))
+            }
+            Ok(Some(solution_data)) => Some(
+                match worker::verify_solution(&settings, nonce, &solution_data.solution) {
+                    Ok(()) => NonceResult::Solution(solution_data),
+                    Err(e) => NonceResult::InvalidSolution(solution_data, e.to_string()),
+                },
+            ),
+            Ok(None) => None,
+            Err(e) => Some(NonceResult::Error(nonce, e)),
+        },
+        |result| match result {
+            NonceResult::Solution(solution_data) => {
+                num_solutions += 1;
+                fuel_consumed.push(solution_data.fuel_consumed);
+                println!("{}", jsonify(&solution_data));
+            }
+            NonceResult::NoSolution(solution_data) => {
+                num_no_solutions += 1;
+                fuel_consumed.push(solution_data.fuel_consumed);
+                println!("{}", jsonify(&solution_data));
+            }
+            NonceResult::InvalidSolution(solution_data, e) => {
+                num_invalid += 1;
+                fuel_consumed.push(solution_data.fuel_consumed);
+                println!(
+                    "{}",
+                    jsonify(&NonceFailure {
+                        nonce: solution_data.nonce,
+                        error: format!("Invalid solution: {}", e),
+                    })
+                );
+            }
+            NonceResult::Error(nonce, e) => {
+                num_errors += 1;
+                println!(
+                    "{}",
+                    jsonify(&NonceFailure {
+                        nonce,
+                        error: e.to_string(),
+                    })
+                );
+            }
+        },
+    );
+
+    let elapsed_ms = start.elapsed().as_millis();
+    let num_instances = num_solutions + num_no_solutions + num_invalid + num_errors;
+    fuel_consumed.sort_unstable();
+    let percentile = |p: usize| match fuel_consumed.len() {
+        0 => 0,
+        n => fuel_consumed[(n - 1) * p / 100],
+    };
+    eprintln!(
+        "#instances: {}, #solutions: {}, #no_solution: {}, #invalid: {}, #errors: {}",
+        num_instances, num_solutions, num_no_solutions, num_invalid, num_errors
+    );
+    eprintln!(
+        "solution rate: {:.2}%, elapsed: {}ms, workers: {}",
+        if num_instances == 0 {
+            0.0
+        } else {
+            num_solutions as f64 * 100.0 / num_instances as f64
+        },
+        elapsed_ms,
+        num_workers
+    );
+    eprintln!(
+        "fuel_consumed p50: {}, p90: {}, p99: {}, max: {}",
+        percentile(50),
+        percentile(90),
+        percentile(99),
+        percentile(100)
+    );
+}
+
+fn generate_instance(mut settings: String, nonce: u64, format: String) {
+    if settings.ends_with(".json") {
+        settings = fs::read_to_string(&settings).unwrap_or_else(|_| {
+            eprintln!("Failed to read settings file: {}", settings);
+            std::process::exit(1);
+        });
+    }
+    let settings = dejsonify::<BenchmarkSettings>(&settings).unwrap_or_else(|_| {
+        eprintln!("Failed to parse settings");
+        std::process::exit(1);
+    });
+
+    let seeds = settings.calc_seeds(nonce);
+    let instance = registry::get(&settings.challenge_id)
+        .and_then(|c| c.generate_instance(seeds, &settings.difficulty))
+        .unwrap_or_else(|e| {
             eprintln!("Error: {}", e);
             std::process::exit(1);
+        });
+    let output = match format.as_str() {
+        "bincode" => instance.to_bincode(),
+        "native" => Ok(format!("{}\n", instance.to_native()).into_bytes()),
+        _ => instance
+            .to_json()
+            .map(|json| format!("{}\n", json).into_bytes()),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    let mut stdout = std::io::stdout();
+    if let Err(e) = stdout.write_all(&output).and_then(|_| stdout.flush()) {
+        eprintln!("Failed to write instance: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn serve() {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    if let Err(e) = serve::Server::new().run(stdin.lock(), stdout.lock()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+// Checks a proof's solution data for a nonce the same way as tig-protocol, returning every mismatch
+fn verify_proof_nonce(
+    executor: &worker::WasmExecutor,
+    settings: &BenchmarkSettings,
+    expected_signatures: &HashMap<u64, u32>,
+    solution_data: &worker::SolutionData,
+    max_memory: u64,
+    max_fuel: u64,
+) -> Vec<String> {
+    let nonce = solution_data.nonce;
+    let mut mismatches = Vec::new();
+    match expected_signatures.get(&nonce) {
+        None => mismatches.push("nonce is not in solutions_meta_data".to_string()),
+        Some(&expected) => {
+            let actual = solution_data.calc_solution_signature();
+            if actual != expected {
+                mismatches.push(format!(
+                    "solution_signature. Expected: {}, Actual: {}",
+                    expected, actual
+                ));
+            }
+        }
+    }
+    match executor.compute_solution(settings, nonce, max_memory, max_fuel) {
+        Ok(Some(actual)) => {
+            if actual.runtime_signature != solution_data.runtime_signature {
+                mismatches.push(format!(
+                    "runtime_signature. Expected: {}, Actual: {}",
+                    solution_data.runtime_signature, actual.runtime_signature
+                ));
+            }
+            if actual.fuel_consumed != solution_data.fuel_consumed {
+                mismatches.push(format!(
+                    "fuel_consumed. Expected: {}, Actual: {}",
+                    solution_data.fuel_consumed, actual.fuel_consumed
+                ));
+            }
+            if actual.solution != solution_data.solution {
+                mismatches.push(format!(
+                    "solution. Expected: {}, Actual: {}",
+                    jsonify(&solution_data.solution),
+                    jsonify(&actual.solution)
+                ));
+            }
+        }
+        Ok(None) => mismatches.push("algorithm returned no solution data".to_string()),
+        Err(e) => mismatches.push(format!("failed to recompute solution: {}", e)),
+    }
+    if let Err(e) = worker::verify_solution(settings, nonce, &solution_data.solution) {
+        mismatches.push(format!("solution is invalid: {}", e));
+    }
+    mismatches
+}
+
+fn verify_proof(
+    mut benchmark_data: String,
+    wasm_path: PathBuf,
+    max_memory: u64,
+    max_fuel: u64,
+    num_workers: Option<usize>,
+) {
+    if benchmark_data.ends_with(".json") {
+        benchmark_data = fs::read_to_string(&benchmark_data).unwrap_or_else(|_| {
+            eprintln!("Failed to read benchmark data file: {}", benchmark_data);
+            std::process::exit(1);
+        });
+    }
+    let benchmark_data = dejsonify::<GetBenchmarkDataResp>(&benchmark_data).unwrap_or_else(|e| {
+        eprintln!("Failed to parse benchmark data: {}", e);
+        std::process::exit(1);
+    });
+    let (benchmark, proof) = match (benchmark_data.benchmark, benchmark_data.proof) {
+        (Some(benchmark), Some(proof)) => (benchmark, proof),
+        (None, _) => {
+            eprintln!("Benchmark data is missing the benchmark");
+            std::process::exit(1);
         }
+        (_, None) => {
+            eprintln!("No proof found for benchmark");
+            std::process::exit(1);
+        }
+    };
+    if let Some(fraud) = &benchmark_data.fraud {
+        println!(
+            "Benchmark is flagged as fraud: {}",
+            fraud.allegation.as_deref().unwrap_or("")
+        );
+    }
+    let solutions_data = proof.solutions_data.unwrap_or_else(|| {
+        eprintln!("Proof is missing solutions_data");
+        std::process::exit(1);
+    });
+    let expected_signatures: HashMap<u64, u32> = benchmark
+        .solutions_meta_data
+        .unwrap_or_default()
+        .into_iter()
+        .map(|x| (x.nonce, x.solution_signature))
+        .collect();
+
+    let wasm = fs::read(&wasm_path).unwrap_or_else(|_| {
+        eprintln!("Failed to read wasm file: {}", wasm_path.display());
+        std::process::exit(1);
+    });
+    let executor = worker::WasmExecutor::new(wasm.as_slice()).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+
+    let settings = &benchmark.settings;
+    let nonces: Vec<u64> = solutions_data.iter().map(|x| x.nonce).collect();
+    let solutions_data: HashMap<u64, worker::SolutionData> =
+        solutions_data.into_iter().map(|x| (x.nonce, x)).collect();
+    println!(
+        "Verifying {} solutions for benchmark {}",
+        nonces.len(),
+        benchmark.id
+    );
+    let mut num_invalid = 0;
+    for_each_nonce_parallel(
+        &nonces,
+        resolve_num_workers(num_workers),
+        |nonce| {
+            let mismatches = verify_proof_nonce(
+                &executor,
+                settings,
+                &expected_signatures,
+                &solutions_data[&nonce],
+                max_memory,
+                max_fuel,
+            );
+            Some((nonce, mismatches))
+        },
+        |(nonce, mismatches)| {
+            if mismatches.is_empty() {
+                println!("Nonce {}: Ok", nonce);
+            } else {
+                num_invalid += 1;
+                for mismatch in mismatches {
+                    println!("Nonce {}: Mismatch in {}", nonce, mismatch);
+                }
+            }
+        },
+    );
+    if num_invalid == 0 {
+        println!("All {} solutions verified", nonces.len());
+        std::process::exit(0);
+    } else {
+        println!(
+            "{} out of {} solutions failed verification",
+            num_invalid,
+            nonces.len()
+        );
+        std::process::exit(1);
     }
 }
 
@@ -0,0 +1,237 @@
+use crate::worker::{verify_solution, BenchmarkSettings, Solution, SolutionData, WasmExecutor};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs,
+    io::{BufRead, Write},
+};
+use tig_challenges::registry;
+use tig_utils::{dejsonify, jsonify, md5_from_bytes};
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const WORKER_ERROR: i64 = -32000;
+
+const DEFAULT_MAX_MEMORY: u64 = 1_000_000_000;
+const DEFAULT_MAX_FUEL: u64 = 1_000_000_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RpcRequest {
+    pub jsonrpc: String,
+    pub id: Value,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RpcResponse {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoadWasmParams {
+    pub wasm_path: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoadWasmResult {
+    pub handle: u64,
+    pub checksum: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ComputeSolutionParams {
+    pub handle: u64,
+    pub settings: BenchmarkSettings,
+    pub nonce: u64,
+    pub max_memory: Option<u64>,
+    pub max_fuel: Option<u64>,
+}
+
+pub type ComputeSolutionResult = SolutionData;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VerifySolutionParams {
+    pub settings: BenchmarkSettings,
+    pub nonce: u64,
+    pub solution: Solution,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VerifySolutionResult {
+    pub valid: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GenerateInstanceParams {
+    pub settings: BenchmarkSettings,
+    pub nonce: u64,
+}
+
+pub type GenerateInstanceResult = Value;
+
+/// Handles newline-delimited JSON-RPC requests, keeping loaded wasm modules across requests
+#[derive(Default)]
+pub struct Server {
+    executors: HashMap<u64, WasmExecutor>,
+    handles: HashMap<String, u64>,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads requests from `input` and writes one response line per request to `output`
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> std::io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            writeln!(output, "{}", jsonify(&self.handle_line(&line)))?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn handle_line(&mut self, line: &str) -> RpcResponse {
+        match dejsonify::<RpcRequest>(line) {
+            Ok(request) => self.handle(request),
+            Err(e) => RpcResponse::error(Value::Null, PARSE_ERROR, e.to_string()),
+        }
+    }
+
+    pub fn handle(&mut self, request: RpcRequest) -> RpcResponse {
+        let result = match request.method.as_str() {
+            "load_wasm" => params(request.params).and_then(|p| self.load_wasm(p)),
+            "compute_solution" => params(request.params).and_then(|p| self.compute_solution(p)),
+            "verify_solution" => params(request.params).and_then(|p| self.verify_solution(p)),
+            "generate_instance" => params(request.params).and_then(|p| self.generate_instance(p)),
+            method => Err(RpcError {
+                code: METHOD_NOT_FOUND,
+                message: format!("Unknown method '{}'", method),
+            }),
+        };
+        match result {
+            Ok(result) => RpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: Some(result),
+                error: None,
+            },
+            Err(e) => RpcResponse::error(request.id, e.code, e.message),
+        }
+    }
+
+    fn load_wasm(&mut self, params: LoadWasmParams) -> Result<Value, RpcError> {
+        let wasm = fs::read(&params.wasm_path).map_err(|e| RpcError {
+            code: INVALID_PARAMS,
+            message: format!("Failed to read wasm file {}: {}", params.wasm_path, e),
+        })?;
+        let checksum = md5_from_bytes(&wasm);
+        let handle = match self.handles.get(&checksum) {
+            Some(&handle) => handle,
+            None => {
+                let executor = WasmExecutor::new(&wasm).map_err(worker_error)?;
+                let handle = self.executors.len() as u64;
+                self.executors.insert(handle, executor);
+                self.handles.insert(checksum.clone(), handle);
+                handle
+            }
+        };
+        to_value(LoadWasmResult { handle, checksum })
+    }
+
+    fn compute_solution(&mut self, params: ComputeSolutionParams) -> Result<Value, RpcError> {
+        let executor = self.executors.get(&params.handle).ok_or_else(|| RpcError {
+            code: INVALID_PARAMS,
+            message: format!("Unknown wasm handle '{}'", params.handle),
+        })?;
+        let solution_data: Option<ComputeSolutionResult> = executor
+            .compute_solution(
+                &params.settings,
+                params.nonce,
+                params.max_memory.unwrap_or(DEFAULT_MAX_MEMORY),
+                params.max_fuel.unwrap_or(DEFAULT_MAX_FUEL),
+            )
+            .map_err(worker_error)?;
+        to_value(solution_data)
+    }
+
+    fn verify_solution(&mut self, params: VerifySolutionParams) -> Result<Value, RpcError> {
+        let result = match verify_solution(&params.settings, params.nonce, &params.solution) {
+            Ok(()) => VerifySolutionResult {
+                valid: true,
+                error: None,
+            },
+            Err(e) => VerifySolutionResult {
+                valid: false,
+                error: Some(e.to_string()),
+            },
+        };
+        to_value(result)
+    }
+
+    fn generate_instance(&mut self, params: GenerateInstanceParams) -> Result<Value, RpcError> {
+        let seeds = params.settings.calc_seeds(params.nonce);
+        let instance = registry::get(&params.settings.challenge_id)
+            .and_then(|c| c.generate_instance(seeds, &params.settings.difficulty))
+            .and_then(|c| c.to_json())
+            .map_err(|e| RpcError {
+                code: INVALID_PARAMS,
+                message: e.to_string(),
+            })?;
+        dejsonify::<GenerateInstanceResult>(&instance).map_err(|e| RpcError {
+            code: WORKER_ERROR,
+            message: e.to_string(),
+        })
+    }
+}
+
+impl RpcResponse {
+    pub fn error(id: Value, code: i64, message: String) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(RpcError { code, message }),
+        }
+    }
+}
+
+fn params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError {
+        code: INVALID_PARAMS,
+        message: format!("Invalid params: {}", e),
+    })
+}
+
+fn to_value<T: Serialize>(result: T) -> Result<Value, RpcError> {
+    serde_json::to_value(result).map_err(|e| RpcError {
+        code: WORKER_ERROR,
+        message: e.to_string(),
+    })
+}
+
+fn worker_error(e: crate::error::WorkerError) -> RpcError {
+    RpcError {
+        code: WORKER_ERROR,
+        message: e.to_string(),
+    }
+}
@@ -1,119 +1,145 @@
-use anyhow::{anyhow, Result};
-use bincode;
-use tig_challenges::*;
+use crate::error::{WorkerError, WorkerResult};
+use anyhow::Result;
+use std::sync::Arc;
+use tig_challenges::registry;
 pub use tig_structs::core::{BenchmarkSettings, Solution, SolutionData};
 use tig_utils::decompress_obj;
 use wasmi::{Config, Engine, Linker, Module, Store, StoreLimitsBuilder};
 
-pub fn compute_solution(
-    settings: &BenchmarkSettings,
-    nonce: u64,
-    wasm: &[u8],
-    max_memory: u64,
-    max_fuel: u64,
-) -> Result<Option<SolutionData>> {
-    let seeds = settings.calc_seeds(nonce);
-    let serialized_challenge = match settings.challenge_id.as_str() {
-        "c001" => {
-            let challenge =
-                satisfiability::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .unwrap();
-            bincode::serialize(&challenge).unwrap()
-        }
-        "c002" => {
-            let challenge =
-                vehicle_routing::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .unwrap();
-            bincode::serialize(&challenge).unwrap()
-        }
-        "c003" => {
-            let challenge =
-                knapsack::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .unwrap();
-            bincode::serialize(&challenge).unwrap()
-        }
-        "c004" => {
-            let challenge =
-                vector_search::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .unwrap();
-            bincode::serialize(&challenge).unwrap()
-        }
-        _ => panic!("Unknown challenge"),
-    };
+/// An algorithm's wasm blob compiled once, so that each nonce only needs a fresh `Store`
+#[derive(Clone)]
+pub struct WasmExecutor {
+    engine: Engine,
+    module: Arc<Module>,
+}
+
+impl WasmExecutor {
+    pub fn new(wasm: &[u8]) -> WorkerResult<Self> {
+        let mut config = Config::default();
+        config.update_runtime_signature(true);
+        config.consume_fuel(true);
+
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm).map_err(|e| WorkerError::InvalidModule {
+            reason: e.to_string(),
+        })?;
+        Ok(Self {
+            engine,
+            module: Arc::new(module),
+        })
+    }
 
-    let mut config = Config::default();
-    config.update_runtime_signature(true);
-    config.consume_fuel(true);
+    pub fn compute_solution(
+        &self,
+        settings: &BenchmarkSettings,
+        nonce: u64,
+        max_memory: u64,
+        max_fuel: u64,
+    ) -> WorkerResult<Option<SolutionData>> {
+        let seeds = settings.calc_seeds(nonce);
+        let serialized_challenge = registry::get(&settings.challenge_id)
+            .and_then(|c| c.generate_instance(seeds, &settings.difficulty))
+            .and_then(|c| c.to_bincode())
+            .map_err(|e| WorkerError::InvalidSettings {
+                reason: e.to_string(),
+            })?;
 
-    let limits = StoreLimitsBuilder::new()
-        .memory_size(max_memory as usize)
-        .memories(1)
-        .trap_on_grow_failure(true)
-        .build();
-    // Setup instance of wasm module
-    let engine = Engine::new(&config);
-    let mut store = Store::new(&engine, limits);
-    store.limiter(|lim| lim);
-    store.set_fuel(max_fuel).unwrap();
-    let linker = Linker::new(&engine);
-    let module = Module::new(store.engine(), wasm).expect("Failed to instantiate module");
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(max_memory as usize)
+            .memories(1)
+            .trap_on_grow_failure(true)
+            .build();
+        // Setup instance of wasm module
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|lim| lim);
+        store.set_fuel(max_fuel).unwrap();
+        let linker = Linker::new(&self.engine);
 
-    let instance = &linker
-        .instantiate(&mut store, &module)
-        .expect("Failed to instantiate linker")
-        .start(&mut store)
-        .expect("Failed to start module");
+        let instance = &linker
+            .instantiate(&mut store, &self.module)
+            .map_err(|e| match WorkerError::from_execution(e) {
+                WorkerError::Trap { message } => WorkerError::InvalidModule { reason: message },
+                e => e,
+            })?
+            .start(&mut store)
+            .map_err(WorkerError::from_execution)?;
 
-    let memory = instance
-        .get_memory(&store, "memory")
-        .expect("Failed to find memory");
+        let memory =
+            instance
+                .get_memory(&store, "memory")
+                .ok_or_else(|| WorkerError::MissingExport {
+                    name: "memory".to_string(),
+                })?;
 
-    // Run algorithm
-    let init = instance
-        .get_typed_func::<u32, u32>(&store, "init")
-        .expect("Failed to find `init` function");
-    let entry_point = instance
-        .get_typed_func::<(u32, u32), u32>(&store, "entry_point")
-        .expect("Failed to find `entry_point` function");
+        // Run algorithm
+        let init = instance
+            .get_typed_func::<u32, u32>(&store, "init")
+            .map_err(|_| WorkerError::MissingExport {
+                name: "init".to_string(),
+            })?;
+        let entry_point = instance
+            .get_typed_func::<(u32, u32), u32>(&store, "entry_point")
+            .map_err(|_| WorkerError::MissingExport {
+                name: "entry_point".to_string(),
+            })?;
 
-    let challenge_len = serialized_challenge.len() as u32;
-    let challenge_ptr: u32 = init.call(&mut store, challenge_len).unwrap();
-    memory
-        .write(&mut store, challenge_ptr as usize, &serialized_challenge)
-        .expect("Failed to write serialized challenge to `memory`");
-    let solution_ptr = entry_point
-        .call(&mut store, (challenge_ptr, challenge_len))
-        .map_err(|e| anyhow!("Failed to call function: {:?}", e))?;
+        let challenge_len = serialized_challenge.len() as u32;
+        let challenge_ptr: u32 = init
+            .call(&mut store, challenge_len)
+            .map_err(WorkerError::from_execution)?;
+        memory
+            .write(&mut store, challenge_ptr as usize, &serialized_challenge)
+            .map_err(|e| WorkerError::Trap {
+                message: format!("Failed to write serialized challenge to `memory`: {}", e),
+            })?;
+        let solution_ptr = entry_point
+            .call(&mut store, (challenge_ptr, challenge_len))
+            .map_err(WorkerError::from_execution)?;
 
-    // Get runtime signature
-    let runtime_signature_u64 = store.get_runtime_signature();
-    let runtime_signature = (runtime_signature_u64 as u32) ^ ((runtime_signature_u64 >> 32) as u32);
-    let fuel_consumed = max_fuel - store.get_fuel().unwrap();
-    // Read solution from memory
-    let mut solution_len_bytes = [0u8; 4];
-    memory
-        .read(&store, solution_ptr as usize, &mut solution_len_bytes)
-        .expect("Failed to read solution length from memory");
-    let solution_len = u32::from_le_bytes(solution_len_bytes);
-    let mut serialized_solution = vec![0u8; solution_len as usize];
-    memory
-        .read(
-            &store,
-            (solution_ptr + 4) as usize,
-            &mut serialized_solution,
-        )
-        .expect("Failed to read solution from memory");
-    let mut solution_data = SolutionData {
-        nonce,
-        runtime_signature,
-        fuel_consumed,
-        solution: Solution::new(),
-    };
-    if solution_len != 0 {
-        solution_data.solution =
-            decompress_obj(&serialized_solution).expect("Failed to decompress solution");
+        // Get runtime signature
+        let runtime_signature_u64 = store.get_runtime_signature();
+        let runtime_signature =
+            (runtime_signature_u64 as u32) ^ ((runtime_signature_u64 >> 32) as u32);
+        let fuel_consumed = max_fuel - store.get_fuel().unwrap();
+        // Read solution from memory
+        let mut solution_len_bytes = [0u8; 4];
+        memory
+            .read(&store, solution_ptr as usize, &mut solution_len_bytes)
+            .map_err(|e| WorkerError::MalformedSolution {
+                reason: format!("Failed to read solution length from memory: {}", e),
+            })?;
+        let solution_len = u32::from_le_bytes(solution_len_bytes);
+        let mut serialized_solution = vec![0u8; solution_len as usize];
+        memory
+            .read(&store, solution_ptr as usize + 4, &mut serialized_solution)
+            .map_err(|e| WorkerError::MalformedSolution {
+                reason: format!("Failed to read solution from memory: {}", e),
+            })?;
+        let mut solution_data = SolutionData {
+            nonce,
+            runtime_signature,
+            fuel_consumed,
+            solution: Solution::new(),
+        };
+        if solution_len != 0 {
+            solution_data.solution = decompress_obj(&serialized_solution).map_err(|e| {
+                WorkerError::SolutionDecompressionFailed {
+                    reason: e.to_string(),
+                }
+            })?;
+        }
+        Ok(Some(solution_data))
     }
-    Ok(Some(solution_data))
+}
+
+pub fn compute_solution(
+    settings: &BenchmarkSettings,
+    nonce: u64,
+    wasm: &[u8],
+    max_memory: u64,
+    max_fuel: u64,
+) -> WorkerResult<Option<SolutionData>> {
+    WasmExecutor::new(wasm)?.compute_solution(settings, nonce, max_memory, max_fuel)
 }
 
 pub fn verify_solution(
@@ -122,51 +148,7 @@ pub fn verify_solution(
     solution: &Solution,
 ) -> Result<()> {
     let seeds = settings.calc_seeds(nonce);
-    match settings.challenge_id.as_str() {
-        "c001" => {
-            let challenge =
-                satisfiability::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .expect("Failed to generate satisfiability instance");
-            match satisfiability::Solution::try_from(solution.clone()) {
-                Ok(solution) => challenge.verify_solution(&solution),
-                Err(_) => Err(anyhow!(
-                    "Invalid solution. Cannot convert to satisfiability::Solution"
-                )),
-            }
-        }
-        "c002" => {
-            let challenge =
-                vehicle_routing::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .expect("Failed to generate vehicle_routing instance");
-            match vehicle_routing::Solution::try_from(solution.clone()) {
-                Ok(solution) => challenge.verify_solution(&solution),
-                Err(_) => Err(anyhow!(
-                    "Invalid solution. Cannot convert to vehicle_routing::Solution"
-                )),
-            }
-        }
-        "c003" => {
-            let challenge =
-                knapsack::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .expect("Failed to generate knapsack instance");
-            match knapsack::Solution::try_from(solution.clone()) {
-                Ok(solution) => challenge.verify_solution(&solution),
-                Err(_) => Err(anyhow!(
-                    "Invalid solution. Cannot convert to knapsack::Solution"
-                )),
-            }
-        }
-        "c004" => {
-            let challenge =
-                vector_search::Challenge::generate_instance_from_vec(seeds, &settings.difficulty)
-                    .expect("Failed to generate vector_search instance");
-            match vector_search::Solution::try_from(solution.clone()) {
-                Ok(solution) => challenge.verify_solution(&solution),
-                Err(_) => Err(anyhow!(
-                    "Invalid solution. Cannot convert to vector_search::Solution"
-                )),
-            }
-        }
-        _ => panic!("Unknown challenge"),
-    }
+    registry::get(&settings.challenge_id)?
+        .generate_instance(seeds, &settings.difficulty)?
+        .verify_solution(solution)
 }
@@ -0,0 +1,63 @@
+use serde_json::json;
+use std::process::Command;
+use tig_worker::*;
+
+const WASM_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../tig-algorithms/wasm/knapsack/dynamic.wasm"
+);
+
+fn verify_proof(benchmark_data: &serde_json::Value) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_tig-worker"))
+        .args(["verify_proof", &benchmark_data.to_string(), WASM_PATH])
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_verify_proof() {
+    let settings = BenchmarkSettings {
+        player_id: "".to_string(),
+        block_id: "".to_string(),
+        challenge_id: "c003".to_string(),
+        algorithm_id: "".to_string(),
+        difficulty: vec![50, 10],
+    };
+    let wasm = std::fs::read(WASM_PATH).unwrap();
+    let solution_data = compute_solution(&settings, 3, &wasm, 1_000_000_000, 1_000_000_000)
+        .unwrap()
+        .unwrap();
+    let benchmark_data = |solution_data: &SolutionData| {
+        json!({
+            "benchmark": {
+                "id": "benchmark",
+                "settings": settings,
+                "details": {"block_started": 0, "num_solutions": 1},
+                "solutions_meta_data": [{
+                    "nonce": 3,
+                    "solution_signature": solution_data.calc_solution_signature()
+                }]
+            },
+            "proof": {
+                "benchmark_id": "benchmark",
+                "solutions_data": [solution_data]
+            },
+            "fraud": null
+        })
+    };
+
+    let (success, stdout) = verify_proof(&benchmark_data(&solution_data));
+    assert!(success, "{}", stdout);
+    assert!(stdout.contains("Nonce 3: Ok"));
+
+    let mut tampered = solution_data.clone();
+    tampered.fuel_consumed += 1;
+    let (success, stdout) = verify_proof(&benchmark_data(&tampered));
+    assert!(!success);
+    assert!(stdout.contains("Nonce 3: Mismatch in fuel_consumed"));
+    assert!(!stdout.contains("Mismatch in solution_signature"));
+}
@@ -0,0 +1,91 @@
+use serde_json::Value;
+use tig_worker::serve::*;
+
+const WASM_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../tig-algorithms/wasm/knapsack/dynamic.wasm"
+);
+const SETTINGS: &str = r#"{"challenge_id":"c003","difficulty":[50,10],"algorithm_id":"","player_id":"","block_id":""}"#;
+
+fn run(requests: &[String]) -> Vec<RpcResponse> {
+    let input = requests.join("\n");
+    let mut output = Vec::new();
+    Server::new().run(input.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_load_wasm_and_compute_solution() {
+    let responses = run(&[
+        format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"load_wasm","params":{{"wasm_path":"{}"}}}}"#,
+            WASM_PATH
+        ),
+        format!(
+            r#"{{"jsonrpc":"2.0","id":2,"method":"load_wasm","params":{{"wasm_path":"{}"}}}}"#,
+            WASM_PATH
+        ),
+        format!(
+            r#"{{"jsonrpc":"2.0","id":3,"method":"compute_solution","params":{{"handle":0,"settings":{},"nonce":3}}}}"#,
+            SETTINGS
+        ),
+    ]);
+    assert_eq!(responses.len(), 3);
+    let loaded = responses[0].result.as_ref().unwrap();
+    // loading the same wasm twice reuses the compiled module
+    assert_eq!(Some(loaded), responses[1].result.as_ref());
+    assert_eq!(loaded["handle"], Value::from(0));
+    assert_eq!(responses[2].id, Value::from(3));
+    let solution_data = responses[2].result.as_ref().unwrap();
+    assert_eq!(solution_data["nonce"], Value::from(3));
+    assert!(solution_data["fuel_consumed"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn test_generate_instance_and_verify_solution() {
+    let responses = run(&[
+        format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"generate_instance","params":{{"settings":{},"nonce":0}}}}"#,
+            SETTINGS
+        ),
+        format!(
+            r#"{{"jsonrpc":"2.0","id":2,"method":"verify_solution","params":{{"settings":{},"nonce":0,"solution":{{"items":[]}}}}}}"#,
+            SETTINGS
+        ),
+    ]);
+    assert!(responses[0].result.as_ref().unwrap()["weights"].is_array());
+    assert_eq!(
+        responses[1].result.as_ref().unwrap()["valid"],
+        Value::from(false)
+    );
+}
+
+#[test]
+fn test_errors() {
+    let responses = run(&[
+        "not json".to_string(),
+        r#"{"jsonrpc":"2.0","id":1,"method":"unknown"}"#.to_string(),
+        r#"{"jsonrpc":"2.0","id":2,"method":"compute_solution","params":{}}"#.to_string(),
+        format!(
+            r#"{{"jsonrpc":"2.0","id":3,"method":"compute_solution","params":{{"handle":7,"settings":{},"nonce":0}}}}"#,
+            SETTINGS
+        ),
+    ]);
+    let codes: Vec<i64> = responses
+        .iter()
+        .map(|r| r.error.as_ref().unwrap().code)
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            PARSE_ERROR,
+            METHOD_NOT_FOUND,
+            INVALID_PARAMS,
+            INVALID_PARAMS
+        ]
+    );
+}
@@ -0,0 +1,153 @@
+use tig_challenges::{knapsack, ChallengeTrait};
+use tig_utils::decompress_obj;
+use tig_worker::*;
+use wasmi::{Config, Engine, Linker, Module, Store, StoreLimitsBuilder};
+
+const WASM: &[u8] = include_bytes!("../../tig-algorithms/wasm/knapsack/dynamic.wasm");
+const MAX_MEMORY: u64 = 1_000_000_000;
+const MAX_FUEL: u64 = 1_000_000_000;
+
+fn settings() -> BenchmarkSettings {
+    BenchmarkSettings {
+        player_id: "0x0000000000000000000000000000000000000000".to_string(),
+        block_id: "".to_string(),
+        challenge_id: "c003".to_string(),
+        algorithm_id: "c003_a001".to_string(),
+        difficulty: vec![50, 10],
+    }
+}
+
+// The one-shot code path from before WasmExecutor, which compiled the module for every nonce
+fn baseline_compute_solution(
+    settings: &BenchmarkSettings,
+    nonce: u64,
+    wasm: &[u8],
+    max_memory: u64,
+    max_fuel: u64,
+) -> SolutionData {
+    let seeds = settings.calc_seeds(nonce);
+    let challenge =
+        knapsack::Challenge::generate_instance_from_vec(seeds, &settings.difficulty).unwrap();
+    let serialized_challenge = bincode::serialize(&challenge).unwrap();
+
+    let mut config = Config::default();
+    config.update_runtime_signature(true);
+    config.consume_fuel(true);
+
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(max_memory as usize)
+        .memories(1)
+        .trap_on_grow_failure(true)
+        .build();
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, limits);
+    store.limiter(|lim| lim);
+    store.set_fuel(max_fuel).unwrap();
+    let linker = Linker::new(&engine);
+    let module = Module::new(store.engine(), wasm).unwrap();
+    let instance = &linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    let init = instance.get_typed_func::<u32, u32>(&store, "init").unwrap();
+    let entry_point = instance
+        .get_typed_func::<(u32, u32), u32>(&store, "entry_point")
+        .unwrap();
+
+    let challenge_len = serialized_challenge.len() as u32;
+    let challenge_ptr = init.call(&mut store, challenge_len).unwrap();
+    memory
+        .write(&mut store, challenge_ptr as usize, &serialized_challenge)
+        .unwrap();
+    let solution_ptr = entry_point
+        .call(&mut store, (challenge_ptr, challenge_len))
+        .unwrap();
+
+    let runtime_signature_u64 = store.get_runtime_signature();
+    let runtime_signature = (runtime_signature_u64 as u32) ^ ((runtime_signature_u64 >> 32) as u32);
+    let fuel_consumed = max_fuel - store.get_fuel().unwrap();
+    let mut solution_len_bytes = [0u8; 4];
+    memory
+        .read(&store, solution_ptr as usize, &mut solution_len_bytes)
+        .unwrap();
+    let solution_len = u32::from_le_bytes(solution_len_bytes);
+    let mut serialized_solution = vec![0u8; solution_len as usize];
+    memory
+        .read(&store, solution_ptr as usize + 4, &mut serialized_solution)
+        .unwrap();
+    SolutionData {
+        nonce,
+        runtime_signature,
+        fuel_consumed,
+        solution: if solution_len == 0 {
+            Solution::new()
+        } else {
+            decompress_obj(&serialized_solution).unwrap()
+        },
+    }
+}
+
+#[test]
+fn test_executor_matches_baseline() {
+    let settings = settings();
+    // one executor is reused across nonces, out of order, to check no state leaks between stores
+    let executor = WasmExecutor::new(WASM).unwrap();
+    for nonce in [2, 0, 1, 0] {
+        let expected = baseline_compute_solution(&settings, nonce, WASM, MAX_MEMORY, MAX_FUEL);
+        let actual = executor
+            .compute_solution(&settings, nonce, MAX_MEMORY, MAX_FUEL)
+            .unwrap()
+            .unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            compute_solution(&settings, nonce, WASM, MAX_MEMORY, MAX_FUEL)
+                .unwrap()
+                .unwrap(),
+            expected
+        );
+        assert!(expected.fuel_consumed > 0);
+    }
+}
+
+#[test]
+fn test_executor_invalid_wasm() {
+    assert!(matches!(
+        WasmExecutor::new(&[0u8; 8]),
+        Err(WorkerError::InvalidModule { .. })
+    ));
+}
+
+#[test]
+fn test_out_of_fuel() {
+    let executor = WasmExecutor::new(WASM).unwrap();
+    assert_eq!(
+        executor
+            .compute_solution(&settings(), 0, MAX_MEMORY, 1_000)
+            .unwrap_err(),
+        WorkerError::OutOfFuel
+    );
+}
+
+#[test]
+fn test_memory_limit_exceeded() {
+    let executor = WasmExecutor::new(WASM).unwrap();
+    assert_eq!(
+        executor
+            .compute_solution(&settings(), 0, 65536, MAX_FUEL)
+            .unwrap_err(),
+        WorkerError::MemoryLimitExceeded
+    );
+}
+
+#[test]
+fn test_unknown_challenge() {
+    let mut settings = settings();
+    settings.challenge_id = "c999".to_string();
+    let executor = WasmExecutor::new(WASM).unwrap();
+    assert!(matches!(
+        executor.compute_solution(&settings, 0, MAX_MEMORY, MAX_FUEL),
+        Err(WorkerError::InvalidSettings { .. })
+    ));
+}